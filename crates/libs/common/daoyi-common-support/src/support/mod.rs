@@ -1,2 +1,3 @@
+pub mod operate_log;
 pub mod orm;
 pub mod tenant;
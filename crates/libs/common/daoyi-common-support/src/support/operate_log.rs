@@ -0,0 +1,81 @@
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+tokio::task_local! {
+    static OPERATE_LOG_CONTEXT: RefCell<OperateLogContext>;
+}
+
+/// 操作日志的上下文，由操作日志中间件为每个请求创建
+///
+/// 处理函数与业务代码可以补充操作日志的业务编号、操作内容与拓展字段，
+/// 不在操作日志中间件内调用时忽略
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OperateLogContext {
+    /// 操作的业务编号
+    pub biz_id: Option<i64>,
+    /// 操作内容
+    pub action: Option<String>,
+    /// 拓展字段
+    pub extra: Map<String, Value>,
+}
+
+impl OperateLogContext {
+    /// 在新的操作日志上下文中执行 future，返回执行结果与上下文
+    pub async fn scope<F: Future>(future: F) -> (F::Output, OperateLogContext) {
+        OPERATE_LOG_CONTEXT
+            .scope(RefCell::new(OperateLogContext::default()), async {
+                let output = future.await;
+                let context = OPERATE_LOG_CONTEXT.with(|context| context.take());
+                (output, context)
+            })
+            .await
+    }
+
+    /// 当前是否在操作日志上下文中
+    pub fn is_active() -> bool {
+        OPERATE_LOG_CONTEXT.try_with(|_| ()).is_ok()
+    }
+
+    /// 设置操作的业务编号
+    pub fn set_biz_id(biz_id: i64) {
+        let _ = OPERATE_LOG_CONTEXT.try_with(|context| context.borrow_mut().biz_id = Some(biz_id));
+    }
+
+    /// 设置操作内容，未设置时使用操作名
+    pub fn set_action(action: impl Into<String>) {
+        let action = action.into();
+        let _ = OPERATE_LOG_CONTEXT.try_with(|context| context.borrow_mut().action = Some(action));
+    }
+
+    /// 添加拓展字段，相同的键覆盖
+    pub fn put_extra(key: impl Into<String>, value: Value) {
+        let key = key.into();
+        let _ = OPERATE_LOG_CONTEXT.try_with(|context| {
+            context.borrow_mut().extra.insert(key, value);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_operate_log_context() {
+        // 不在上下文中时忽略
+        OperateLogContext::set_biz_id(1);
+        assert!(!OperateLogContext::is_active());
+        let (output, context) = OperateLogContext::scope(async {
+            assert!(OperateLogContext::is_active());
+            OperateLogContext::set_biz_id(1024);
+            OperateLogContext::put_extra("name", json!("张三"));
+            "ok"
+        })
+        .await;
+        assert_eq!(output, "ok");
+        assert_eq!(context.biz_id, Some(1024));
+        assert_eq!(context.action, None);
+        assert_eq!(context.extra.get("name"), Some(&json!("张三")));
+    }
+}
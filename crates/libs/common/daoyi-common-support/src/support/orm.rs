@@ -1,6 +1,26 @@
+use crate::support::operate_log::OperateLogContext;
 use crate::support::tenant::TenantContextHolder;
 use crate::utils::errors::error::ApiError;
-use sea_orm::ActiveModelTrait;
+use sea_orm::sea_query::sea_value_to_json_value;
+use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait, IdenStatic, Iterable, ModelTrait};
+use serde::Serialize;
+use serde_json::Value;
+
+/// 变更记录写入操作日志拓展字段时使用的键
+pub const CHANGES_EXTRA_KEY: &str = "changes";
+/// 变更记录中需要脱敏的字段
+const SENSITIVE_FIELDS: [&str; 3] = ["password", "secret", "api_secret"];
+/// 变更记录中忽略的通用字段
+const IGNORED_FIELDS: [&str; 6] = [
+    "creator",
+    "create_time",
+    "updater",
+    "update_time",
+    "deleted",
+    "tenant_id",
+];
+/// 脱敏后的字段值
+const MASKED_VALUE: &str = "******";
 
 /// 通用字段更新trait
 pub trait CommonFieldsUpdater {
@@ -48,6 +68,77 @@ where
     Ok(active_model)
 }
 
+/// 带变更记录的更新操作函数
+///
+/// 在通用的更新操作的基础上，对比更新前的数据，将变更的字段写入操作日志的拓展字段，
+/// 不在操作日志中间件内调用时不记录
+pub async fn update_with_change_log<T>(
+    before: &<T::Entity as EntityTrait>::Model,
+    active_model: T,
+    updater: Option<String>,
+) -> Result<T, ApiError>
+where
+    T: ActiveModelTrait + CommonFieldsUpdater,
+{
+    let active_model = update_with_common_fields(active_model, updater).await?;
+    let changes = diff_active_model(before, &active_model);
+    if !changes.is_empty() {
+        OperateLogContext::put_extra(CHANGES_EXTRA_KEY, serde_json::to_value(changes)?);
+    }
+
+    Ok(active_model)
+}
+
+/// 字段的变更记录
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    /// 字段名
+    pub field: String,
+    /// 变更前的值
+    pub before: Value,
+    /// 变更后的值
+    pub after: Value,
+}
+
+/// 对比更新前的数据与 ActiveModel 中设置的值，返回变更的字段，忽略通用字段，脱敏敏感字段
+pub fn diff_active_model<T>(
+    before: &<T::Entity as EntityTrait>::Model,
+    active_model: &T,
+) -> Vec<FieldChange>
+where
+    T: ActiveModelTrait,
+{
+    <T::Entity as EntityTrait>::Column::iter()
+        .filter_map(|column| {
+            let field = column.as_str();
+            if IGNORED_FIELDS.contains(&field) {
+                return None;
+            }
+            let ActiveValue::Set(after) = active_model.get(column) else {
+                return None;
+            };
+            let before = before.get(column);
+            if before == after {
+                return None;
+            }
+            let (before, after) = if SENSITIVE_FIELDS.contains(&field) {
+                (Value::from(MASKED_VALUE), Value::from(MASKED_VALUE))
+            } else {
+                (
+                    sea_value_to_json_value(&before),
+                    sea_value_to_json_value(&after),
+                )
+            };
+            Some(FieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
 /// 通用的软删除操作函数
 pub async fn soft_delete_with_common_fields<T>(
     mut active_model: T,
@@ -112,3 +203,61 @@ macro_rules! impl_common_fields_updater {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{IntoActiveModel, Set};
+    use serde_json::json;
+
+    mod user {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "user")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub nickname: String,
+            pub password: String,
+            pub remark: Option<String>,
+            pub updater: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_diff_active_model() {
+        let before = user::Model {
+            id: 1,
+            nickname: String::from("张三"),
+            password: String::from("old"),
+            remark: None,
+            updater: Some(String::from("1")),
+        };
+        let mut active_model = before.clone().into_active_model();
+        active_model.nickname = Set(String::from("李四"));
+        active_model.password = Set(String::from("new"));
+        active_model.remark = Set(None);
+        active_model.updater = Set(Some(String::from("2")));
+        assert_eq!(
+            diff_active_model(&before, &active_model),
+            vec![
+                FieldChange {
+                    field: String::from("nickname"),
+                    before: json!("张三"),
+                    after: json!("李四"),
+                },
+                FieldChange {
+                    field: String::from("password"),
+                    before: json!(MASKED_VALUE),
+                    after: json!(MASKED_VALUE),
+                },
+            ]
+        );
+    }
+}
@@ -32,6 +32,16 @@ pub enum AuthMethod {
     Db,
 }
 
+/// 同时在线的会话数达到上限时的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionLimitPolicy {
+    /// 拒绝新的登录
+    Reject,
+    /// 踢出最早登录的会话
+    EvictOldest,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum)]
 #[serde(rename_all = "snake_case")]
 #[sea_orm(
@@ -257,6 +267,8 @@ pub enum LoginLogTypeEnum {
     LogoutSelf = 200,
     /// 强制退出
     LogoutDelete = 202,
+    /// 已轮换的刷新令牌被再次使用，令牌可能已被盗用
+    RefreshTokenReuse = 300,
 }
 
 impl LoginLogTypeEnum {
@@ -265,6 +277,19 @@ impl LoginLogTypeEnum {
         *self as i32
     }
 
+    /// 获取类型名
+    pub fn name(&self) -> &'static str {
+        match self {
+            LoginLogTypeEnum::LoginUsername => "账号登录",
+            LoginLogTypeEnum::LoginSocial => "社交登录",
+            LoginLogTypeEnum::LoginMobile => "手机登录",
+            LoginLogTypeEnum::LoginSms => "短信登录",
+            LoginLogTypeEnum::LogoutSelf => "主动登出",
+            LoginLogTypeEnum::LogoutDelete => "强制退出",
+            LoginLogTypeEnum::RefreshTokenReuse => "刷新令牌重用",
+        }
+    }
+
     /// 根据值查找对应的枚举 variant
     pub fn from_type_value(value: i32) -> Option<LoginLogTypeEnum> {
         match value {
@@ -274,6 +299,7 @@ impl LoginLogTypeEnum {
             104 => Some(LoginLogTypeEnum::LoginSms),
             200 => Some(LoginLogTypeEnum::LogoutSelf),
             202 => Some(LoginLogTypeEnum::LogoutDelete),
+            300 => Some(LoginLogTypeEnum::RefreshTokenReuse),
             _ => None,
         }
     }
@@ -287,6 +313,7 @@ impl LoginLogTypeEnum {
             LoginLogTypeEnum::LoginSms,
             LoginLogTypeEnum::LogoutSelf,
             LoginLogTypeEnum::LogoutDelete,
+            LoginLogTypeEnum::RefreshTokenReuse,
         ]
     }
 }
@@ -299,13 +326,107 @@ pub mod oauth2_client_constants {
     pub const CLIENT_ID_DEFAULT: &'static str = "default";
 }
 
+/// OAuth2 授权类型（模式）的枚举
+///
+/// 对应 Java 中的 OAuth2GrantTypeEnum 枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OAuth2GrantTypeEnum {
+    /// 密码模式
+    Password,
+    /// 授权码模式
+    AuthorizationCode,
+    /// 简化模式
+    Implicit,
+    /// 客户端模式
+    ClientCredentials,
+    /// 刷新模式
+    RefreshToken,
+    /// 设备授权模式，参见 RFC 8628
+    DeviceCode,
+}
+
+impl OAuth2GrantTypeEnum {
+    /// 获取授权类型
+    pub fn grant_type(&self) -> &'static str {
+        match self {
+            OAuth2GrantTypeEnum::Password => "password",
+            OAuth2GrantTypeEnum::AuthorizationCode => "authorization_code",
+            OAuth2GrantTypeEnum::Implicit => "implicit",
+            OAuth2GrantTypeEnum::ClientCredentials => "client_credentials",
+            OAuth2GrantTypeEnum::RefreshToken => "refresh_token",
+            OAuth2GrantTypeEnum::DeviceCode => "urn:ietf:params:oauth:grant-type:device_code",
+        }
+    }
+
+    /// 根据授权类型查找对应的枚举 variant
+    pub fn from_grant_type(grant_type: &str) -> Option<OAuth2GrantTypeEnum> {
+        Self::values()
+            .into_iter()
+            .find(|item| item.grant_type() == grant_type)
+    }
+
+    /// 获取所有枚举值
+    pub fn values() -> Vec<OAuth2GrantTypeEnum> {
+        vec![
+            OAuth2GrantTypeEnum::Password,
+            OAuth2GrantTypeEnum::AuthorizationCode,
+            OAuth2GrantTypeEnum::Implicit,
+            OAuth2GrantTypeEnum::ClientCredentials,
+            OAuth2GrantTypeEnum::RefreshToken,
+            OAuth2GrantTypeEnum::DeviceCode,
+        ]
+    }
+}
+
+/// OAuth2 规范的错误码常量，参见 RFC 6749 5.2
+pub mod oauth2_error_constants {
+    /// 请求缺少必要参数，或参数不正确
+    pub const INVALID_REQUEST: &str = "invalid_request";
+    /// 客户端认证失败
+    pub const INVALID_CLIENT: &str = "invalid_client";
+    /// 授权码、刷新令牌、用户凭证等授权许可无效
+    pub const INVALID_GRANT: &str = "invalid_grant";
+    /// 客户端无权使用该授权类型
+    pub const UNAUTHORIZED_CLIENT: &str = "unauthorized_client";
+    /// 授权服务器不支持该授权类型
+    pub const UNSUPPORTED_GRANT_TYPE: &str = "unsupported_grant_type";
+    /// 授权范围无效或超出客户端的授权范围
+    pub const INVALID_SCOPE: &str = "invalid_scope";
+    /// 授权服务器内部错误
+    pub const SERVER_ERROR: &str = "server_error";
+    /// 访问令牌无效或已过期，参见 RFC 6750 3.1
+    pub const INVALID_TOKEN: &str = "invalid_token";
+    /// 访问令牌的授权范围不足，参见 RFC 6750 3.1
+    pub const INSUFFICIENT_SCOPE: &str = "insufficient_scope";
+    /// 用户拒绝授权
+    pub const ACCESS_DENIED: &str = "access_denied";
+    /// 设备授权模式，用户尚未完成授权，参见 RFC 8628 3.5
+    pub const AUTHORIZATION_PENDING: &str = "authorization_pending";
+    /// 设备授权模式，轮询过于频繁，参见 RFC 8628 3.5
+    pub const SLOW_DOWN: &str = "slow_down";
+    /// 设备授权模式，device_code 已过期，参见 RFC 8628 3.5
+    pub const EXPIRED_TOKEN: &str = "expired_token";
+}
+
+/// 字典类型的常量，与 `system_dict_type.type` 对应
+pub mod dict_type_constants {
+    /// 通用状态
+    pub const COMMON_STATUS: &str = "common_status";
+    /// 用户性别
+    pub const USER_SEX: &str = "system_user_sex";
+    /// 社交平台的类型
+    pub const SOCIAL_TYPE: &str = "system_social_type";
+    /// 通知公告的类型
+    pub const NOTICE_TYPE: &str = "system_notice_type";
+}
+
 /// System Redis Key 常量
 ///
 /// 对应 Java 中的 RedisKeyConstants 接口
 pub mod redis_key_constants {
     /// 指定部门的所有子部门编号数组的缓存
     ///
-    /// KEY 格式：dept_children_ids:{id}
+    /// KEY 格式：dept_children_ids:{tenantId}:{id}
     /// VALUE 数据类型：String 子部门编号集合
     pub const DEPT_CHILDREN_ID_LIST: &'static str = "dept_children_ids";
 
@@ -350,6 +471,42 @@ pub mod redis_key_constants {
     /// 由于动态过期时间，使用 RedisTemplate 操作
     pub const OAUTH2_ACCESS_TOKEN: &'static str = "oauth2_access_token";
 
+    /// 访问令牌对应的登录用户的缓存
+    ///
+    /// KEY 格式：access_token:{token}
+    /// VALUE 数据类型：String 登录用户信息
+    pub const ACCESS_TOKEN: &str = "access_token";
+
+    /// 设备授权的缓存
+    ///
+    /// KEY 格式：oauth2_device_code:{device_code}
+    /// VALUE 数据类型：String 设备授权信息
+    pub const OAUTH2_DEVICE_CODE: &str = "oauth2_device_code";
+
+    /// 设备授权的用户码与 device_code 的映射
+    ///
+    /// KEY 格式：oauth2_device_user_code:{user_code}
+    /// VALUE 数据类型：String device_code
+    pub const OAUTH2_DEVICE_USER_CODE: &str = "oauth2_device_user_code";
+
+    /// 设备的轮询状态，与设备授权分开缓存，避免轮询覆盖用户的确认结果
+    ///
+    /// KEY 格式：oauth2_device_poll:{device_code}
+    /// VALUE 数据类型：String 轮询间隔与上次轮询时间
+    pub const OAUTH2_DEVICE_POLL: &str = "oauth2_device_poll";
+
+    /// 租户有效性校验结果的缓存
+    ///
+    /// KEY 格式：valid_tenant:{id}
+    /// VALUE 数据类型：String 是否有效
+    pub const VALID_TENANT: &str = "valid_tenant";
+
+    /// 全部开启状态的字典数据的缓存
+    ///
+    /// KEY 格式：dict_data_simple_list
+    /// VALUE 数据类型：String 字典数据列表
+    pub const DICT_DATA_SIMPLE_LIST: &str = "dict_data_simple_list";
+
     /// 站内信模版的缓存
     ///
     /// KEY 格式：notify_template:{code}
@@ -394,6 +551,8 @@ pub enum LoginResultEnum {
     CaptchaNotFound = 30,
     /// 图片验证码不正确
     CaptchaCodeError = 31,
+    /// 刷新令牌已被轮换
+    RefreshTokenReused = 40,
 }
 
 impl LoginResultEnum {
@@ -402,6 +561,18 @@ impl LoginResultEnum {
         *self as i32
     }
 
+    /// 获取结果名
+    pub fn name(&self) -> &'static str {
+        match self {
+            LoginResultEnum::Success => "成功",
+            LoginResultEnum::BadCredentials => "账号或密码不正确",
+            LoginResultEnum::UserDisabled => "用户被禁用",
+            LoginResultEnum::CaptchaNotFound => "图片验证码不存在",
+            LoginResultEnum::CaptchaCodeError => "图片验证码不正确",
+            LoginResultEnum::RefreshTokenReused => "刷新令牌已被轮换",
+        }
+    }
+
     /// 根据值查找对应的枚举 variant
     pub fn from_result(value: i32) -> Option<LoginResultEnum> {
         match value {
@@ -410,6 +581,7 @@ impl LoginResultEnum {
             20 => Some(LoginResultEnum::UserDisabled),
             30 => Some(LoginResultEnum::CaptchaNotFound),
             31 => Some(LoginResultEnum::CaptchaCodeError),
+            40 => Some(LoginResultEnum::RefreshTokenReused),
             _ => None,
         }
     }
@@ -422,6 +594,7 @@ impl LoginResultEnum {
             LoginResultEnum::UserDisabled,
             LoginResultEnum::CaptchaNotFound,
             LoginResultEnum::CaptchaCodeError,
+            LoginResultEnum::RefreshTokenReused,
         ]
     }
 }
@@ -461,6 +634,84 @@ impl RoleCode {
     }
 }
 
+/// 角色类型枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleTypeEnum {
+    /// 内置角色
+    System = 1,
+    /// 自定义角色
+    Custom = 2,
+}
+
+impl RoleTypeEnum {
+    /// 获取类型值
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 判断是否为内置角色
+    pub fn is_system(value: i32) -> bool {
+        value == RoleTypeEnum::System.value()
+    }
+}
+
+/// 数据范围枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataScopeEnum {
+    /// 全部数据权限
+    All = 1,
+    /// 指定部门数据权限
+    DeptCustom = 2,
+    /// 部门数据权限
+    DeptOnly = 3,
+    /// 部门及以下数据权限
+    DeptAndChild = 4,
+    /// 仅本人数据权限
+    SelfOnly = 5,
+}
+
+impl DataScopeEnum {
+    /// 获取范围值
+    pub fn scope(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 根据范围值获取对应的枚举
+    pub fn from_scope(scope: i32) -> Option<DataScopeEnum> {
+        match scope {
+            1 => Some(DataScopeEnum::All),
+            2 => Some(DataScopeEnum::DeptCustom),
+            3 => Some(DataScopeEnum::DeptOnly),
+            4 => Some(DataScopeEnum::DeptAndChild),
+            5 => Some(DataScopeEnum::SelfOnly),
+            _ => None,
+        }
+    }
+}
+impl_array_valuable!(DataScopeEnum, i32, [1, 2, 3, 4, 5]);
+
+/// 通知公告状态枚举，与 `system_notice.status` 对应
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeStatusEnum {
+    /// 已发布
+    Published = 0,
+    /// 草稿
+    Draft = 1,
+}
+
+impl NoticeStatusEnum {
+    /// 获取状态值
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 判断是否为已发布
+    pub fn is_published(value: i32) -> bool {
+        value == NoticeStatusEnum::Published.value()
+    }
+}
+impl_array_valuable!(NoticeStatusEnum, i32, [0, 1]);
+
 /// 菜单类型枚举类
 ///
 /// 对应 Java 中的 cn.iocoder.yudao.module.system.enums.permission.MenuTypeEnum
@@ -1,6 +1,6 @@
 use super::ErrorCode;
 use crate::utils::web::response::ApiResponse;
-use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
+use axum::extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum_valid::ValidRejection;
@@ -39,6 +39,8 @@ pub enum ApiError {
     Path(#[from] PathRejection),
     #[error("Body参数错误: {0}")]
     Json(#[from] JsonRejection),
+    #[error("表单参数错误: {0}")]
+    Form(#[from] FormRejection),
     #[error("参数验证失败: {0}")]
     Validation(String),
     #[error("密码Hash错误: {0}")]
@@ -92,6 +94,7 @@ impl ApiError {
             ApiError::Query(_)
             | ApiError::Path(_)
             | ApiError::Json(_)
+            | ApiError::Form(_)
             | ApiError::Validation(_)
             | ApiError::ValidationError(_) => StatusCode::BAD_REQUEST,
             ApiError::JWT(_)
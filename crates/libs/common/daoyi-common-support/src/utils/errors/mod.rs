@@ -78,6 +78,8 @@ pub mod error_code_constants {
     pub const AUTH_MOBILE_NOT_EXISTS: ErrorCode = ErrorCode::new(1_002_000_007, "手机号不存在");
     pub const AUTH_REGISTER_CAPTCHA_CODE_ERROR: ErrorCode =
         ErrorCode::new(1_002_000_008, "验证码不正确，原因：{}");
+    pub const AUTH_LOGIN_SESSION_LIMIT: ErrorCode =
+        ErrorCode::new(1_002_000_009, "登录失败，同时在线的会话数已达上限 {}");
 
     // ========== 菜单模块 1-002-001-000 ==========
     pub const MENU_NAME_DUPLICATE: ErrorCode =
@@ -213,6 +215,8 @@ pub mod error_code_constants {
         ErrorCode::new(1_002_015_004, "名字为【{}】的租户已存在");
     pub const TENANT_WEBSITE_DUPLICATE: ErrorCode =
         ErrorCode::new(1_002_015_005, "域名为【{}】的租户已存在");
+    pub const TENANT_NOT_SYSTEM: ErrorCode =
+        ErrorCode::new(1_002_015_006, "只有系统租户才能管理租户、租户套餐");
 
     // ========== 租户套餐 1-002-016-000 ==========
     pub const TENANT_PACKAGE_NOT_EXISTS: ErrorCode =
@@ -271,6 +275,22 @@ pub mod error_code_constants {
         ErrorCode::new(1_002_021_001, "redirect_uri 不匹配");
     pub const OAUTH2_GRANT_STATE_MISMATCH: ErrorCode =
         ErrorCode::new(1_002_021_002, "state 不匹配");
+    pub const OAUTH2_GRANT_CODE_VERIFIER_MISMATCH: ErrorCode =
+        ErrorCode::new(1_002_021_003, "code_verifier 不匹配");
+    pub const OAUTH2_GRANT_DEVICE_AUTHORIZATION_PENDING: ErrorCode =
+        ErrorCode::new(1_002_021_004, "用户尚未完成设备授权");
+    pub const OAUTH2_GRANT_DEVICE_SLOW_DOWN: ErrorCode =
+        ErrorCode::new(1_002_021_005, "轮询过于频繁，请降低轮询频率");
+    pub const OAUTH2_GRANT_DEVICE_CODE_EXPIRE: ErrorCode =
+        ErrorCode::new(1_002_021_006, "device_code 不存在或已过期");
+    pub const OAUTH2_GRANT_DEVICE_ACCESS_DENIED: ErrorCode =
+        ErrorCode::new(1_002_021_007, "用户拒绝了设备授权");
+    pub const OAUTH2_GRANT_DEVICE_USER_CODE_NOT_EXISTS: ErrorCode =
+        ErrorCode::new(1_002_021_008, "用户码不存在或已过期");
+    pub const OAUTH2_ACCESS_TOKEN_NOT_EXISTS: ErrorCode =
+        ErrorCode::new(1_002_021_009, "访问令牌不存在或已过期");
+    pub const OAUTH2_SESSION_NOT_EXISTS: ErrorCode =
+        ErrorCode::new(1_002_021_010, "登录会话不存在或已过期");
 
     // ========== OAuth2 授权 1-002-022-000 =========
     pub const OAUTH2_CODE_NOT_EXISTS: ErrorCode = ErrorCode::new(1_002_022_000, "code 不存在");
@@ -300,4 +320,8 @@ pub mod error_code_constants {
     // ========== 站内信发送 1-002-028-000 ==========
     pub const NOTIFY_SEND_TEMPLATE_PARAM_MISS: ErrorCode =
         ErrorCode::new(1_002_028_000, "模板参数({})缺失");
+
+    // ========== INFRA 参数配置 1-001-000-000 ==========
+    pub const CONFIG_NOT_EXISTS: ErrorCode = ErrorCode::new(1_001_000_001, "参数配置不存在");
+    pub const CONFIG_KEY_DUPLICATE: ErrorCode = ErrorCode::new(1_001_000_002, "参数配置 key 重复");
 }
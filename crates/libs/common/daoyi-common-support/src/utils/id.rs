@@ -1,4 +1,6 @@
 use idgenerator::{IdGeneratorOptions, IdInstance};
+use rand::Rng;
+use rand::distr::Alphanumeric;
 use sea_orm::prelude::Date;
 
 pub fn init() -> anyhow::Result<()> {
@@ -39,3 +41,20 @@ pub fn generate_sms_code(begin_code: i32, end_code: i32) -> String {
     // 格式化为固定长度的字符串，不足位数前面补0
     format!("{:0width$}", random_number, width = code_length)
 }
+
+// 生成指定长度的随机字母数字字符串，用于客户端密钥等
+pub fn generate_secret(length: usize) -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+// 从指定字符集中生成指定长度的随机字符串，用于设备授权的用户码等
+pub fn generate_code(charset: &[u8], length: usize) -> String {
+    let mut rng = rand::rng();
+    (0..length)
+        .map(|_| charset[rng.random_range(0..charset.len())] as char)
+        .collect()
+}
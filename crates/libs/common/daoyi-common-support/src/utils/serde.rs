@@ -23,6 +23,21 @@ where
     }
 }
 
+// 反序列化可选数字，兼容字符串和数字，空字符串视为 None
+pub fn deserializer_option_number<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    match Option::<StringOrNumber<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrNumber::String(s)) if s.is_empty() => Ok(None),
+        Some(StringOrNumber::String(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+        Some(StringOrNumber::Number(n)) => Ok(Some(n)),
+    }
+}
+
 // 序列化函数
 pub fn serialize_hashmap<S>(
     hashmap: &HashMap<String, String>,
@@ -66,7 +81,7 @@ pub mod datetime_format {
     use sea_orm::prelude::DateTime;
     use serde::{Deserialize, Deserializer, Serializer};
 
-    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    pub const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
     pub fn serialize<S>(date: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -85,6 +100,35 @@ pub mod datetime_format {
     }
 }
 
+pub mod option_datetime_format {
+    use super::datetime_format::FORMAT;
+    use sea_orm::prelude::DateTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &Option<DateTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => serializer.serialize_str(&format!("{}", date.format(FORMAT))),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    // 空字符串视为 None，便于前端直接传空的查询条件
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) if !s.is_empty() => DateTime::parse_from_str(&s, FORMAT)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
 pub fn deserialize_human_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
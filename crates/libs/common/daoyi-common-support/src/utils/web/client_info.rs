@@ -0,0 +1,102 @@
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::header;
+use axum::http::request::Parts;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use woothee::parser::Parser;
+
+/// 请求编号的请求头，由服务端为每个请求生成，用作链路追踪编号
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// User-Agent 的最大长度，超出部分截断，与数据库字段长度一致
+const USER_AGENT_MAX_LENGTH: usize = 512;
+/// woothee 无法识别时返回的值
+const UNKNOWN: &str = "UNKNOWN";
+
+/// 请求的客户端信息，用于记录登录会话、登录日志等
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// 客户端 IP，即连接的对端地址
+    pub ip: String,
+    /// User-Agent 请求头，不存在时为空字符串
+    pub user_agent: String,
+    /// 链路追踪编号，即请求编号，不存在时为空字符串
+    pub trace_id: String,
+}
+
+impl<S> FromRequestParts<S> for ClientInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let ip = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+            .unwrap_or_default();
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .chars()
+            .take(USER_AGENT_MAX_LENGTH)
+            .collect();
+        let trace_id = parts
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        Ok(Self {
+            ip,
+            user_agent,
+            trace_id,
+        })
+    }
+}
+
+/// 解析后的 User-Agent，用于展示登录设备
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserAgent {
+    /// 浏览器名称与版本，例如 Chrome 120.0.0.0
+    pub browser: Option<String>,
+    /// 操作系统名称，例如 Windows 10
+    pub os: Option<String>,
+}
+
+impl UserAgent {
+    pub fn parse(user_agent: &str) -> Self {
+        let Some(result) = Parser::new().parse(user_agent) else {
+            return Self::default();
+        };
+        let known = |value: &str| !value.is_empty() && value != UNKNOWN;
+        let browser = Some(result.name).filter(|name| known(name)).map(|name| {
+            if known(result.version) {
+                format!("{name} {}", result.version)
+            } else {
+                name.to_string()
+            }
+        });
+        Self {
+            browser,
+            os: Some(result.os).filter(|os| known(os)).map(String::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_agent() {
+        let user_agent = UserAgent::parse(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+        );
+        assert_eq!(user_agent.browser.as_deref(), Some("Chrome 120.0.0.0"));
+        assert_eq!(user_agent.os.as_deref(), Some("Windows 10"));
+        assert_eq!(UserAgent::parse(""), UserAgent::default());
+    }
+}
@@ -0,0 +1,16 @@
+use crate::utils::errors::error::ApiError;
+use axum::extract::FromRequest;
+use axum_valid::HasValidate;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, FromRequest)]
+#[from_request(via(axum::extract::Form), rejection(ApiError))]
+pub struct Form<T>(pub T);
+
+impl<T> HasValidate for Form<T> {
+    type Validate = T;
+
+    fn get_validate(&self) -> &Self::Validate {
+        &self.0
+    }
+}
@@ -1,3 +1,5 @@
+pub mod client_info;
+pub mod form;
 pub mod json;
 pub mod path;
 pub mod query;
@@ -1,6 +1,12 @@
 use crate::utils::{
     errors::error::ApiError,
-    web::{json::Json, path::Path, query::Query},
+    web::{
+        form::Form,
+        json::Json,
+        path::Path,
+        query::Query,
+        validation::{DictValidate, validate_dict_checks},
+    },
 };
 use axum::extract::{FromRequest, FromRequestParts, Request};
 use axum::http::request::Parts;
@@ -21,6 +27,15 @@ pub struct ValidPath<T>(pub T);
 #[derive(Debug, Clone, Default)]
 pub struct ValidJson<T>(pub T);
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct ValidForm<T>(pub T);
+
+/// 在 ValidJson 的基础上，同步校验通过后异步校验请求参数中的字典值，请求参数需要实现 DictValidate
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct ValidDictJson<T>(pub T);
+
 macro_rules! impl_from_request {
     ($name:ident, $wrapper: ident, FromRequestParts) => {
         impl<S, T> FromRequestParts<S> for $name<T>
@@ -56,6 +71,22 @@ macro_rules! impl_from_request {
 impl_from_request!(ValidQuery, Query, FromRequestParts);
 impl_from_request!(ValidPath, Path, FromRequestParts);
 impl_from_request!(ValidJson, Json, FromRequest);
+impl_from_request!(ValidForm, Form, FromRequest);
+
+impl<S, T> FromRequest<S> for ValidDictJson<T>
+where
+    S: Send + Sync,
+    T: DictValidate + Send,
+    Valid<Json<T>>: FromRequest<S, Rejection = ApiError>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = Valid::from_request(request, state).await?.0.0;
+        validate_dict_checks(value.dict_checks()).await?;
+        Ok(ValidDictJson(value))
+    }
+}
 
 // impl<S, T> FromRequestParts<S> for ValidQuery<T>
 // where
@@ -1,7 +1,11 @@
+use crate::utils::enumeration::OAuth2GrantTypeEnum;
+use crate::utils::errors::error::ApiError;
 use regex::Regex;
 use std::borrow::Cow;
 use std::cell::LazyCell;
 use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{LazyLock, OnceLock};
 use validator::ValidationError;
 
 const MOBILE_PHONE_REGEX: LazyCell<Regex> = LazyCell::new(|| {
@@ -25,6 +29,68 @@ pub fn validate_username(username: &str) -> Result<(), ValidationError> {
     }
 }
 
+static DICT_TYPE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9_]*$").expect("Failed to compile dict type regex"));
+pub fn validate_dict_type(dict_type: &str) -> Result<(), ValidationError> {
+    if !DICT_TYPE_REGEX.is_match(dict_type) {
+        return Err(build_validation_error(
+            "字典类型必须以字母开头，且只能为（小写英文字母、数字、下划线）",
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_oauth2_grant_types(grant_types: &[String]) -> Result<(), ValidationError> {
+    if grant_types
+        .iter()
+        .all(|grant_type| OAuth2GrantTypeEnum::from_grant_type(grant_type).is_some())
+    {
+        Ok(())
+    } else {
+        Err(build_validation_error("授权类型不正确"))
+    }
+}
+
+pub fn validate_json(value: &str) -> Result<(), ValidationError> {
+    if serde_json::from_str::<serde_json::Value>(value).is_ok() {
+        Ok(())
+    } else {
+        Err(build_validation_error("必须是 JSON 格式"))
+    }
+}
+
+/// 字典数据的异步校验函数，参数为字典类型、字典值
+pub type DictDataValidator =
+    fn(&'static str, String) -> Pin<Box<dyn Future<Output = Result<(), ApiError>> + Send>>;
+
+static DICT_DATA_VALIDATOR: OnceLock<DictDataValidator> = OnceLock::new();
+
+/// 注册字典数据的异步校验函数，应用启动时调用一次
+pub fn set_dict_data_validator(validator: DictDataValidator) {
+    let _ = DICT_DATA_VALIDATOR.set(validator);
+}
+
+/// 需要校验字典值的请求参数
+///
+/// 使用 ValidDictJson 提取器时，在同步校验通过后，异步校验返回的字典值存在且处于开启状态
+pub trait DictValidate {
+    /// 需要校验的字典类型与字典值
+    fn dict_checks(&self) -> Vec<(&'static str, String)>;
+}
+
+/// 校验字典值存在且处于开启状态，未注册校验函数时跳过
+pub(crate) async fn validate_dict_checks(
+    checks: Vec<(&'static str, String)>,
+) -> Result<(), ApiError> {
+    let Some(validator) = DICT_DATA_VALIDATOR.get() else {
+        return Ok(());
+    };
+    for (dict_type, value) in checks {
+        validator(dict_type, value).await?;
+    }
+    Ok(())
+}
+
 pub fn build_validation_error(message: &'static str) -> ValidationError {
     ValidationError {
         code: Cow::from("invalid"),
@@ -1,8 +1,15 @@
 use crate::app::{auth::Principal, database, redis_util};
+use crate::config::AuthConfig;
+use chrono::{Local, TimeDelta};
+use daoyi_common_support::support::orm::update_with_common_fields;
 use daoyi_common_support::utils;
+use daoyi_common_support::utils::enumeration::redis_key_constants::{
+    ACCESS_TOKEN, OAUTH2_ACCESS_TOKEN,
+};
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
-use daoyi_entities_system::entity::{prelude::SystemOauth2AccessToken, system_oauth2_access_token};
-use sea_orm::prelude::*;
+use daoyi_entities_system::entity::prelude::{SystemOauth2AccessToken, SystemOauth2Client};
+use daoyi_entities_system::entity::{system_oauth2_access_token, system_oauth2_client};
+use sea_orm::{ActiveModelTrait, IntoActiveModel, Set, prelude::*};
 use std::sync::LazyLock;
 
 static DB_AUTH: LazyLock<DbAuth> = LazyLock::new(|| DbAuth);
@@ -15,10 +22,18 @@ impl super::Auth for DbAuth {
     }
 
     async fn decode(&self, token: &str) -> ApiResult<Principal> {
-        let cache_key = format!("access_token:{}", token);
+        let config = crate::config::get().await;
+        let auth_config = config.auth();
+        let cache_key = format!("{ACCESS_TOKEN}:{token}");
+        // 缓存与访问令牌同时过期，进入续期窗口后需要查询数据库续期
         let cached = redis_util::cache_get_json::<Principal>(&cache_key).await?;
-        if cached.is_some() {
-            return Ok(cached.unwrap());
+        if let Some(principal) = cached {
+            let renewable = principal.expires_time.is_none_or(|expires_time| {
+                auth_config.auto_renew() && Self::in_renew_window(&expires_time, auth_config)
+            });
+            if !renewable {
+                return Ok(principal);
+            }
         }
         let at = SystemOauth2AccessToken::find()
             .filter(system_oauth2_access_token::Column::AccessToken.eq(token))
@@ -26,24 +41,180 @@ impl super::Auth for DbAuth {
             .await?
             .ok_or_else(|| ApiError::Unauthenticated(String::from("访问令牌不存在")))?;
         if utils::is_expired(&at.expires_time)? {
-            if crate::config::get().await.auth().auto_renew() {
-                // 自动续期,即为永不过期，但是数据暂时不改变，前端拿到的Token看起来还是保持过期状态
-            } else {
-                return Err(ApiError::Unauthenticated(String::from("访问令牌已过期")));
-            }
+            return Err(ApiError::Unauthenticated(String::from("访问令牌已过期")));
         }
+        let at = if auth_config.auto_renew() {
+            Self::renew(at, auth_config).await?
+        } else {
+            at
+        };
         let principal = Principal {
             tenant_id: at.tenant_id,
             user_id: at.user_id,
             user_type: utils::enumeration::UserTypeEnum::from_value(at.user_type)
                 .ok_or_else(|| ApiError::Unauthenticated(String::from("用户类型不存在")))?,
             token: String::from(token),
+            expires_time: Some(at.expires_time),
         };
-        redis_util::cache_set_json(&cache_key, &principal).await?;
+        let remaining_seconds = Self::remaining_seconds(&at.expires_time);
+        if remaining_seconds > 0 {
+            redis_util::cache_set_json_ex(&cache_key, &principal, remaining_seconds).await?;
+        }
         Ok(principal)
     }
 }
 
+impl DbAuth {
+    /// 访问令牌的剩余有效期是否小于续期窗口
+    fn in_renew_window(expires_time: &DateTime, auth_config: &AuthConfig) -> bool {
+        Self::remaining_seconds(expires_time) < auth_config.renew_window().as_secs()
+    }
+
+    /// 计算续期后的过期时间：进入续期窗口时，从当前时间延长访问令牌的有效期，不超过会话的最长有效期
+    ///
+    /// 未进入续期窗口，或已达到会话的最长有效期无法延长时，返回 None
+    fn renewed_expires_time(
+        expires_time: DateTime,
+        create_time: DateTime,
+        now: DateTime,
+        validity: TimeDelta,
+        renew_window: TimeDelta,
+        max_session_lifetime: TimeDelta,
+    ) -> Option<DateTime> {
+        if expires_time - now >= renew_window {
+            return None;
+        }
+        let renewed = (now + validity).min(create_time + max_session_lifetime);
+        (renewed > expires_time).then_some(renewed)
+    }
+
+    fn remaining_seconds(expires_time: &DateTime) -> u64 {
+        (*expires_time - Local::now().naive_local())
+            .num_seconds()
+            .max(0) as u64
+    }
+
+    /// 滑动续期：进入续期窗口时，按照客户端的访问令牌有效期延长过期时间，不超过会话的最长有效期
+    async fn renew(
+        at: system_oauth2_access_token::Model,
+        auth_config: &AuthConfig,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        if !Self::in_renew_window(&at.expires_time, auth_config) {
+            return Ok(at);
+        }
+        let db = database::get()?;
+        let client = SystemOauth2Client::find()
+            .filter(system_oauth2_client::Column::ClientId.eq(&at.client_id))
+            .filter(system_oauth2_client::Column::Deleted.eq(0))
+            .one(db)
+            .await?;
+        let Some(client) = client else {
+            return Ok(at);
+        };
+        let renew_window =
+            TimeDelta::from_std(auth_config.renew_window()).map_err(anyhow::Error::from)?;
+        let max_session_lifetime =
+            TimeDelta::from_std(auth_config.max_session_lifetime()).map_err(anyhow::Error::from)?;
+        // 已达到会话的最长有效期，不再续期
+        let Some(expires_time) = Self::renewed_expires_time(
+            at.expires_time,
+            at.create_time,
+            Local::now().naive_local(),
+            TimeDelta::seconds(client.access_token_validity_seconds as i64),
+            renew_window,
+            max_session_lifetime,
+        ) else {
+            return Ok(at);
+        };
+        let updater = Some(at.user_id.to_string());
+        let mut active_model = at.into_active_model();
+        active_model.expires_time = Set(expires_time);
+        let model = update_with_common_fields(active_model, updater)
+            .await?
+            .update(db)
+            .await?;
+        // 同步访问令牌的缓存，使其与新的过期时间一致
+        redis_util::cache_set_json_ex(
+            &format!("{OAUTH2_ACCESS_TOKEN}:{}", model.access_token),
+            &model,
+            Self::remaining_seconds(&model.expires_time),
+        )
+        .await?;
+        tracing::debug!(
+            "访问令牌续期，用户编号: {}，过期时间: {}",
+            model.user_id,
+            model.expires_time
+        );
+        Ok(model)
+    }
+}
+
 pub fn get_default_db_auth() -> &'static DbAuth {
     &DB_AUTH
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, min: u32) -> DateTime {
+        chrono::NaiveDate::from_ymd_opt(2026, 10, 1)
+            .and_then(|date| date.and_hms_opt(hour, min, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_renewed_expires_time() {
+        let validity = TimeDelta::minutes(30);
+        let renew_window = TimeDelta::minutes(10);
+        let max_session_lifetime = TimeDelta::hours(8);
+        // 进入续期窗口，从当前时间延长有效期
+        assert_eq!(
+            DbAuth::renewed_expires_time(
+                time(10, 30),
+                time(10, 0),
+                time(10, 25),
+                validity,
+                renew_window,
+                max_session_lifetime,
+            ),
+            Some(time(10, 55))
+        );
+        // 未进入续期窗口，不续期
+        assert_eq!(
+            DbAuth::renewed_expires_time(
+                time(10, 30),
+                time(10, 0),
+                time(10, 15),
+                validity,
+                renew_window,
+                max_session_lifetime,
+            ),
+            None
+        );
+        // 续期后的过期时间不超过会话的最长有效期
+        assert_eq!(
+            DbAuth::renewed_expires_time(
+                time(17, 50),
+                time(10, 0),
+                time(17, 45),
+                validity,
+                renew_window,
+                max_session_lifetime,
+            ),
+            Some(time(18, 0))
+        );
+        // 已达到会话的最长有效期，不再续期
+        assert_eq!(
+            DbAuth::renewed_expires_time(
+                time(18, 0),
+                time(10, 0),
+                time(17, 55),
+                validity,
+                renew_window,
+                max_session_lifetime,
+            ),
+            None
+        );
+    }
+}
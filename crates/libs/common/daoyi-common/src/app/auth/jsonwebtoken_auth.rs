@@ -1,4 +1,5 @@
 use super::Principal;
+use chrono::{DateTime, Local};
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
@@ -116,6 +117,8 @@ impl super::Auth for JWT {
                 .parse()
                 .map_err(|_| ApiError::InvalidToken)?,
             token: String::from(token),
+            expires_time: DateTime::from_timestamp(claims.exp as i64, 0)
+                .map(|time| time.with_timezone(&Local).naive_local()),
         };
         Ok(principal)
     }
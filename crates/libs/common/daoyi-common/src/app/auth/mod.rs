@@ -1,16 +1,23 @@
 use daoyi_common_support::utils::enumeration::UserTypeEnum;
 use daoyi_common_support::utils::errors::error::ApiResult;
+use sea_orm::prelude::DateTime;
 use serde::{Deserialize, Serialize};
 
 pub mod db_auth;
 pub mod jsonwebtoken_auth;
 
+/// 访问令牌过期时间的响应头，访问令牌续期后，前端据此获得新的过期时间
+pub const TOKEN_EXPIRES_TIME_HEADER: &str = "x-token-expires-time";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Principal {
     pub tenant_id: i64,
     pub user_id: i64,
     pub user_type: UserTypeEnum,
     pub token: String,
+    /// 访问令牌的过期时间
+    #[serde(default)]
+    pub expires_time: Option<DateTime>,
 }
 
 pub trait Auth {
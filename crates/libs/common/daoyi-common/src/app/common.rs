@@ -1,5 +1,6 @@
 use daoyi_common_support::utils::serde::deserializer_number;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
 const DEFAULT_PAGE: u64 = 1;
@@ -15,7 +16,15 @@ pub struct PaginationParams {
     pub size: u64,
 }
 
-#[derive(Debug, Serialize)]
+/// 编号请求参数
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Validate)]
+pub struct IdParams {
+    #[serde(deserialize_with = "deserializer_number")]
+    pub id: i64,
+}
+
+/// 分页结果
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Page<T> {
     pub total: u64,
@@ -1,15 +1,19 @@
 use crate::app::{
     TenantContextHolder,
-    auth::{Auth, db_auth::get_default_db_auth, jsonwebtoken_auth::get_default_jwt},
+    auth::{
+        Auth, Principal, TOKEN_EXPIRES_TIME_HEADER, db_auth::get_default_db_auth,
+        jsonwebtoken_auth::get_default_jwt,
+    },
     utils::path_any_matches,
 };
 use crate::config;
 use axum::{
     body::Body,
-    http::{Request, Response, header},
+    http::{HeaderValue, Request, Response, header},
+    middleware::Next,
     response::IntoResponse,
 };
-use daoyi_common_support::utils::{enumeration, errors::error::ApiError};
+use daoyi_common_support::utils::{enumeration, errors::error::ApiError, serde::datetime_format};
 use std::pin::Pin;
 use std::sync::LazyLock;
 use tower_http::auth::{AsyncAuthorizeRequest, AsyncRequireAuthorizationLayer};
@@ -35,6 +39,15 @@ impl AsyncAuthorizeRequest<Body> for JWTAuth {
             let c = config::get().await;
             let auth_config = c.auth();
             let ignore_urls = auth_config.ignore_urls();
+            // 忽略认证的地址可能使用其它认证方式，例如 OAuth2 客户端的 Basic 认证
+            let bearer = request
+                .headers()
+                .get(header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("Bearer "));
+            if !bearer && path_any_matches(&ignore_urls, request.uri().path())? {
+                return Ok(request);
+            }
             let token = request
                 .headers()
                 .get(header::AUTHORIZATION)
@@ -55,9 +68,6 @@ impl AsyncAuthorizeRequest<Body> for JWTAuth {
                     Ok(token)
                 })
                 .transpose()?;
-            if token.is_none() && path_any_matches(&ignore_urls, request.uri().path())? {
-                return Ok(request);
-            }
             let token = token.ok_or_else(|| {
                 ApiError::Unauthenticated(String::from("Authorization请求头必须存在"))
             })?;
@@ -78,6 +88,24 @@ impl AsyncAuthorizeRequest<Body> for JWTAuth {
     }
 }
 
+/// 在响应头中返回访问令牌的过期时间，访问令牌续期后前端据此更新本地的过期时间
+pub async fn token_expires_time_header(request: Request<Body>, next: Next) -> Response<Body> {
+    let expires_time = request
+        .extensions()
+        .get::<Principal>()
+        .and_then(|principal| principal.expires_time);
+    let mut response = next.run(request).await;
+    if let Some(expires_time) = expires_time {
+        let value = expires_time.format(datetime_format::FORMAT).to_string();
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response
+                .headers_mut()
+                .insert(TOKEN_EXPIRES_TIME_HEADER, value);
+        }
+    }
+    response
+}
+
 pub fn get_auth_layer() -> &'static AsyncRequireAuthorizationLayer<JWTAuth> {
     &AUTH_LAYER
 }
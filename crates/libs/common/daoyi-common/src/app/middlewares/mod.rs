@@ -1,4 +1,5 @@
 pub mod auth_middleware;
+pub mod operate_log_middleware;
 pub mod tenant_middleware;
 
 pub mod permission_middleware;
@@ -0,0 +1,280 @@
+use crate::app::{TenantContextHolder, auth::Principal, database};
+use axum::body::{Body, HttpBody};
+use axum::extract::ConnectInfo;
+use axum::http::{Request, Response, header};
+use daoyi_common_support::support::operate_log::OperateLogContext;
+use daoyi_common_support::support::orm::create_with_common_fields;
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::web::client_info::REQUEST_ID_HEADER;
+use daoyi_entities_system::entity::system_operate_log;
+use sea_orm::{ActiveModelTrait, Set};
+use serde_json::Value;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// 解析请求与响应的 JSON 时，允许的最大长度，超出时不解析
+const MAX_INSPECT_BODY_SIZE: u64 = 1024 * 1024;
+/// User-Agent 的最大长度，与数据库字段长度一致
+const USER_AGENT_MAX_LENGTH: usize = 512;
+/// 请求地址的最大长度，与数据库字段长度一致
+const REQUEST_URL_MAX_LENGTH: usize = 255;
+
+/// 操作日志的中间件，为路由声明操作，请求完成后记录操作日志
+///
+/// 业务编号依次取自操作日志上下文、`id` 查询参数、请求体的 `id` 字段、响应数据中的数字，
+/// 都没有时为 0。操作结果以响应码为准，非 0 时记录失败，并将失败原因写入拓展字段
+///
+/// ```ignore
+/// Router::new().route(
+///     "/create",
+///     routing::post(create_user).layer(OperateLogLayer::new("SYSTEM 用户", "创建用户")),
+/// )
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OperateLogLayer {
+    /// 操作模块类型
+    r#type: &'static str,
+    /// 操作名
+    sub_type: &'static str,
+}
+
+impl OperateLogLayer {
+    pub fn new(r#type: &'static str, sub_type: &'static str) -> Self {
+        Self { r#type, sub_type }
+    }
+}
+
+impl<S> Layer<S> for OperateLogLayer {
+    type Service = OperateLogMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        OperateLogMiddleware {
+            inner,
+            layer: *self,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperateLogMiddleware<S> {
+    inner: S,
+    layer: OperateLogLayer,
+}
+
+impl<S> Service<Request<Body>> for OperateLogMiddleware<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // 使用已就绪的服务处理请求，克隆的服务留作下次使用
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let layer = self.layer;
+        Box::pin(async move {
+            // 未登录的请求不记录操作日志
+            let Some(principal) = request.extensions().get::<Principal>().cloned() else {
+                return inner.call(request).await;
+            };
+            let metadata = RequestMetadata::from_request(&request);
+            let (parts, body) = request.into_parts();
+            let (body, request_json) = read_json(body, is_json(&parts.headers)).await;
+            let request = Request::from_parts(parts, body);
+            let (response, context) = OperateLogContext::scope(inner.call(request)).await;
+            let response = response?;
+            let (parts, body) = response.into_parts();
+            let (body, response_json) = read_json(body, is_json(&parts.headers)).await;
+            let response = Response::from_parts(parts, body);
+
+            let operate_log = OperateLog {
+                layer,
+                principal,
+                metadata,
+                context,
+                success: response.status().is_success()
+                    && response_json
+                        .as_ref()
+                        .and_then(|json| json.get("code"))
+                        .and_then(Value::as_i64)
+                        .is_none_or(|code| code == 0),
+                request_json,
+                response_json,
+            };
+            if let Err(e) = operate_log.save().await {
+                tracing::error!("记录操作日志失败: {e}");
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// 请求的元数据，在请求交给处理函数前提取
+struct RequestMetadata {
+    tenant: Option<TenantContextHolder>,
+    request_method: String,
+    request_url: String,
+    query_id: Option<i64>,
+    user_ip: Option<String>,
+    user_agent: Option<String>,
+    trace_id: String,
+}
+
+impl RequestMetadata {
+    fn from_request(request: &Request<Body>) -> Self {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        let query_id = request.uri().query().and_then(|query| {
+            query
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(key, _)| *key == "id")
+                .and_then(|(_, value)| value.parse().ok())
+        });
+        Self {
+            tenant: request.extensions().get::<TenantContextHolder>().copied(),
+            request_method: request.method().to_string(),
+            request_url: request
+                .uri()
+                .path_and_query()
+                .map(|path| path.as_str())
+                .unwrap_or_default()
+                .chars()
+                .take(REQUEST_URL_MAX_LENGTH)
+                .collect(),
+            query_id,
+            user_ip: request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string()),
+            user_agent: header(header::USER_AGENT.as_str())
+                .map(|user_agent| user_agent.chars().take(USER_AGENT_MAX_LENGTH).collect()),
+            trace_id: header(REQUEST_ID_HEADER).unwrap_or_default(),
+        }
+    }
+}
+
+struct OperateLog {
+    layer: OperateLogLayer,
+    principal: Principal,
+    metadata: RequestMetadata,
+    context: OperateLogContext,
+    success: bool,
+    request_json: Option<Value>,
+    response_json: Option<Value>,
+}
+
+impl OperateLog {
+    async fn save(self) -> ApiResult<()> {
+        let OperateLog {
+            layer,
+            principal,
+            metadata,
+            mut context,
+            success,
+            request_json,
+            response_json,
+        } = self;
+        let biz_id = context
+            .biz_id
+            .or(metadata.query_id)
+            .or_else(|| {
+                request_json
+                    .as_ref()
+                    .and_then(|json| json_id(json.get("id")))
+            })
+            .or_else(|| {
+                response_json
+                    .as_ref()
+                    .and_then(|json| json_id(json.get("data")))
+            })
+            .unwrap_or_default();
+        if !success {
+            let message = response_json
+                .as_ref()
+                .and_then(|json| json.get("message"))
+                .cloned()
+                .unwrap_or(Value::Null);
+            context.extra.insert(String::from("errorMessage"), message);
+        }
+        let active_model = system_operate_log::ActiveModel {
+            trace_id: Set(metadata.trace_id),
+            user_id: Set(principal.user_id),
+            user_type: Set(principal.user_type.value()),
+            r#type: Set(layer.r#type.to_string()),
+            sub_type: Set(layer.sub_type.to_string()),
+            biz_id: Set(biz_id),
+            action: Set(context.action.unwrap_or_else(|| layer.sub_type.to_string())),
+            success: Set(success),
+            extra: Set(serde_json::to_string(&context.extra)?),
+            request_method: Set(Some(metadata.request_method)),
+            request_url: Set(Some(metadata.request_url)),
+            user_ip: Set(metadata.user_ip),
+            user_agent: Set(metadata.user_agent),
+            ..Default::default()
+        };
+        // 以登录用户的租户为准，忽略租户的请求也记录到用户所在的租户
+        let tenant = metadata
+            .tenant
+            .filter(|tenant| !tenant.ignore())
+            .unwrap_or_else(|| TenantContextHolder::set_tenant_id(principal.tenant_id));
+        create_with_common_fields(active_model, Some(principal.user_id.to_string()), &tenant)
+            .await?
+            .insert(database::get()?)
+            .await?;
+        Ok(())
+    }
+}
+
+fn is_json(headers: &axum::http::HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// 读取 JSON 请求体或响应体，并重新构建 body，长度未知或超出限制时不读取
+async fn read_json(body: Body, json: bool) -> (Body, Option<Value>) {
+    let size = body.size_hint().upper();
+    if !json || size.is_none_or(|size| size > MAX_INSPECT_BODY_SIZE) {
+        return (body, None);
+    }
+    match axum::body::to_bytes(body, MAX_INSPECT_BODY_SIZE as usize).await {
+        Ok(bytes) => {
+            let value = serde_json::from_slice(&bytes).ok();
+            (Body::from(bytes), value)
+        }
+        Err(e) => {
+            tracing::warn!("读取操作日志的请求或响应失败: {e}");
+            (Body::empty(), None)
+        }
+    }
+}
+
+/// 解析业务编号，兼容数字与字符串
+fn json_id(value: Option<&Value>) -> Option<i64> {
+    match value? {
+        Value::Number(number) => number.as_i64(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+    .filter(|id| *id > 0)
+}
@@ -1,5 +1,6 @@
 use crate::app::auth::Principal;
 use crate::app::{database, redis_util};
+use crate::service::TenantApi;
 use axum::body::Body;
 use axum::http::{Request, Response};
 use daoyi_common_support::support::tenant::TenantContextHolder;
@@ -10,6 +11,7 @@ use daoyi_entities_system::entity::prelude::{
 };
 use daoyi_entities_system::entity::{system_menu, system_role, system_role_menu, system_user_role};
 use sea_orm::prelude::*;
+use std::collections::HashSet;
 use std::pin::Pin;
 use std::sync::LazyLock;
 use tower_http::auth::{AsyncAuthorizeRequest, AsyncRequireAuthorizationLayer};
@@ -50,7 +52,7 @@ impl AsyncAuthorizeRequest<Body> for PermissionAuth {
 }
 
 async fn check_permission(
-    _tenant: &TenantContextHolder,
+    tenant: &TenantContextHolder,
     principal: &Principal,
     api_path: &str,
 ) -> ApiResult<()> {
@@ -64,14 +66,26 @@ async fn check_permission(
     if cached.is_some() && cached.unwrap() {
         return Ok(());
     }
-    let menus = SystemMenu::find()
-        .filter(system_menu::Column::PermApis.contains(api_path))
+    let menu_ids = SystemMenu::find()
+        .filter(system_menu::Column::PermApis.contains(perm_api_pattern(api_path)))
+        .filter(system_menu::Column::Deleted.eq(0))
         .all(database::get()?)
-        .await?;
-    if menus.is_empty() {
+        .await?
+        .into_iter()
+        .map(|menu| menu.id)
+        .collect::<Vec<_>>();
+    if menu_ids.is_empty() {
         redis_util::cache_set(cache_key.as_ref(), true).await?;
         return Ok(());
     }
+    // 租户用户只能访问租户套餐内的菜单
+    let package_menu_ids = TenantApi.get_tenant_menu_ids(tenant).await?;
+    let menu_ids = filter_package_menu_ids(menu_ids, package_menu_ids.as_ref());
+    if menu_ids.is_empty() {
+        return Err(ApiError::Unauthenticated(format!(
+            "接口[{api_path}]没有访问权限"
+        )));
+    }
     let user_roles = SystemUserRole::find()
         .filter(system_user_role::Column::UserId.eq(principal.user_id))
         .all(database::get()?)
@@ -82,7 +96,6 @@ async fn check_permission(
         )));
     }
     let role_ids: Vec<i64> = user_roles.iter().map(|r| r.role_id).collect();
-    let menu_ids: Vec<i64> = menus.iter().map(|menu| menu.id).collect();
     let role_menus = SystemRoleMenu::find()
         .filter(system_role_menu::Column::MenuId.is_in(menu_ids))
         .all(database::get()?)
@@ -108,6 +121,58 @@ async fn check_permission(
         "接口[{api_path}]没有访问权限"
     )))
 }
+
+/// 菜单授权接口的匹配模式，perm_apis 为 JSON 数组，带上引号完整匹配数组中的接口，
+/// 避免 `/system/user/update` 匹配到 `/system/user/update-status`
+fn perm_api_pattern(api_path: &str) -> String {
+    format!("\"{api_path}\"")
+}
+
+/// 过滤出租户套餐内的菜单编号，套餐菜单为 None 时不限制
+fn filter_package_menu_ids(
+    menu_ids: Vec<i64>,
+    package_menu_ids: Option<&HashSet<i64>>,
+) -> Vec<i64> {
+    match package_menu_ids {
+        Some(package_menu_ids) => menu_ids
+            .into_iter()
+            .filter(|menu_id| package_menu_ids.contains(menu_id))
+            .collect(),
+        None => menu_ids,
+    }
+}
+
 pub fn get_permission_layer() -> &'static AsyncRequireAuthorizationLayer<PermissionAuth> {
     &PERMISSION_LAYER
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perm_api_pattern() {
+        let perm_apis = r#"["/system/admin-api/system/user/update-status"]"#;
+        assert!(perm_apis.contains(&perm_api_pattern(
+            "/system/admin-api/system/user/update-status"
+        )));
+        assert!(!perm_apis.contains(&perm_api_pattern("/system/admin-api/system/user/update")));
+    }
+
+    #[test]
+    fn test_filter_package_menu_ids() {
+        // 系统租户或忽略租户时不限制
+        assert_eq!(
+            filter_package_menu_ids(vec![1001, 1002], None),
+            vec![1001, 1002]
+        );
+        // 租户只能使用套餐内的菜单
+        let package_menu_ids = HashSet::from([1001]);
+        assert_eq!(
+            filter_package_menu_ids(vec![1001, 1002], Some(&package_menu_ids)),
+            vec![1001]
+        );
+        // 接口的菜单都不在套餐内时，没有可用的菜单，拒绝访问
+        assert!(filter_package_menu_ids(vec![1002], Some(&package_menu_ids)).is_empty());
+    }
+}
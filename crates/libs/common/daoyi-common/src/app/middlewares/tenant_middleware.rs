@@ -4,13 +4,14 @@ use crate::config;
 use axum::body::Body;
 use axum::http::{Request, Response};
 use daoyi_common_support::utils::{
-    enumeration,
+    enumeration::{self, redis_key_constants},
     errors::{
         TENANT_DISABLE, TENANT_EXPIRE, TENANT_NOT_EXISTS,
         error::{ApiError, ApiResult},
     },
 };
 use daoyi_entities_system::entity::prelude::SystemTenant;
+use daoyi_entities_system::entity::system_tenant;
 use sea_orm::prelude::*;
 use std::pin::Pin;
 use std::sync::LazyLock;
@@ -84,14 +85,17 @@ impl AsyncAuthorizeRequest<Body> for TenantAuth {
 }
 
 async fn valid_tenant(tenant_id: i64) -> ApiResult<()> {
-    let cache_key = &format!("valid_tenant:{}", tenant_id);
+    let cache_key = &format!("{}:{}", redis_key_constants::VALID_TENANT, tenant_id);
     if let Some(t) = redis_util::cache_get::<bool>(cache_key).await? {
         if t {
             return Ok(());
         }
     }
     let db = database::get()?;
-    let tenant = SystemTenant::find_by_id(tenant_id).one(db).await?;
+    let tenant = SystemTenant::find_by_id(tenant_id)
+        .filter(system_tenant::Column::Deleted.eq(0))
+        .one(db)
+        .await?;
     if tenant.is_none() {
         return Err(ApiError::BizCode(TENANT_NOT_EXISTS));
     }
@@ -9,9 +9,12 @@ pub mod redis_util;
 pub mod server;
 
 use crate::config;
+use crate::service::DictApi;
 use axum::Router;
 pub use daoyi_common_support::support::tenant::TenantContextHolder;
 use daoyi_common_support::utils;
+use daoyi_common_support::utils::web::validation;
+pub use middlewares::operate_log_middleware::OperateLogLayer;
 
 #[derive(Clone)]
 pub struct AppState {}
@@ -29,6 +32,9 @@ pub async fn run(router: Router<AppState>) -> anyhow::Result<()> {
     config::refresh().await?;
     redis_util::init_redis().await?;
     database::init_db().await?;
+    validation::set_dict_data_validator(|dict_type, value| {
+        Box::pin(async move { DictApi.validate_dict_data(dict_type, &value).await })
+    });
     let state = AppState::new();
 
     server::Server.start(state, router).await
@@ -78,14 +78,14 @@ where
     V: Serialize,
 {
     let json_str = serde_json::to_string(value)?;
-    cache_set(key_generator(key).await.as_ref(), json_str).await
+    cache_set(key, json_str).await
 }
 pub async fn cache_set_json_ex<V>(key: &str, value: &V, expire_seconds: u64) -> anyhow::Result<()>
 where
     V: Serialize,
 {
     let json_str = serde_json::to_string(value)?;
-    cache_set_ex(key_generator(key).await.as_ref(), json_str, expire_seconds).await
+    cache_set_ex(key, json_str, expire_seconds).await
 }
 
 pub async fn cache_get<V>(key: &str) -> anyhow::Result<Option<V>>
@@ -111,6 +111,42 @@ where
     Ok(())
 }
 
+/// 删除带前缀的缓存
+pub async fn cache_del(key: &str) -> anyhow::Result<()> {
+    del(key_generator(key).await.as_ref()).await
+}
+
+/// 删除带前缀的缓存，返回缓存是否存在并被当前调用删除
+///
+/// 并发删除同一个键时只有一个调用返回 true，可用于一次性凭证的消费
+pub async fn cache_remove(key: &str) -> anyhow::Result<bool> {
+    let pool = get_pool()?;
+    let mut conn = pool.get().await?;
+    let removed: usize = conn.del(key_generator(key).await).await?;
+    Ok(removed > 0)
+}
+
+/// 按模式删除带前缀的缓存
+///
+/// # 参数
+/// * `pattern` - 键模式，例如 `user_has_permission:1:*`
+pub async fn cache_del_pattern(pattern: &str) -> anyhow::Result<()> {
+    let pattern = key_generator(pattern).await;
+    let pool = get_pool()?;
+    let mut conn = pool.get().await?;
+    let mut keys = Vec::<String>::new();
+    {
+        let mut iter = conn.scan_match::<_, String>(&pattern).await?;
+        while let Some(key) = iter.next_item().await {
+            keys.push(key);
+        }
+    }
+    if !keys.is_empty() {
+        let _: () = conn.del(keys).await?;
+    }
+    Ok(())
+}
+
 /// 获取Redis中指定键的值
 ///
 /// # 参数
@@ -3,12 +3,14 @@ use crate::app::{
     auth::Principal,
     latency::LatencyOnResponse,
     middlewares::{
-        auth_middleware::get_auth_layer, permission_middleware::get_permission_layer,
+        auth_middleware::{get_auth_layer, token_expires_time_header},
+        permission_middleware::get_permission_layer,
         tenant_middleware::get_tenant_layer,
     },
     openapi,
 };
 use crate::config;
+use axum::http::{HeaderName, Request as HttpRequest};
 use axum::{
     Router,
     extract::Request,
@@ -16,17 +18,29 @@ use axum::{
 };
 use bytesize::ByteSize;
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::web::client_info::REQUEST_ID_HEADER;
 use std::{net::SocketAddr, time::Duration};
 use tokio::net::TcpListener;
 use tower_http::{
     cors::{self, CorsLayer},
     normalize_path::NormalizePathLayer,
+    request_id::{MakeRequestId, PropagateRequestIdLayer, RequestId, SetRequestIdLayer},
     timeout::TimeoutLayer,
     trace::TraceLayer,
 };
 
 pub struct Server;
 
+/// 使用 xid 生成请求编号，请求编号同时用作日志的链路追踪编号
+#[derive(Clone, Copy)]
+struct XidRequestId;
+
+impl MakeRequestId for XidRequestId {
+    fn make_request_id<B>(&mut self, _request: &HttpRequest<B>) -> Option<RequestId> {
+        xid::new().to_string().parse().ok().map(RequestId::new)
+    }
+}
+
 impl Server {
     pub async fn start(&self, state: AppState, router: Router<AppState>) -> anyhow::Result<()> {
         let router = self.build_router(state, router);
@@ -56,11 +70,18 @@ impl Server {
             .allow_credentials(false)
             .max_age(Duration::from_secs(3600 * 12));
         let normalize_path = NormalizePathLayer::trim_trailing_slash();
+        let request_id_header = HeaderName::from_static(REQUEST_ID_HEADER);
+        let set_request_id = SetRequestIdLayer::new(request_id_header.clone(), XidRequestId);
+        let propagate_request_id = PropagateRequestIdLayer::new(request_id_header);
         let trace = TraceLayer::new_for_http()
             .make_span_with(|result: &Request| {
                 let method = result.method();
                 let path = result.uri().path();
-                let req_id = xid::new();
+                let req_id = result
+                    .headers()
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or("unknown");
                 let ext = result.extensions();
                 // 尝试从 extensions 中获取客户端 IP
                 let client_ip = ext
@@ -91,6 +112,9 @@ impl Server {
             .layer(timeout)
             .layer(body_limit)
             .layer(trace)
+            .layer(propagate_request_id)
+            .layer(set_request_id)
+            .route_layer(axum::middleware::from_fn(token_expires_time_header))
             .route_layer(get_permission_layer())
             .route_layer(get_auth_layer())
             .route_layer(get_tenant_layer())
@@ -1,5 +1,7 @@
 use daoyi_common_support::utils::enumeration;
+use daoyi_common_support::utils::serde::deserialize_human_duration;
 use serde::Deserialize;
+use std::time::Duration;
 
 #[derive(Debug, Deserialize)]
 pub struct AuthConfig {
@@ -7,12 +9,33 @@ pub struct AuthConfig {
     method: enumeration::AuthMethod,
     #[serde(default = "default_ignore_urls")]
     ignore_urls: Vec<String>,
-    /// Token是否自动续期
+    /// Token是否自动续期，即滑动过期：在续期窗口内使用访问令牌时，延长其过期时间
     #[serde(default = "default_auto_renew")]
     auto_renew: bool,
+    /// 续期窗口，访问令牌的剩余有效期小于该值时续期
+    #[serde(
+        default = "default_renew_window",
+        deserialize_with = "deserialize_human_duration"
+    )]
+    renew_window: Duration,
+    /// 会话的最长有效期，自访问令牌创建起计算，续期不会超过该时间
+    #[serde(
+        default = "default_max_session_lifetime",
+        deserialize_with = "deserialize_human_duration"
+    )]
+    max_session_lifetime: Duration,
     /// 验证码开关
     #[serde(default = "default_captcha")]
     captcha: bool,
+    /// 每个用户在每个客户端同时在线的最大会话数，0 表示不限制
+    #[serde(default)]
+    max_sessions: u64,
+    /// 会话数达到上限时的处理策略
+    #[serde(default = "default_session_limit_policy")]
+    session_limit_policy: enumeration::SessionLimitPolicy,
+    /// 刷新令牌是否轮换，开启后每次刷新都会签发新的刷新令牌
+    #[serde(default)]
+    refresh_token_rotation: bool,
 }
 
 impl Default for AuthConfig {
@@ -21,7 +44,12 @@ impl Default for AuthConfig {
             method: default_method(),
             ignore_urls: default_ignore_urls(),
             auto_renew: default_auto_renew(),
+            renew_window: default_renew_window(),
+            max_session_lifetime: default_max_session_lifetime(),
             captcha: default_captcha(),
+            max_sessions: 0,
+            session_limit_policy: default_session_limit_policy(),
+            refresh_token_rotation: false,
         }
     }
 }
@@ -36,9 +64,24 @@ impl AuthConfig {
     pub fn auto_renew(&self) -> bool {
         self.auto_renew
     }
+    pub fn renew_window(&self) -> Duration {
+        self.renew_window
+    }
+    pub fn max_session_lifetime(&self) -> Duration {
+        self.max_session_lifetime
+    }
     pub fn captcha(&self) -> bool {
         self.captcha
     }
+    pub fn max_sessions(&self) -> u64 {
+        self.max_sessions
+    }
+    pub fn session_limit_policy(&self) -> enumeration::SessionLimitPolicy {
+        self.session_limit_policy
+    }
+    pub fn refresh_token_rotation(&self) -> bool {
+        self.refresh_token_rotation
+    }
 }
 fn default_method() -> enumeration::AuthMethod {
     enumeration::AuthMethod::Jwt
@@ -52,6 +95,18 @@ fn default_auto_renew() -> bool {
     true
 }
 
+fn default_renew_window() -> Duration {
+    Duration::from_secs(10 * 60)
+}
+
+fn default_max_session_lifetime() -> Duration {
+    Duration::from_secs(7 * 24 * 60 * 60)
+}
+
 fn default_captcha() -> bool {
     false
 }
+
+fn default_session_limit_policy() -> enumeration::SessionLimitPolicy {
+    enumeration::SessionLimitPolicy::EvictOldest
+}
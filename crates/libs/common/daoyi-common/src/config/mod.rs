@@ -1,6 +1,7 @@
 mod auth;
 mod database;
 mod nacos;
+mod oidc;
 mod redis;
 mod server;
 mod sms_code;
@@ -12,6 +13,7 @@ use anyhow::{Context, anyhow};
 pub use auth::AuthConfig;
 use config::{Config, FileFormat};
 pub use database::DatabaseConfig;
+pub use oidc::OidcConfig;
 pub use redis::RedisConfig;
 use serde::Deserialize;
 pub use server::ServerConfig;
@@ -37,6 +39,8 @@ pub struct AppConfig {
     redis: RedisConfig,
     #[serde(default = "SmsCodeConfig::default")]
     sms_code: SmsCodeConfig,
+    #[serde(default = "OidcConfig::default")]
+    oidc: OidcConfig,
 }
 
 impl AppConfig {
@@ -91,6 +95,9 @@ impl AppConfig {
     pub fn sms_code(&self) -> &SmsCodeConfig {
         &self.sms_code
     }
+    pub fn oidc(&self) -> &OidcConfig {
+        &self.oidc
+    }
 }
 pub async fn refresh() -> anyhow::Result<()> {
     let new_config = AppConfig::load().await?;
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct OidcConfig {
+    /// 签发者，即 id_token 的 iss，`{issuer}/.well-known/openid-configuration` 为发现端点
+    #[serde(default = "default_issuer")]
+    issuer: String,
+    /// 授权页地址，即用户登录并确认授权的前端页面，未配置时使用授权接口地址
+    authorization_endpoint: Option<String>,
+    /// 设备授权的验证页地址，即用户输入用户码并确认授权的前端页面，未配置时使用验证接口地址
+    device_verification_uri: Option<String>,
+    /// 签名 id_token 的 RSA 私钥文件路径，PEM 格式
+    ///
+    /// 未配置时启动后随机生成，重启或多实例部署时签名密钥会不一致
+    private_key_path: Option<String>,
+}
+
+impl Default for OidcConfig {
+    fn default() -> Self {
+        Self {
+            issuer: default_issuer(),
+            authorization_endpoint: None,
+            device_verification_uri: None,
+            private_key_path: None,
+        }
+    }
+}
+
+impl OidcConfig {
+    pub fn issuer(&self) -> &str {
+        self.issuer.trim_end_matches('/')
+    }
+    pub fn authorization_endpoint(&self) -> String {
+        self.authorization_endpoint
+            .clone()
+            .unwrap_or_else(|| format!("{}/authorize", self.issuer()))
+    }
+    pub fn device_verification_uri(&self) -> String {
+        self.device_verification_uri
+            .clone()
+            .unwrap_or_else(|| format!("{}/device/verify", self.issuer()))
+    }
+    pub fn private_key_path(&self) -> Option<&str> {
+        self.private_key_path.as_deref()
+    }
+}
+
+fn default_issuer() -> String {
+    String::from("http://localhost:8080/system/admin-api/system/oauth2")
+}
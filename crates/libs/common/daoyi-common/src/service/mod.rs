@@ -1,5 +1,9 @@
 mod infra_config_service;
+mod system_dict_service;
+mod system_tenant_service;
 pub use infra_config_service::ConfigApi;
+pub use system_dict_service::DictApi;
+pub use system_tenant_service::TenantApi;
 
 #[macro_export]
 macro_rules! impl_tenant_instance {
@@ -0,0 +1,70 @@
+use crate::app::{database, redis_util};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::enumeration::redis_key_constants::DICT_DATA_SIMPLE_LIST;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{DICT_DATA_NOT_ENABLE, DICT_DATA_NOT_EXISTS};
+use daoyi_entities_system::entity::prelude::SystemDictData;
+use daoyi_entities_system::entity::system_dict_data;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+pub struct DictApi;
+
+impl DictApi {
+    /// 获得全部开启状态的字典数据，优先从缓存读取
+    pub async fn get_simple_dict_data_list(&self) -> ApiResult<Vec<system_dict_data::Model>> {
+        if let Some(list) = redis_util::cache_get_json(DICT_DATA_SIMPLE_LIST).await? {
+            return Ok(list);
+        }
+        let list = SystemDictData::find()
+            .filter(system_dict_data::Column::Deleted.eq(0))
+            .filter(system_dict_data::Column::Status.eq(CommonStatusEnum::Enable.status()))
+            .order_by_asc(system_dict_data::Column::DictType)
+            .order_by_asc(system_dict_data::Column::Sort)
+            .order_by_asc(system_dict_data::Column::Id)
+            .all(database::get()?)
+            .await?;
+        redis_util::cache_set_json(DICT_DATA_SIMPLE_LIST, &list).await?;
+        Ok(list)
+    }
+
+    /// 获得指定字典类型下，字典值对应的字典标签
+    pub async fn get_label(&self, dict_type: &str, value: &str) -> ApiResult<Option<String>> {
+        Ok(self
+            .get_simple_dict_data_list()
+            .await?
+            .into_iter()
+            .find(|data| data.dict_type == dict_type && data.value == value)
+            .map(|data| data.label))
+    }
+
+    /// 校验字典数据存在且处于开启状态
+    pub async fn validate_dict_data(&self, dict_type: &str, value: &str) -> ApiResult<()> {
+        // 优先从开启状态的字典数据缓存中校验
+        if self
+            .get_simple_dict_data_list()
+            .await?
+            .iter()
+            .any(|data| data.dict_type == dict_type && data.value == value)
+        {
+            return Ok(());
+        }
+        // 缓存中不存在时，区分字典数据不存在与未开启
+        let dict_data = SystemDictData::find()
+            .filter(system_dict_data::Column::Deleted.eq(0))
+            .filter(system_dict_data::Column::DictType.eq(dict_type))
+            .filter(system_dict_data::Column::Value.eq(value))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DICT_DATA_NOT_EXISTS))?;
+        Err(ApiError::BizCodeWithArgs(
+            DICT_DATA_NOT_ENABLE,
+            vec![dict_data.label],
+        ))
+    }
+
+    /// 字典类型或字典数据变更后，清空字典数据的缓存
+    pub async fn evict_cache(&self) -> ApiResult<()> {
+        redis_util::cache_del(DICT_DATA_SIMPLE_LIST).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::app::database;
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_entities_system::entity::prelude::{SystemTenant, SystemTenantPackage};
+use daoyi_entities_system::entity::{system_tenant, system_tenant_package};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use std::collections::HashSet;
+
+pub struct TenantApi;
+
+impl TenantApi {
+    /// 系统租户使用的套餐编号，不受套餐菜单限制
+    pub const PACKAGE_ID_SYSTEM: i64 = 0;
+
+    /// 获得租户套餐允许的菜单编号，返回 None 表示不限制（忽略租户或系统租户）
+    pub async fn get_tenant_menu_ids(
+        &self,
+        tenant: &TenantContextHolder,
+    ) -> ApiResult<Option<HashSet<i64>>> {
+        if tenant.ignore() {
+            return Ok(None);
+        }
+        // 1. 获得租户，租户不存在时不允许任何菜单
+        let tenant = SystemTenant::find()
+            .filter(system_tenant::Column::Id.eq(tenant.tenant_id()))
+            .filter(system_tenant::Column::Deleted.eq(0))
+            .one(database::get()?)
+            .await?;
+        let Some(tenant) = tenant else {
+            return Ok(Some(HashSet::new()));
+        };
+        if tenant.package_id == Self::PACKAGE_ID_SYSTEM {
+            return Ok(None);
+        }
+        // 2. 获得租户套餐的菜单，套餐不存在时不允许任何菜单
+        let package = SystemTenantPackage::find()
+            .filter(system_tenant_package::Column::Id.eq(tenant.package_id))
+            .filter(system_tenant_package::Column::Deleted.eq(0))
+            .one(database::get()?)
+            .await?;
+        match package {
+            Some(package) => Ok(Some(serde_json::from_str(&package.menu_ids)?)),
+            None => Ok(Some(HashSet::new())),
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub mod system_mail_log;
 pub mod system_mail_template;
 pub mod system_menu;
 pub mod system_notice;
+pub mod system_notice_read;
 pub mod system_notify_message;
 pub mod system_notify_template;
 pub mod system_oauth2_access_token;
@@ -18,6 +18,7 @@ pub use super::system_mail_template::Entity as SystemMailTemplate;
 pub use super::system_menu::Entity as SystemMenu;
 #[allow(unused_imports)]
 pub use super::system_notice::Entity as SystemNotice;
+pub use super::system_notice_read::Entity as SystemNoticeRead;
 #[allow(unused_imports)]
 pub use super::system_notify_message::Entity as SystemNotifyMessage;
 #[allow(unused_imports)]
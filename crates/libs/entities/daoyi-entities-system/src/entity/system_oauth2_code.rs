@@ -18,6 +18,9 @@ pub struct Model {
     pub expires_time: DateTime,
     pub redirect_uri: Option<String>,
     pub state: String,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    pub nonce: Option<String>,
     pub creator: Option<String>,
     pub create_time: DateTime,
     pub updater: Option<String>,
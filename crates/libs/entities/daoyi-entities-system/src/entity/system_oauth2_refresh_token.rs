@@ -16,6 +16,9 @@ pub struct Model {
     pub client_id: String,
     pub scopes: Option<String>,
     pub expires_time: DateTime,
+    pub user_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub family_id: Option<String>,
     pub creator: Option<String>,
     pub create_time: DateTime,
     pub updater: Option<String>,
@@ -61,6 +61,7 @@ async fn login(
         user_id: user.id,
         user_type: enumeration::UserTypeEnum::Member,
         token: String::new(),
+        expires_time: None,
     };
     let access_token = get_default_jwt().encode(&principal).await?;
     tracing::info!("登录成功...JWT token: {access_token}");
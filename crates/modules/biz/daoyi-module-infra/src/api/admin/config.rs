@@ -0,0 +1,107 @@
+use crate::service::config::ConfigService;
+use crate::vo::config::config_resp_vo::ConfigRespVo;
+use crate::vo::config::config_save_req_vo::ConfigSaveReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::IdParams;
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "INFRA 参数配置";
+
+/// 参数配置模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_config,
+        update_config,
+    ),
+    components(
+        schemas(
+            ConfigRespVo,
+            ConfigSaveReqVo,
+        )
+    ),
+    tags(
+        (name = "config", description = "参数配置 API")
+    )
+)]
+pub struct ConfigApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new().route("/get", routing::get(get_config)).route(
+        "/update",
+        routing::put(update_config).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新参数配置")),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/infra/config/get",
+    tag = "config",
+    summary = "获得参数配置",
+    description = "根据编号获得参数配置详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "参数配置编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<ConfigRespVo>),
+        (status = 400, description = "参数配置不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_config(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<ConfigRespVo> {
+    api_json_ok(
+        ConfigService::new(tenant)
+            .get_config(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/infra/config/update",
+    tag = "config",
+    summary = "修改参数配置",
+    description = "修改参数配置，参数键名全局唯一，变更的字段记录在操作日志中，可通过操作日志的变更历史查看",
+    request_body = ConfigSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_config(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<ConfigSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    ConfigService::new(tenant)
+        .update_config(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,8 @@
+use axum::Router;
+use daoyi_common::app::AppState;
+
+pub mod config;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new().nest("/infra/config", config::create_router())
+}
@@ -1,8 +1,10 @@
+pub mod admin;
+
 use axum::Router;
 use daoyi_common::app::AppState;
 
 pub fn create_router() -> Router<AppState> {
-    Router::new().nest("/api", Router::new())
+    Router::new().nest("/admin-api", admin::create_router())
 }
 
 // 错误示范（违反孤儿规则）
@@ -0,0 +1,67 @@
+use crate::vo::config::config_save_req_vo::ConfigSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::database;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::update_with_change_log;
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{CONFIG_KEY_DUPLICATE, CONFIG_NOT_EXISTS};
+use daoyi_entities_infra::entity::infra_config;
+use daoyi_entities_infra::entity::prelude::InfraConfig;
+use sea_orm::*;
+
+pub struct ConfigService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(ConfigService);
+impl ConfigService {
+    // 提取公共查询条件到基础方法，参数配置不区分租户
+    fn base_query(&self) -> Select<InfraConfig> {
+        InfraConfig::find().filter(infra_config::Column::Deleted.eq(0))
+    }
+}
+
+impl ConfigService {
+    pub async fn update_config(
+        &self,
+        req_vo: ConfigSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(CONFIG_NOT_EXISTS))?;
+        // 校验自己存在
+        let config = self.get_config(id).await?;
+        // 校验参数配置 key 的唯一性
+        self.validate_config_key_unique(Some(id), &req_vo.key)
+            .await?;
+        // 更新参数配置，变更的字段记录到操作日志
+        let mut active_model = infra_config::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_change_log(&config, active_model, Some(operator.user_id.to_string()))
+                .await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn get_config(&self, id: i64) -> ApiResult<infra_config::Model> {
+        self.base_query()
+            .filter(infra_config::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(CONFIG_NOT_EXISTS))
+    }
+
+    async fn validate_config_key_unique(&self, id: Option<i64>, key: &str) -> ApiResult<()> {
+        let config = self
+            .base_query()
+            .filter(infra_config::Column::ConfigKey.eq(key))
+            .one(database::get()?)
+            .await?;
+        match config {
+            Some(config) if Some(config.id) != id => Err(ApiError::BizCode(CONFIG_KEY_DUPLICATE)),
+            _ => Ok(()),
+        }
+    }
+}
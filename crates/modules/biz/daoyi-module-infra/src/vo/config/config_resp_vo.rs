@@ -0,0 +1,55 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_infra::entity::infra_config;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// ConfigRespVO，管理后台 - 参数配置信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRespVo {
+    /// 参数分组
+    #[schema(example = "biz")]
+    pub category: String,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 参数配置序号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 参数键名
+    #[schema(example = "yunai.db.username")]
+    pub key: String,
+    /// 参数名称
+    #[schema(example = "数据库名")]
+    pub name: String,
+    /// 备注
+    #[schema(example = "备注一下很帅气！")]
+    pub remark: Option<String>,
+    /// 参数类型，1 系统内置，2 自定义
+    #[schema(example = 1)]
+    pub r#type: i32,
+    /// 参数键值
+    #[schema(example = "1024")]
+    pub value: String,
+    /// 是否可见
+    #[schema(example = true)]
+    pub visible: bool,
+}
+
+impl From<infra_config::Model> for ConfigRespVo {
+    fn from(model: infra_config::Model) -> Self {
+        Self {
+            category: model.category,
+            create_time: model.create_time,
+            id: model.id,
+            key: model.config_key,
+            name: model.name,
+            remark: model.remark,
+            r#type: model.r#type,
+            value: model.value,
+            visible: model.visible,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use daoyi_entities_infra::entity::infra_config;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// ConfigSaveReqVO，管理后台 - 参数配置创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSaveReqVo {
+    /// 参数分组
+    #[validate(length(min = 1, max = 50, message = "参数分组长度为 1-50 个字符"))]
+    #[schema(example = "biz")]
+    pub category: String,
+    /// 参数配置序号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 参数键名
+    #[validate(length(min = 1, max = 100, message = "参数键名长度为 1-100 个字符"))]
+    #[schema(example = "yunai.db.username")]
+    pub key: String,
+    /// 参数名称
+    #[validate(length(min = 1, max = 100, message = "参数名称长度为 1-100 个字符"))]
+    #[schema(example = "数据库名")]
+    pub name: String,
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过 500 个字符"))]
+    #[schema(example = "备注一下很帅气！")]
+    pub remark: Option<String>,
+    /// 参数键值
+    #[validate(length(min = 1, max = 500, message = "参数键值长度为 1-500 个字符"))]
+    #[schema(example = "1024")]
+    pub value: String,
+    /// 是否可见
+    #[schema(example = true)]
+    pub visible: bool,
+}
+
+impl From<ConfigSaveReqVo> for infra_config::ActiveModel {
+    fn from(value: ConfigSaveReqVo) -> Self {
+        Self {
+            category: Set(value.category),
+            config_key: Set(value.key),
+            name: Set(value.name),
+            remark: Set(value.remark),
+            value: Set(value.value),
+            visible: Set(value.visible),
+            ..Default::default()
+        }
+    }
+}
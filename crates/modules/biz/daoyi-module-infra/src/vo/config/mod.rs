@@ -0,0 +1,2 @@
+pub mod config_resp_vo;
+pub mod config_save_req_vo;
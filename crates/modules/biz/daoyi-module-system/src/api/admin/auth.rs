@@ -8,7 +8,8 @@ use crate::vo::auth::auth_sms_send_req_vo::AuthSmsSendReqVo;
 use axum::extract::ConnectInfo;
 use axum::{Extension, Router, debug_handler, routing};
 use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
-use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_common_support::utils::web::valid::{ValidDictJson, ValidJson, ValidQuery};
 use daoyi_common_support::utils::{
     errors::error::{ApiJsonResult, api_json_ok},
     web::response::ApiJsonResponse,
@@ -107,9 +108,14 @@ async fn send_sms_code(
 #[debug_handler]
 async fn register(
     Extension(tenant): Extension<TenantContextHolder>,
-    ValidJson(params): ValidJson<AuthRegisterReqVo>,
+    client_info: ClientInfo,
+    ValidDictJson(params): ValidDictJson<AuthRegisterReqVo>,
 ) -> ApiJsonResult<AuthLoginRespVo> {
-    api_json_ok(AdminAuthService::new(tenant).register(params).await?)
+    api_json_ok(
+        AdminAuthService::new(tenant)
+            .register(params, &client_info)
+            .await?,
+    )
 }
 
 #[utoipa::path(
@@ -165,11 +171,12 @@ async fn get_permission_info(
 #[debug_handler]
 async fn refresh_token(
     Extension(tenant): Extension<TenantContextHolder>,
+    client_info: ClientInfo,
     ValidQuery(params): ValidQuery<AuthRefreshTokenReqVo>,
 ) -> ApiJsonResult<AuthLoginRespVo> {
     api_json_ok(
         AdminAuthService::new(tenant)
-            .refresh_token(params.refresh_token)
+            .refresh_token(params.refresh_token, &client_info)
             .await?,
     )
 }
@@ -197,8 +204,13 @@ async fn refresh_token(
 async fn logout(
     Extension(tenant): Extension<TenantContextHolder>,
     Extension(principal): Extension<Principal>,
+    client_info: ClientInfo,
 ) -> ApiJsonResult<()> {
-    api_json_ok(AdminAuthService::new(tenant).logout(principal).await?)
+    api_json_ok(
+        AdminAuthService::new(tenant)
+            .logout(principal, &client_info)
+            .await?,
+    )
 }
 
 #[utoipa::path(
@@ -224,7 +236,12 @@ async fn logout(
 #[debug_handler]
 async fn login(
     Extension(tenant): Extension<TenantContextHolder>,
-    ValidJson(params): ValidJson<AuthLoginReqVo>,
+    client_info: ClientInfo,
+    ValidDictJson(params): ValidDictJson<AuthLoginReqVo>,
 ) -> ApiJsonResult<AuthLoginRespVo> {
-    api_json_ok(AdminAuthService::new(tenant).login(params).await?)
+    api_json_ok(
+        AdminAuthService::new(tenant)
+            .login(params, &client_info)
+            .await?,
+    )
 }
@@ -0,0 +1,296 @@
+use crate::service::dept::DeptService;
+use crate::vo::dept::dept_list_req_vo::DeptListReqVo;
+use crate::vo::dept::dept_resp_vo::DeptRespVo;
+use crate::vo::dept::dept_save_req_vo::DeptSaveReqVo;
+use crate::vo::dept::dept_simple_resp_vo::DeptSimpleRespVo;
+use crate::vo::dept::dept_tree_resp_vo::DeptTreeRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::IdParams;
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 部门";
+
+/// 部门管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_dept_list,
+        get_dept_tree,
+        get_simple_dept_list,
+        get_dept,
+        create_dept,
+        update_dept,
+        delete_dept,
+    ),
+    components(
+        schemas(
+            DeptRespVo,
+            DeptSaveReqVo,
+            DeptSimpleRespVo,
+            DeptTreeRespVo,
+        )
+    ),
+    tags(
+        (name = "dept", description = "部门管理 API")
+    )
+)]
+pub struct DeptApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/list", routing::get(get_dept_list))
+        .route("/tree", routing::get(get_dept_tree))
+        .route("/simple-list", routing::get(get_simple_dept_list))
+        .route("/get", routing::get(get_dept))
+        .route(
+            "/create",
+            routing::post(create_dept).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建部门")),
+        )
+        .route(
+            "/update",
+            routing::put(update_dept).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新部门")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_dept).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除部门")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dept/list",
+    tag = "dept",
+    summary = "获取部门列表",
+    description = "获取当前租户的部门平铺列表，按名称和状态过滤",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "部门名称，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<DeptRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dept_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<DeptListReqVo>,
+) -> ApiJsonResult<Vec<DeptRespVo>> {
+    api_json_ok(
+        DeptService::new(tenant)
+            .get_dept_list_by_condition(params)
+            .await?
+            .into_iter()
+            .map(DeptRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dept/tree",
+    tag = "dept",
+    summary = "获取部门树",
+    description = "获取当前租户的部门树，按名称和状态过滤",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "部门名称，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<DeptTreeRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dept_tree(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<DeptListReqVo>,
+) -> ApiJsonResult<Vec<DeptTreeRespVo>> {
+    let list = DeptService::new(tenant)
+        .get_dept_list_by_condition(params)
+        .await?;
+    api_json_ok(DeptService::build_tree(list))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dept/simple-list",
+    tag = "dept",
+    summary = "获取部门精简信息列表",
+    description = "只包含被开启的部门，主要用于前端的下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<DeptSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_dept_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<DeptSimpleRespVo>> {
+    let req_vo = DeptListReqVo {
+        name: None,
+        status: Some(CommonStatusEnum::Enable.status()),
+    };
+    api_json_ok(
+        DeptService::new(tenant)
+            .get_dept_list_by_condition(req_vo)
+            .await?
+            .into_iter()
+            .map(DeptSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dept/get",
+    tag = "dept",
+    summary = "获得部门信息",
+    description = "根据编号获得部门详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "部门编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<DeptRespVo>),
+        (status = 400, description = "部门不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dept(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<DeptRespVo> {
+    api_json_ok(DeptService::new(tenant).get_dept(params.id).await?.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/dept/create",
+    tag = "dept",
+    summary = "创建部门",
+    description = "创建部门，校验父部门、同级名称唯一和负责人",
+    request_body = DeptSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回部门编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_dept(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DeptSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        DeptService::new(tenant)
+            .create_dept(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/dept/update",
+    tag = "dept",
+    summary = "更新部门",
+    description = "更新部门信息，不能将父部门设置为自己或自己的子部门",
+    request_body = DeptSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "更新成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_dept(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DeptSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    DeptService::new(tenant)
+        .update_dept(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/dept/delete",
+    tag = "dept",
+    summary = "删除部门",
+    description = "逻辑删除部门，存在子部门时不允许删除",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "部门编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "部门不存在或存在子部门"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_dept(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    DeptService::new(tenant)
+        .delete_dept(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
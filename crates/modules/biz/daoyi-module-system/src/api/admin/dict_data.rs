@@ -0,0 +1,262 @@
+use crate::service::dict_data::DictDataService;
+use crate::vo::dict::dict_data_page_req_vo::DictDataPageReqVo;
+use crate::vo::dict::dict_data_resp_vo::DictDataRespVo;
+use crate::vo::dict::dict_data_save_req_vo::DictDataSaveReqVo;
+use crate::vo::dict::dict_data_simple_resp_vo::DictDataSimpleRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common::service::DictApi;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 字典数据";
+
+/// 字典数据模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_dict_data_page,
+        get_dict_data,
+        get_simple_dict_data_list,
+        create_dict_data,
+        update_dict_data,
+        delete_dict_data,
+    ),
+    components(
+        schemas(
+            DictDataRespVo,
+            DictDataSaveReqVo,
+            DictDataSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "dict-data", description = "字典数据 API")
+    )
+)]
+pub struct DictDataApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_dict_data_page))
+        .route("/get", routing::get(get_dict_data))
+        .route("/simple-list", routing::get(get_simple_dict_data_list))
+        .route(
+            "/create",
+            routing::post(create_dict_data)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建字典数据")),
+        )
+        .route(
+            "/update",
+            routing::put(update_dict_data)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新字典数据")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_dict_data)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除字典数据")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-data/page",
+    tag = "dict-data",
+    summary = "获得字典数据的分页列表",
+    description = "按字典类型、标签、状态分页查询字典数据",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("dictType" = Option<String>, Query, description = "字典类型，精确匹配"),
+        ("label" = Option<String>, Query, description = "字典标签，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<DictDataRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dict_data_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<DictDataPageReqVo>,
+) -> ApiJsonResult<Page<DictDataRespVo>> {
+    api_json_ok(
+        DictDataService::new(tenant)
+            .get_dict_data_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-data/get",
+    tag = "dict-data",
+    summary = "查询字典数据详细",
+    description = "根据编号获得字典数据详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "字典数据编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<DictDataRespVo>),
+        (status = 400, description = "字典数据不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dict_data(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<DictDataRespVo> {
+    api_json_ok(
+        DictDataService::new(tenant)
+            .get_dict_data(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-data/simple-list",
+    tag = "dict-data",
+    summary = "获得全部字典数据列表",
+    description = "一次性返回所有开启状态的字典数据，供前端缓存使用，结果由 Redis 缓存",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<DictDataSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_dict_data_list() -> ApiJsonResult<Vec<DictDataSimpleRespVo>> {
+    api_json_ok(
+        DictApi
+            .get_simple_dict_data_list()
+            .await?
+            .into_iter()
+            .map(DictDataSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/dict-data/create",
+    tag = "dict-data",
+    summary = "新增字典数据",
+    description = "新增字典数据，字典值在同一字典类型下唯一",
+    request_body = DictDataSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回字典数据编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_dict_data(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DictDataSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        DictDataService::new(tenant)
+            .create_dict_data(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/dict-data/update",
+    tag = "dict-data",
+    summary = "修改字典数据",
+    description = "修改字典数据信息",
+    request_body = DictDataSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_dict_data(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DictDataSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    DictDataService::new(tenant)
+        .update_dict_data(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/dict-data/delete",
+    tag = "dict-data",
+    summary = "删除字典数据",
+    description = "逻辑删除字典数据",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "字典数据编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "字典数据不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_dict_data(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    DictDataService::new(tenant)
+        .delete_dict_data(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,265 @@
+use crate::service::dict_type::DictTypeService;
+use crate::vo::dict::dict_type_page_req_vo::DictTypePageReqVo;
+use crate::vo::dict::dict_type_resp_vo::DictTypeRespVo;
+use crate::vo::dict::dict_type_save_req_vo::DictTypeSaveReqVo;
+use crate::vo::dict::dict_type_simple_resp_vo::DictTypeSimpleRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 字典类型";
+
+/// 字典类型模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_dict_type_page,
+        get_dict_type,
+        get_simple_dict_type_list,
+        create_dict_type,
+        update_dict_type,
+        delete_dict_type,
+    ),
+    components(
+        schemas(
+            DictTypeRespVo,
+            DictTypeSaveReqVo,
+            DictTypeSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "dict-type", description = "字典类型 API")
+    )
+)]
+pub struct DictTypeApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_dict_type_page))
+        .route("/get", routing::get(get_dict_type))
+        .route("/simple-list", routing::get(get_simple_dict_type_list))
+        .route(
+            "/create",
+            routing::post(create_dict_type)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建字典类型")),
+        )
+        .route(
+            "/update",
+            routing::put(update_dict_type)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新字典类型")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_dict_type)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除字典类型")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-type/page",
+    tag = "dict-type",
+    summary = "获得字典类型的分页列表",
+    description = "按名称、类型、状态、创建时间分页查询字典类型",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "字典类型名称，模糊匹配"),
+        ("type" = Option<String>, Query, description = "字典类型，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态"),
+        ("beginCreateTime" = Option<String>, Query, description = "创建时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "创建时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<DictTypeRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dict_type_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<DictTypePageReqVo>,
+) -> ApiJsonResult<Page<DictTypeRespVo>> {
+    api_json_ok(
+        DictTypeService::new(tenant)
+            .get_dict_type_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-type/get",
+    tag = "dict-type",
+    summary = "查询字典类型详细",
+    description = "根据编号获得字典类型详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "字典类型编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<DictTypeRespVo>),
+        (status = 400, description = "字典类型不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_dict_type(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<DictTypeRespVo> {
+    api_json_ok(
+        DictTypeService::new(tenant)
+            .get_dict_type(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/dict-type/simple-list",
+    tag = "dict-type",
+    summary = "获得全部字典类型列表",
+    description = "包括开启 + 禁用的字典类型，主要用于前端的下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<DictTypeSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_dict_type_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<DictTypeSimpleRespVo>> {
+    api_json_ok(
+        DictTypeService::new(tenant)
+            .get_dict_type_list()
+            .await?
+            .into_iter()
+            .map(DictTypeSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/dict-type/create",
+    tag = "dict-type",
+    summary = "创建字典类型",
+    description = "创建字典类型，名称和类型全局唯一",
+    request_body = DictTypeSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回字典类型编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_dict_type(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DictTypeSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        DictTypeService::new(tenant)
+            .create_dict_type(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/dict-type/update",
+    tag = "dict-type",
+    summary = "修改字典类型",
+    description = "修改字典类型，类型标识变更时同步更新其下的字典数据",
+    request_body = DictTypeSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_dict_type(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<DictTypeSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    DictTypeService::new(tenant)
+        .update_dict_type(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/dict-type/delete",
+    tag = "dict-type",
+    summary = "删除字典类型",
+    description = "逻辑删除字典类型，存在字典数据时不允许删除",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "字典类型编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "字典类型不存在或还有字典数据"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_dict_type(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    DictTypeService::new(tenant)
+        .delete_dict_type(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
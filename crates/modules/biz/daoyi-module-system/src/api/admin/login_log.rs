@@ -0,0 +1,125 @@
+use crate::service::login_logger::LoginLogService;
+use crate::vo::logger::login_log_page_req_vo::LoginLogPageReqVo;
+use crate::vo::logger::login_log_resp_vo::LoginLogRespVo;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::common::Page;
+use daoyi_common::app::{AppState, TenantContextHolder};
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::web::valid::ValidQuery;
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 登录日志模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_login_log_page,
+        export_login_log,
+    ),
+    components(
+        schemas(
+            LoginLogRespVo,
+        )
+    ),
+    tags(
+        (name = "login-log", description = "登录日志 API")
+    )
+)]
+pub struct LoginLogApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_login_log_page))
+        .route("/export-csv", routing::get(export_login_log))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/login-log/page",
+    tag = "login-log",
+    summary = "获得登录日志分页",
+    description = "分页查询当前租户的登录、登出日志，包括登录失败、强制退出与刷新令牌重用",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("username" = Option<String>, Query, description = "用户账号，模糊匹配"),
+        ("userIp" = Option<String>, Query, description = "用户 IP，模糊匹配"),
+        ("logType" = Option<i64>, Query, description = "日志类型，参见 LoginLogTypeEnum 枚举", example = 100),
+        ("result" = Option<i32>, Query, description = "登录结果，参见 LoginResultEnum 枚举", example = 0),
+        ("traceId" = Option<String>, Query, description = "链路追踪编号"),
+        ("beginCreateTime" = Option<String>, Query, description = "登录时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "登录时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<LoginLogRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_login_log_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<LoginLogPageReqVo>,
+) -> ApiJsonResult<Page<LoginLogRespVo>> {
+    api_json_ok(
+        LoginLogService::new(tenant)
+            .get_login_log_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/login-log/export-csv",
+    tag = "login-log",
+    summary = "导出登录日志",
+    description = "按照与分页查询相同的条件导出全部登录日志为 CSV 文件，忽略分页参数",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("username" = Option<String>, Query, description = "用户账号，模糊匹配"),
+        ("userIp" = Option<String>, Query, description = "用户 IP，模糊匹配"),
+        ("logType" = Option<i64>, Query, description = "日志类型，参见 LoginLogTypeEnum 枚举", example = 100),
+        ("result" = Option<i32>, Query, description = "登录结果，参见 LoginResultEnum 枚举", example = 0),
+        ("traceId" = Option<String>, Query, description = "链路追踪编号"),
+        ("beginCreateTime" = Option<String>, Query, description = "登录时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "登录时间的结束", example = "2024-12-31 23:59:59")
+    ),
+    responses(
+        (status = 200, description = "导出成功", body = String, content_type = "text/csv"),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn export_login_log(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<LoginLogPageReqVo>,
+) -> ApiResult<impl IntoResponse> {
+    let content = LoginLogService::new(tenant)
+        .export_login_log(params)
+        .await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"login-log.csv\"",
+            ),
+        ],
+        content,
+    ))
+}
@@ -0,0 +1,260 @@
+use crate::service::menu::MenuService;
+use crate::vo::menu::menu_list_req_vo::MenuListReqVo;
+use crate::vo::menu::menu_resp_vo::MenuRespVo;
+use crate::vo::menu::menu_save_req_vo::MenuSaveReqVo;
+use crate::vo::menu::menu_simple_resp_vo::MenuSimpleRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::IdParams;
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 菜单";
+
+/// 菜单管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_menu_list,
+        get_simple_menu_list,
+        get_menu,
+        create_menu,
+        update_menu,
+        delete_menu,
+    ),
+    components(
+        schemas(
+            MenuRespVo,
+            MenuSaveReqVo,
+            MenuSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "menu", description = "菜单管理 API")
+    )
+)]
+pub struct MenuApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/list", routing::get(get_menu_list))
+        .route("/simple-list", routing::get(get_simple_menu_list))
+        .route("/get", routing::get(get_menu))
+        .route(
+            "/create",
+            routing::post(create_menu).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建菜单")),
+        )
+        .route(
+            "/update",
+            routing::put(update_menu).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新菜单")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_menu).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除菜单")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/menu/list",
+    tag = "menu",
+    summary = "获取菜单列表",
+    description = "用于【菜单管理】界面，按名称和状态过滤",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "菜单名称，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<MenuRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_menu_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<MenuListReqVo>,
+) -> ApiJsonResult<Vec<MenuRespVo>> {
+    api_json_ok(
+        MenuService::new(tenant)
+            .get_menu_list_by_condition(params)
+            .await?
+            .into_iter()
+            .map(MenuRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/menu/simple-list",
+    tag = "menu",
+    summary = "获取菜单精简信息列表",
+    description = "只包含被开启的菜单，用于【角色分配菜单】功能的选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<MenuSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_menu_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<MenuSimpleRespVo>> {
+    let req_vo = MenuListReqVo {
+        name: None,
+        status: Some(CommonStatusEnum::Enable.status()),
+    };
+    api_json_ok(
+        MenuService::new(tenant)
+            .get_menu_list_by_condition(req_vo)
+            .await?
+            .into_iter()
+            .map(MenuSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/menu/get",
+    tag = "menu",
+    summary = "获取菜单信息",
+    description = "根据编号获取菜单详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "菜单编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<MenuRespVo>),
+        (status = 400, description = "菜单不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_menu(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<MenuRespVo> {
+    api_json_ok(MenuService::new(tenant).get_menu(params.id).await?.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/menu/create",
+    tag = "menu",
+    summary = "创建菜单",
+    description = "创建目录、菜单或按钮，可同时配置授权接口",
+    request_body = MenuSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回菜单编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_menu(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<MenuSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        MenuService::new(tenant)
+            .create_menu(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/menu/update",
+    tag = "menu",
+    summary = "修改菜单",
+    description = "修改菜单信息，修改父菜单时会校验是否成环",
+    request_body = MenuSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_menu(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<MenuSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    MenuService::new(tenant)
+        .update_menu(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/menu/delete",
+    tag = "menu",
+    summary = "删除菜单",
+    description = "逻辑删除菜单，存在子菜单时不允许删除",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "菜单编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "菜单不存在或存在子菜单"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_menu(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    MenuService::new(tenant)
+        .delete_menu(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -2,7 +2,47 @@ use axum::Router;
 use daoyi_common::app::AppState;
 
 pub mod auth;
+pub mod dept;
+pub mod dict_data;
+pub mod dict_type;
+pub mod login_log;
+pub mod menu;
+pub mod notice;
+pub mod oauth2_client;
+pub mod oauth2_device;
+pub mod oauth2_oidc;
+pub mod oauth2_open;
+pub mod oauth2_token;
+pub mod operate_log;
+pub mod post;
+pub mod role;
+pub mod tenant;
+pub mod tenant_package;
+pub mod user;
+pub mod user_profile;
 
 pub fn create_router() -> Router<AppState> {
-    Router::new().nest("/auth", auth::create_router())
+    Router::new()
+        .nest("/auth", auth::create_router())
+        .nest("/system/dept", dept::create_router())
+        .nest("/system/dict-data", dict_data::create_router())
+        .nest("/system/dict-type", dict_type::create_router())
+        .nest("/system/login-log", login_log::create_router())
+        .nest("/system/menu", menu::create_router())
+        .nest("/system/notice", notice::create_router())
+        .nest(
+            "/system/oauth2",
+            oauth2_open::create_router()
+                .merge(oauth2_device::create_router())
+                .merge(oauth2_oidc::create_router()),
+        )
+        .nest("/system/oauth2-client", oauth2_client::create_router())
+        .nest("/system/oauth2-token", oauth2_token::create_router())
+        .nest("/system/operate-log", operate_log::create_router())
+        .nest("/system/post", post::create_router())
+        .nest("/system/role", role::create_router())
+        .nest("/system/tenant", tenant::create_router())
+        .nest("/system/tenant-package", tenant_package::create_router())
+        .nest("/system/user", user::create_router())
+        .nest("/system/user/profile", user_profile::create_router())
 }
@@ -0,0 +1,363 @@
+use crate::service::notice::NoticeService;
+use crate::vo::notice::notice_my_page_req_vo::NoticeMyPageReqVo;
+use crate::vo::notice::notice_my_resp_vo::NoticeMyRespVo;
+use crate::vo::notice::notice_page_req_vo::NoticePageReqVo;
+use crate::vo::notice::notice_read_req_vo::NoticeReadReqVo;
+use crate::vo::notice::notice_resp_vo::NoticeRespVo;
+use crate::vo::notice::notice_save_req_vo::NoticeSaveReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::{ValidDictJson, ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 通知公告";
+
+/// 通知公告模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_notice_page,
+        get_notice,
+        create_notice,
+        update_notice,
+        delete_notice,
+        get_my_notice_page,
+        get_unread_notice_count,
+        update_notice_read,
+        update_all_notice_read,
+    ),
+    components(
+        schemas(
+            NoticeMyRespVo,
+            NoticeReadReqVo,
+            NoticeRespVo,
+            NoticeSaveReqVo,
+        )
+    ),
+    tags(
+        (name = "notice", description = "通知公告 API")
+    )
+)]
+pub struct NoticeApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_notice_page))
+        .route("/get", routing::get(get_notice))
+        .route(
+            "/create",
+            routing::post(create_notice)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建通知公告")),
+        )
+        .route(
+            "/update",
+            routing::put(update_notice)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新通知公告")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_notice)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除通知公告")),
+        )
+        .route("/my-page", routing::get(get_my_notice_page))
+        .route("/get-unread-count", routing::get(get_unread_notice_count))
+        .route("/update-read", routing::put(update_notice_read))
+        .route("/update-all-read", routing::put(update_all_notice_read))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/notice/page",
+    tag = "notice",
+    summary = "获取通知公告列表",
+    description = "按标题、状态、类型分页查询当前租户的通知公告，包含草稿",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("title" = Option<String>, Query, description = "公告标题，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "公告状态，0 已发布、1 草稿"),
+        ("type" = Option<i32>, Query, description = "公告类型"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<NoticeRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_notice_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<NoticePageReqVo>,
+) -> ApiJsonResult<Page<NoticeRespVo>> {
+    api_json_ok(NoticeService::new(tenant).get_notice_page(params).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/notice/get",
+    tag = "notice",
+    summary = "获得通知公告",
+    description = "根据编号获得通知公告详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "通知公告编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<NoticeRespVo>),
+        (status = 400, description = "通知公告不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_notice(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<NoticeRespVo> {
+    api_json_ok(
+        NoticeService::new(tenant)
+            .get_notice(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/notice/create",
+    tag = "notice",
+    summary = "创建通知公告",
+    description = "创建通知公告，状态为草稿时不会出现在用户的公告列表中",
+    request_body = NoticeSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回通知公告编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_notice(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidDictJson(params): ValidDictJson<NoticeSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        NoticeService::new(tenant)
+            .create_notice(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/notice/update",
+    tag = "notice",
+    summary = "修改通知公告",
+    description = "修改通知公告，可通过状态在草稿与已发布之间切换",
+    request_body = NoticeSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_notice(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidDictJson(params): ValidDictJson<NoticeSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    NoticeService::new(tenant)
+        .update_notice(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/notice/delete",
+    tag = "notice",
+    summary = "删除通知公告",
+    description = "逻辑删除通知公告，并清理其已读记录",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "通知公告编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "通知公告不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_notice(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    NoticeService::new(tenant)
+        .delete_notice(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/notice/my-page",
+    tag = "notice",
+    summary = "获得我的通知公告分页",
+    description = "获得当前租户已发布的通知公告，并标记当前用户是否已读",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("readStatus" = Option<bool>, Query, description = "是否已读，不传时返回全部"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<NoticeMyRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_my_notice_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<NoticeMyPageReqVo>,
+) -> ApiJsonResult<Page<NoticeMyRespVo>> {
+    api_json_ok(
+        NoticeService::new(tenant)
+            .get_my_notice_page(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/notice/get-unread-count",
+    tag = "notice",
+    summary = "获得当前用户的未读公告数量",
+    description = "用于管理后台顶部的未读公告角标",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<u64>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_unread_notice_count(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+) -> ApiJsonResult<u64> {
+    api_json_ok(
+        NoticeService::new(tenant)
+            .get_unread_notice_count(&principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/notice/update-read",
+    tag = "notice",
+    summary = "标记通知公告为已读",
+    description = "标记当前用户的指定通知公告为已读",
+    request_body = NoticeReadReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "标记成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_notice_read(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<NoticeReadReqVo>,
+) -> ApiJsonResult<bool> {
+    NoticeService::new(tenant)
+        .update_notice_read(params.ids, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/notice/update-all-read",
+    tag = "notice",
+    summary = "标记所有通知公告为已读",
+    description = "标记当前用户的所有已发布通知公告为已读",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "标记成功", body = ApiJsonResponse<bool>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_all_notice_read(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+) -> ApiJsonResult<bool> {
+    NoticeService::new(tenant)
+        .update_all_notice_read(&principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,266 @@
+use crate::service::oauth2_client::OAuth2ClientService;
+use crate::vo::oauth2::oauth2_client_page_req_vo::OAuth2ClientPageReqVo;
+use crate::vo::oauth2::oauth2_client_resp_vo::OAuth2ClientRespVo;
+use crate::vo::oauth2::oauth2_client_save_req_vo::OAuth2ClientSaveReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM OAuth2 客户端";
+
+/// OAuth2 客户端模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_oauth2_client_page,
+        get_oauth2_client,
+        create_oauth2_client,
+        update_oauth2_client,
+        delete_oauth2_client,
+        regenerate_oauth2_client_secret,
+    ),
+    components(
+        schemas(
+            OAuth2ClientRespVo,
+            OAuth2ClientSaveReqVo,
+        )
+    ),
+    tags(
+        (name = "oauth2-client", description = "OAuth2 客户端 API")
+    )
+)]
+pub struct OAuth2ClientApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_oauth2_client_page))
+        .route("/get", routing::get(get_oauth2_client))
+        .route(
+            "/create",
+            routing::post(create_oauth2_client)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建OAuth2 客户端")),
+        )
+        .route(
+            "/update",
+            routing::put(update_oauth2_client)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新OAuth2 客户端")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_oauth2_client)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除OAuth2 客户端")),
+        )
+        .route(
+            "/regenerate-secret",
+            routing::put(regenerate_oauth2_client_secret).layer(OperateLogLayer::new(
+                OPERATE_LOG_TYPE,
+                "重置 OAuth2 客户端密钥",
+            )),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2-client/page",
+    tag = "oauth2-client",
+    summary = "获得 OAuth2 客户端分页",
+    description = "按应用名、状态分页查询 OAuth2 客户端",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "应用名，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "状态"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<OAuth2ClientRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_oauth2_client_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<OAuth2ClientPageReqVo>,
+) -> ApiJsonResult<Page<OAuth2ClientRespVo>> {
+    api_json_ok(
+        OAuth2ClientService::new(tenant)
+            .get_oauth2_client_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2-client/get",
+    tag = "oauth2-client",
+    summary = "获得 OAuth2 客户端",
+    description = "根据编号获得 OAuth2 客户端详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "OAuth2 客户端编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<OAuth2ClientRespVo>),
+        (status = 400, description = "OAuth2 客户端不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_oauth2_client(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<OAuth2ClientRespVo> {
+    api_json_ok(
+        OAuth2ClientService::new(tenant)
+            .get_oauth2_client(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2-client/create",
+    tag = "oauth2-client",
+    summary = "创建 OAuth2 客户端",
+    description = "创建 OAuth2 客户端，客户端编号唯一，未指定密钥时自动生成",
+    request_body = OAuth2ClientSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回 OAuth2 客户端编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_oauth2_client(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<OAuth2ClientSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        OAuth2ClientService::new(tenant)
+            .create_oauth2_client(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/oauth2-client/update",
+    tag = "oauth2-client",
+    summary = "更新 OAuth2 客户端",
+    description = "更新 OAuth2 客户端，未指定密钥时保持不变，并清理客户端缓存",
+    request_body = OAuth2ClientSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_oauth2_client(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<OAuth2ClientSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    OAuth2ClientService::new(tenant)
+        .update_oauth2_client(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/oauth2-client/delete",
+    tag = "oauth2-client",
+    summary = "删除 OAuth2 客户端",
+    description = "逻辑删除 OAuth2 客户端，并清理客户端缓存",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "OAuth2 客户端编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "OAuth2 客户端不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_oauth2_client(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    OAuth2ClientService::new(tenant)
+        .delete_oauth2_client(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/oauth2-client/regenerate-secret",
+    tag = "oauth2-client",
+    summary = "重新生成 OAuth2 客户端密钥",
+    description = "重新生成客户端密钥并返回，原密钥立即失效",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "OAuth2 客户端编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "生成成功，返回新的客户端密钥", body = ApiJsonResponse<String>),
+        (status = 400, description = "OAuth2 客户端不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn regenerate_oauth2_client_secret(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<String> {
+    api_json_ok(
+        OAuth2ClientService::new(tenant)
+            .regenerate_oauth2_client_secret(params.id, &principal)
+            .await?,
+    )
+}
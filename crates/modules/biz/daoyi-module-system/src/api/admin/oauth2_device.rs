@@ -0,0 +1,155 @@
+use crate::service::oauth2_device::OAuth2DeviceService;
+use crate::service::oauth2_open::OAuth2OpenService;
+use crate::vo::oauth2::oauth2_device_approve_req_vo::OAuth2DeviceApproveReqVo;
+use crate::vo::oauth2::oauth2_device_authorization_req_vo::OAuth2DeviceAuthorizationReqVo;
+use crate::vo::oauth2::oauth2_device_authorization_resp_vo::OAuth2DeviceAuthorizationRespVo;
+use crate::vo::oauth2::oauth2_device_info_req_vo::OAuth2DeviceInfoReqVo;
+use crate::vo::oauth2::oauth2_device_info_resp_vo::OAuth2DeviceInfoRespVo;
+use crate::vo::oauth2::oauth2_error_resp_vo::OAuth2ErrorRespVo;
+use axum::extract::rejection::FormRejection;
+use axum::http::{HeaderMap, header};
+use axum::response::IntoResponse;
+use axum::{Extension, Form, Json, Router, debug_handler, routing};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::errors::error::ApiError;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// OAuth2 设备授权模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        create_device_authorization,
+        get_device_info,
+        approve_or_deny,
+    ),
+    components(
+        schemas(
+            OAuth2DeviceApproveReqVo,
+            OAuth2DeviceAuthorizationRespVo,
+            OAuth2DeviceInfoRespVo,
+        )
+    ),
+    tags(
+        (name = "oauth2-device", description = "OAuth2.0 设备授权 API")
+    )
+)]
+pub struct OAuth2DeviceApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/device/authorize",
+            routing::post(create_device_authorization),
+        )
+        .route(
+            "/device/verify",
+            routing::get(get_device_info).post(approve_or_deny),
+        )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/device/authorize",
+    tag = "oauth2-device",
+    summary = "申请设备授权",
+    description = "遵循 RFC 8628 规范的设备授权端点，供命令行工具等无法重定向浏览器的客户端使用。表单参数：client_id、client_secret、scope。返回设备码与用户码，用户在验证页输入用户码确认授权后，设备使用设备码轮询令牌端点获得访问令牌",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "客户端 Basic 认证，格式如 `Basic base64(client_id:client_secret)`，可选", example = "Basic ZGVmYXVsdDphZG1pbjEyMw==")
+    ),
+    responses(
+        (status = 200, description = "申请成功", body = OAuth2DeviceAuthorizationRespVo),
+        (status = 400, description = "请求参数错误", body = OAuth2ErrorRespVo),
+        (status = 401, description = "客户端认证失败", body = OAuth2ErrorRespVo),
+    ),
+    security(
+        ("tenant_id" = [])
+    )
+)]
+#[debug_handler]
+async fn create_device_authorization(
+    Extension(tenant): Extension<TenantContextHolder>,
+    headers: HeaderMap,
+    params: Result<Form<OAuth2DeviceAuthorizationReqVo>, FormRejection>,
+) -> Result<impl IntoResponse, OAuth2ErrorRespVo> {
+    let Form(params) = params.map_err(ApiError::Form)?;
+    let device_authorization = OAuth2OpenService::new(tenant)
+        .create_device_authorization(&headers, params)
+        .await?;
+    Ok((
+        [
+            (header::CACHE_CONTROL, "no-store"),
+            (header::PRAGMA, "no-cache"),
+        ],
+        Json(device_authorization),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2/device/verify",
+    tag = "oauth2-device",
+    summary = "获得设备授权信息",
+    description = "验证页根据用户码展示申请授权的客户端与授权范围，用户码忽略大小写与分隔符",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("userCode" = String, Query, description = "用户码", example = "WDJB-MJHT")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<OAuth2DeviceInfoRespVo>),
+        (status = 400, description = "用户码不存在或已过期"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_device_info(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<OAuth2DeviceInfoReqVo>,
+) -> ApiJsonResult<OAuth2DeviceInfoRespVo> {
+    api_json_ok(
+        OAuth2DeviceService::new(tenant)
+            .get_device_info(&params.user_code)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/device/verify",
+    tag = "oauth2-device",
+    summary = "确认设备授权",
+    description = "当前登录用户批准或拒绝设备授权，确认后用户码立即失效，设备下次轮询时获得访问令牌或 access_denied 错误",
+    request_body = OAuth2DeviceApproveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "确认成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "用户码不存在或已过期"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn approve_or_deny(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<OAuth2DeviceApproveReqVo>,
+) -> ApiJsonResult<bool> {
+    OAuth2DeviceService::new(tenant)
+        .approve_or_deny(&principal, &params.user_code, params.approved)
+        .await?;
+    api_json_ok(true)
+}
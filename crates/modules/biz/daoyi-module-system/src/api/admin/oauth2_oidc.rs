@@ -0,0 +1,108 @@
+use crate::service::oauth2_oidc::OAuth2OidcService;
+use crate::vo::oauth2::oauth2_error_resp_vo::OAuth2ErrorRespVo;
+use crate::vo::oauth2::oidc_discovery_resp_vo::OidcDiscoveryRespVo;
+use crate::vo::oauth2::oidc_user_info_resp_vo::OidcUserInfoRespVo;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::{Extension, Json, Router, debug_handler, routing};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use utoipa::OpenApi;
+
+/// OpenID Connect 模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_openid_configuration,
+        get_jwks,
+        get_user_info,
+    ),
+    components(
+        schemas(
+            OidcDiscoveryRespVo,
+            OidcUserInfoRespVo,
+        )
+    ),
+    tags(
+        (name = "oidc", description = "OpenID Connect API")
+    )
+)]
+pub struct OAuth2OidcApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/.well-known/openid-configuration",
+            routing::get(get_openid_configuration),
+        )
+        .route("/jwks", routing::get(get_jwks))
+        .route("/userinfo", routing::get(get_user_info).post(get_user_info))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2/.well-known/openid-configuration",
+    tag = "oidc",
+    summary = "获得 OpenID Connect 发现文档",
+    description = "遵循 OpenID Connect Discovery 1.0 规范，返回签发者、各端点地址与支持的能力，无需认证",
+    responses(
+        (status = 200, description = "获取成功", body = OidcDiscoveryRespVo),
+    )
+)]
+#[debug_handler]
+async fn get_openid_configuration(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> Json<OidcDiscoveryRespVo> {
+    Json(
+        OAuth2OidcService::new(tenant)
+            .get_openid_configuration()
+            .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2/jwks",
+    tag = "oidc",
+    summary = "获得签名公钥",
+    description = "遵循 RFC 7517 规范，返回签名 id_token 的公钥集合，无需认证",
+    responses(
+        (status = 200, description = "获取成功", content_type = "application/json"),
+        (status = 500, description = "签名密钥加载失败"),
+    )
+)]
+#[debug_handler]
+async fn get_jwks(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> Result<impl IntoResponse, OAuth2ErrorRespVo> {
+    let jwks = OAuth2OidcService::new(tenant).get_jwks().await?;
+    Ok(Json(jwks))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2/userinfo",
+    tag = "oidc",
+    summary = "获得用户信息",
+    description = "遵循 OpenID Connect Core 1.0 规范的用户信息端点，同时支持 POST 请求。访问令牌必须包含 openid 授权范围，按照 profile、email、phone、dept 授权范围返回对应的用户声明；以访问令牌的租户为准，无需传递租户请求头",
+    params(
+        ("Authorization" = String, Header, description = "Bearer Token，格式如 `Bearer <token>`", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = OidcUserInfoRespVo),
+        (status = 401, description = "访问令牌无效", body = OAuth2ErrorRespVo),
+        (status = 403, description = "访问令牌的授权范围不足", body = OAuth2ErrorRespVo),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_user_info(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+) -> Result<impl IntoResponse, OAuth2ErrorRespVo> {
+    let user_info = OAuth2OidcService::new(tenant)
+        .get_user_info(&principal)
+        .await?;
+    Ok(([(header::CACHE_CONTROL, "no-store")], Json(user_info)))
+}
@@ -0,0 +1,234 @@
+use crate::service::oauth2_open::OAuth2OpenService;
+use crate::vo::oauth2::oauth2_access_token_resp_vo::OAuth2AccessTokenRespVo;
+use crate::vo::oauth2::oauth2_authorize_client_vo::OAuth2AuthorizeClientVo;
+use crate::vo::oauth2::oauth2_authorize_info_req_vo::OAuth2AuthorizeInfoReqVo;
+use crate::vo::oauth2::oauth2_authorize_info_resp_vo::OAuth2AuthorizeInfoRespVo;
+use crate::vo::oauth2::oauth2_authorize_req_vo::OAuth2AuthorizeReqVo;
+use crate::vo::oauth2::oauth2_authorize_scope_vo::OAuth2AuthorizeScopeVo;
+use crate::vo::oauth2::oauth2_error_resp_vo::OAuth2ErrorRespVo;
+use crate::vo::oauth2::oauth2_introspect_req_vo::OAuth2IntrospectReqVo;
+use crate::vo::oauth2::oauth2_introspect_resp_vo::OAuth2IntrospectRespVo;
+use crate::vo::oauth2::oauth2_revoke_req_vo::OAuth2RevokeReqVo;
+use crate::vo::oauth2::oauth2_token_req_vo::OAuth2TokenReqVo;
+use axum::extract::rejection::FormRejection;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::IntoResponse;
+use axum::{Extension, Form, Json, Router, debug_handler, routing};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::errors::error::ApiError;
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_common_support::utils::web::valid::{ValidForm, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// OAuth2 开放接口模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        authorize,
+        approve_or_deny,
+        post_access_token,
+        introspect_token,
+        revoke_token,
+    ),
+    components(
+        schemas(
+            OAuth2AccessTokenRespVo,
+            OAuth2AuthorizeClientVo,
+            OAuth2AuthorizeInfoRespVo,
+            OAuth2AuthorizeScopeVo,
+            OAuth2ErrorRespVo,
+            OAuth2IntrospectRespVo,
+        )
+    ),
+    tags(
+        (name = "oauth2", description = "OAuth2.0 授权 API")
+    )
+)]
+pub struct OAuth2OpenApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/authorize", routing::get(authorize).post(approve_or_deny))
+        .route("/token", routing::post(post_access_token))
+        .route("/introspect", routing::post(introspect_token))
+        .route("/revoke", routing::post(revoke_token))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2/authorize",
+    tag = "oauth2",
+    summary = "获得授权信息",
+    description = "授权页展示客户端信息，以及当前用户对各授权范围的批准情况",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("clientId" = String, Query, description = "客户端编号", example = "default")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<OAuth2AuthorizeInfoRespVo>),
+        (status = 400, description = "客户端不存在或已禁用"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn authorize(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<OAuth2AuthorizeInfoReqVo>,
+) -> ApiJsonResult<OAuth2AuthorizeInfoRespVo> {
+    api_json_ok(
+        OAuth2OpenService::new(tenant)
+            .get_authorize_info(&principal, &params.client_id)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/authorize",
+    tag = "oauth2",
+    summary = "申请授权",
+    description = "授权码模式申请授权，表单参数：response_type、client_id、scope、redirect_uri、auto_approve、state、code_challenge、code_challenge_method。返回携带授权码的重定向地址；自动授权且用户尚未批准时返回空，需展示授权页",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "申请成功，返回重定向地址", body = ApiJsonResponse<Option<String>>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn approve_or_deny(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidForm(params): ValidForm<OAuth2AuthorizeReqVo>,
+) -> ApiJsonResult<Option<String>> {
+    api_json_ok(
+        OAuth2OpenService::new(tenant)
+            .approve_or_deny(&principal, params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/token",
+    tag = "oauth2",
+    summary = "获得访问令牌",
+    description = "遵循 RFC 6749 规范的令牌端点，支持 authorization_code、password、refresh_token、client_credentials 授权类型。表单参数：grant_type、code、redirect_uri、state、code_verifier、username、password、refresh_token、scope、client_id、client_secret。客户端可以使用 Basic 认证传递客户端编号与密钥；未使用密钥认证时只支持 authorization_code（必须使用 PKCE）、device_code 授权类型，其它授权类型必须使用密钥认证",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "客户端 Basic 认证，格式如 `Basic base64(client_id:client_secret)`，可选", example = "Basic ZGVmYXVsdDphZG1pbjEyMw==")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = OAuth2AccessTokenRespVo),
+        (status = 400, description = "请求参数错误或授权许可无效", body = OAuth2ErrorRespVo),
+        (status = 401, description = "客户端认证失败", body = OAuth2ErrorRespVo),
+    ),
+    security(
+        ("tenant_id" = [])
+    )
+)]
+#[debug_handler]
+async fn post_access_token(
+    Extension(tenant): Extension<TenantContextHolder>,
+    headers: HeaderMap,
+    client_info: ClientInfo,
+    params: Result<Form<OAuth2TokenReqVo>, FormRejection>,
+) -> Result<impl IntoResponse, OAuth2ErrorRespVo> {
+    let Form(params) = params.map_err(ApiError::Form)?;
+    let access_token = OAuth2OpenService::new(tenant)
+        .post_access_token(&headers, &client_info, params)
+        .await?;
+    // 令牌响应不允许缓存，参见 RFC 6749 5.1
+    Ok((
+        [
+            (header::CACHE_CONTROL, "no-store"),
+            (header::PRAGMA, "no-cache"),
+        ],
+        Json(access_token),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/introspect",
+    tag = "oauth2",
+    summary = "校验访问令牌",
+    description = "遵循 RFC 7662 规范的令牌校验端点，供资源服务器校验访问令牌。表单参数：token、token_type_hint、client_id、client_secret。客户端必须使用密钥认证；令牌不存在或已过期时返回 active = false",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "客户端 Basic 认证，格式如 `Basic base64(client_id:client_secret)`，可选", example = "Basic ZGVmYXVsdDphZG1pbjEyMw==")
+    ),
+    responses(
+        (status = 200, description = "校验成功", body = OAuth2IntrospectRespVo),
+        (status = 400, description = "请求参数错误", body = OAuth2ErrorRespVo),
+        (status = 401, description = "客户端认证失败", body = OAuth2ErrorRespVo),
+    ),
+    security(
+        ("tenant_id" = [])
+    )
+)]
+#[debug_handler]
+async fn introspect_token(
+    Extension(tenant): Extension<TenantContextHolder>,
+    headers: HeaderMap,
+    params: Result<Form<OAuth2IntrospectReqVo>, FormRejection>,
+) -> Result<impl IntoResponse, OAuth2ErrorRespVo> {
+    let Form(params) = params.map_err(ApiError::Form)?;
+    let introspection = OAuth2OpenService::new(tenant)
+        .introspect_token(&headers, params)
+        .await?;
+    Ok((
+        [
+            (header::CACHE_CONTROL, "no-store"),
+            (header::PRAGMA, "no-cache"),
+        ],
+        Json(introspection),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/oauth2/revoke",
+    tag = "oauth2",
+    summary = "撤销令牌",
+    description = "遵循 RFC 7009 规范的令牌撤销端点。表单参数：token、token_type_hint、client_id、client_secret。客户端必须使用密钥认证；撤销访问令牌时一并撤销其刷新令牌，撤销刷新令牌时一并撤销其派生的全部访问令牌。令牌无效或不属于该客户端时同样返回成功",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "客户端 Basic 认证，格式如 `Basic base64(client_id:client_secret)`，可选", example = "Basic ZGVmYXVsdDphZG1pbjEyMw==")
+    ),
+    responses(
+        (status = 200, description = "撤销成功"),
+        (status = 400, description = "请求参数错误", body = OAuth2ErrorRespVo),
+        (status = 401, description = "客户端认证失败", body = OAuth2ErrorRespVo),
+    ),
+    security(
+        ("tenant_id" = [])
+    )
+)]
+#[debug_handler]
+async fn revoke_token(
+    Extension(tenant): Extension<TenantContextHolder>,
+    headers: HeaderMap,
+    params: Result<Form<OAuth2RevokeReqVo>, FormRejection>,
+) -> Result<StatusCode, OAuth2ErrorRespVo> {
+    let Form(params) = params.map_err(ApiError::Form)?;
+    OAuth2OpenService::new(tenant)
+        .revoke_token(&headers, params)
+        .await?;
+    Ok(StatusCode::OK)
+}
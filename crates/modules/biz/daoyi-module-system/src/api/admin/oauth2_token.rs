@@ -0,0 +1,105 @@
+use crate::service::oauth2_token::OAuth2TokenService;
+use crate::vo::oauth2::oauth2_token_page_req_vo::OAuth2TokenPageReqVo;
+use crate::vo::oauth2::oauth2_token_resp_vo::OAuth2TokenRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder};
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_common_support::utils::web::valid::ValidQuery;
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// OAuth2 令牌（在线用户）模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_access_token_page,
+        delete_access_token,
+    ),
+    components(
+        schemas(
+            OAuth2TokenRespVo,
+        )
+    ),
+    tags(
+        (name = "oauth2-token", description = "OAuth2 令牌（在线用户）API")
+    )
+)]
+pub struct OAuth2TokenApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_access_token_page))
+        .route("/delete", routing::delete(delete_access_token))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/oauth2-token/page",
+    tag = "oauth2-token",
+    summary = "获得在线用户分页",
+    description = "分页查询当前租户未过期的访问令牌，即在线用户，包含用户、客户端、IP、登录时间与过期时间",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("userId" = Option<i64>, Query, description = "用户编号"),
+        ("userType" = Option<i32>, Query, description = "用户类型"),
+        ("clientId" = Option<String>, Query, description = "客户端编号"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<OAuth2TokenRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_access_token_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<OAuth2TokenPageReqVo>,
+) -> ApiJsonResult<Page<OAuth2TokenRespVo>> {
+    api_json_ok(
+        OAuth2TokenService::new(tenant)
+            .get_access_token_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/oauth2-token/delete",
+    tag = "oauth2-token",
+    summary = "强制退出",
+    description = "删除访问令牌所属的会话，包括刷新令牌、刷新令牌派生的全部访问令牌及其缓存，用户需要重新登录",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "访问令牌编号", example = 1024)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "访问令牌不存在或已过期"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_access_token(
+    Extension(tenant): Extension<TenantContextHolder>,
+    client_info: ClientInfo,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    OAuth2TokenService::new(tenant)
+        .force_logout(params.id, &client_info)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,110 @@
+use crate::service::operate_logger::OperateLogService;
+use crate::vo::logger::operate_log_page_req_vo::OperateLogPageReqVo;
+use crate::vo::logger::operate_log_resp_vo::OperateLogRespVo;
+use crate::vo::logger::operate_log_timeline_req_vo::OperateLogTimelineReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::common::Page;
+use daoyi_common::app::{AppState, TenantContextHolder};
+use daoyi_common_support::utils::web::valid::ValidQuery;
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_operate_log_page,
+        get_operate_log_timeline,
+    ),
+    components(
+        schemas(
+            OperateLogRespVo,
+        )
+    ),
+    tags(
+        (name = "operate-log", description = "操作日志 API")
+    )
+)]
+pub struct OperateLogApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_operate_log_page))
+        .route("/timeline", routing::get(get_operate_log_timeline))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/operate-log/page",
+    tag = "operate-log",
+    summary = "获得操作日志分页",
+    description = "分页查询当前租户的操作日志，记录谁在什么时间对哪条业务数据做了什么操作，以及操作结果",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("userId" = Option<i64>, Query, description = "用户编号"),
+        ("type" = Option<String>, Query, description = "操作模块类型", example = "SYSTEM 用户"),
+        ("subType" = Option<String>, Query, description = "操作名，模糊匹配"),
+        ("bizId" = Option<i64>, Query, description = "操作的业务编号"),
+        ("success" = Option<bool>, Query, description = "操作结果"),
+        ("beginCreateTime" = Option<String>, Query, description = "操作时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "操作时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<OperateLogRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_operate_log_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<OperateLogPageReqVo>,
+) -> ApiJsonResult<Page<OperateLogRespVo>> {
+    api_json_ok(
+        OperateLogService::new(tenant)
+            .get_operate_log_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/operate-log/timeline",
+    tag = "operate-log",
+    summary = "获得业务数据的变更历史",
+    description = "按操作时间正序返回一条业务数据的全部操作日志，例如用户、角色、租户、参数配置，更新操作变更的字段记录在拓展字段的 `changes` 中，密码、密钥等敏感字段已脱敏",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("type" = String, Query, description = "操作模块类型", example = "SYSTEM 用户"),
+        ("bizId" = i64, Query, description = "操作的业务编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<OperateLogRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_operate_log_timeline(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<OperateLogTimelineReqVo>,
+) -> ApiJsonResult<Vec<OperateLogRespVo>> {
+    api_json_ok(
+        OperateLogService::new(tenant)
+            .get_operate_log_timeline(params)
+            .await?,
+    )
+}
@@ -0,0 +1,252 @@
+use crate::service::post::PostService;
+use crate::vo::post::post_page_req_vo::PostPageReqVo;
+use crate::vo::post::post_resp_vo::PostRespVo;
+use crate::vo::post::post_save_req_vo::PostSaveReqVo;
+use crate::vo::post::post_simple_resp_vo::PostSimpleRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 岗位";
+
+/// 岗位管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_post_page,
+        get_post,
+        get_simple_post_list,
+        create_post,
+        update_post,
+        delete_post,
+    ),
+    components(
+        schemas(
+            PostRespVo,
+            PostSaveReqVo,
+            PostSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "post", description = "岗位管理 API")
+    )
+)]
+pub struct PostApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_post_page))
+        .route("/get", routing::get(get_post))
+        .route("/simple-list", routing::get(get_simple_post_list))
+        .route(
+            "/create",
+            routing::post(create_post).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建岗位")),
+        )
+        .route(
+            "/update",
+            routing::put(update_post).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新岗位")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_post).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除岗位")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/post/page",
+    tag = "post",
+    summary = "获得岗位分页列表",
+    description = "按编码、名称、状态分页查询岗位",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("code" = Option<String>, Query, description = "岗位编码，模糊匹配"),
+        ("name" = Option<String>, Query, description = "岗位名称，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "展示状态"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<PostRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_post_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<PostPageReqVo>,
+) -> ApiJsonResult<Page<PostRespVo>> {
+    api_json_ok(PostService::new(tenant).get_post_page(params).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/post/get",
+    tag = "post",
+    summary = "获得岗位信息",
+    description = "根据编号获得岗位详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "岗位编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<PostRespVo>),
+        (status = 400, description = "岗位不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_post(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<PostRespVo> {
+    api_json_ok(PostService::new(tenant).get_post(params.id).await?.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/post/simple-list",
+    tag = "post",
+    summary = "获取岗位全列表",
+    description = "只包含被开启的岗位，主要用于前端的下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<PostSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_post_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<PostSimpleRespVo>> {
+    api_json_ok(
+        PostService::new(tenant)
+            .get_post_list_by_status(CommonStatusEnum::Enable.status())
+            .await?
+            .into_iter()
+            .map(PostSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/post/create",
+    tag = "post",
+    summary = "创建岗位",
+    description = "创建岗位，岗位名称和编码在租户内唯一",
+    request_body = PostSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回岗位编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_post(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<PostSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        PostService::new(tenant)
+            .create_post(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/post/update",
+    tag = "post",
+    summary = "修改岗位",
+    description = "修改岗位信息",
+    request_body = PostSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_post(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<PostSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    PostService::new(tenant)
+        .update_post(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/post/delete",
+    tag = "post",
+    summary = "删除岗位",
+    description = "逻辑删除岗位，并解除用户与该岗位的关联",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "岗位编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "岗位不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_post(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    PostService::new(tenant)
+        .delete_post(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,463 @@
+use crate::service::admin_user::AdminUserService;
+use crate::service::permission::PermissionService;
+use crate::service::role::RoleService;
+use crate::vo::permission::permission_assign_role_data_scope_req_vo::PermissionAssignRoleDataScopeReqVo;
+use crate::vo::permission::permission_assign_role_menu_req_vo::PermissionAssignRoleMenuReqVo;
+use crate::vo::permission::permission_user_roles_req_vo::PermissionUserRolesReqVo;
+use crate::vo::role::role_page_req_vo::RolePageReqVo;
+use crate::vo::role::role_resp_vo::RoleRespVo;
+use crate::vo::role::role_save_req_vo::RoleSaveReqVo;
+use crate::vo::role::role_update_status_req_vo::RoleUpdateStatusReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use std::collections::HashSet;
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 角色";
+
+/// 角色管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_role_page,
+        get_role,
+        get_simple_role_list,
+        create_role,
+        update_role,
+        delete_role,
+        update_role_status,
+        assign_role_menu,
+        assign_role_data_scope,
+        get_role_menu_list,
+        get_user_role_list,
+    ),
+    components(
+        schemas(
+            RoleRespVo,
+            RoleSaveReqVo,
+            RoleUpdateStatusReqVo,
+            PermissionAssignRoleMenuReqVo,
+            PermissionAssignRoleDataScopeReqVo,
+        )
+    ),
+    tags(
+        (name = "role", description = "角色管理 API")
+    )
+)]
+pub struct RoleApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_role_page))
+        .route("/get", routing::get(get_role))
+        .route("/simple-list", routing::get(get_simple_role_list))
+        .route(
+            "/create",
+            routing::post(create_role).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建角色")),
+        )
+        .route(
+            "/update",
+            routing::put(update_role).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新角色")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_role).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除角色")),
+        )
+        .route(
+            "/update-status",
+            routing::put(update_role_status)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新角色状态")),
+        )
+        .route(
+            "/assign-menus",
+            routing::put(assign_role_menu)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "分配角色菜单")),
+        )
+        .route(
+            "/assign-data-scope",
+            routing::put(assign_role_data_scope)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "分配角色数据权限")),
+        )
+        .route("/list-menus", routing::get(get_role_menu_list))
+        .route("/list-by-user", routing::get(get_user_role_list))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/role/page",
+    tag = "role",
+    summary = "获得角色分页列表",
+    description = "按名称、标识、状态、创建时间分页查询角色",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "角色名称，模糊匹配"),
+        ("code" = Option<String>, Query, description = "角色标识，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "角色状态"),
+        ("beginCreateTime" = Option<String>, Query, description = "创建时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "创建时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<RoleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_role_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<RolePageReqVo>,
+) -> ApiJsonResult<Page<RoleRespVo>> {
+    api_json_ok(RoleService::new(tenant).get_role_page(params).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/role/get",
+    tag = "role",
+    summary = "获得角色详情",
+    description = "根据编号获得角色详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "角色编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<RoleRespVo>),
+        (status = 400, description = "角色不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_role(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<RoleRespVo> {
+    api_json_ok(RoleService::new(tenant).get_role(params.id).await?.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/role/simple-list",
+    tag = "role",
+    summary = "获得角色精简列表",
+    description = "只包含被开启的角色，主要用于前端的下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<RoleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_role_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<RoleRespVo>> {
+    api_json_ok(
+        RoleService::new(tenant)
+            .get_role_list_by_status(CommonStatusEnum::Enable.status())
+            .await?
+            .into_iter()
+            .map(RoleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/role/create",
+    tag = "role",
+    summary = "创建角色",
+    description = "创建自定义角色，默认拥有全部数据权限",
+    request_body = RoleSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回角色编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_role(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<RoleSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        RoleService::new(tenant)
+            .create_role(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/role/update",
+    tag = "role",
+    summary = "修改角色",
+    description = "修改自定义角色，内置角色不允许修改",
+    request_body = RoleSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_role(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<RoleSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    RoleService::new(tenant)
+        .update_role(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/role/delete",
+    tag = "role",
+    summary = "删除角色",
+    description = "逻辑删除角色，并清理角色的菜单和用户关联",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "角色编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "角色不存在或为内置角色"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_role(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    RoleService::new(tenant)
+        .delete_role(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/role/update-status",
+    tag = "role",
+    summary = "修改角色状态",
+    description = "启用或停用角色",
+    request_body = RoleUpdateStatusReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_role_status(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<RoleUpdateStatusReqVo>,
+) -> ApiJsonResult<bool> {
+    RoleService::new(tenant)
+        .update_role_status(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/role/assign-menus",
+    tag = "role",
+    summary = "赋予角色菜单",
+    description = "覆盖式设置角色拥有的菜单",
+    request_body = PermissionAssignRoleMenuReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "赋予成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn assign_role_menu(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<PermissionAssignRoleMenuReqVo>,
+) -> ApiJsonResult<bool> {
+    // 校验角色存在
+    RoleService::new(tenant).get_role(params.role_id).await?;
+    PermissionService::new(tenant)
+        .assign_role_menu(params.role_id, params.menu_ids, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/role/assign-data-scope",
+    tag = "role",
+    summary = "赋予角色数据权限",
+    description = "设置角色的数据范围，指定部门时需要传部门编号列表",
+    request_body = PermissionAssignRoleDataScopeReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "赋予成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn assign_role_data_scope(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<PermissionAssignRoleDataScopeReqVo>,
+) -> ApiJsonResult<bool> {
+    RoleService::new(tenant)
+        .update_role_data_scope(
+            params.role_id,
+            params.data_scope,
+            params.data_scope_dept_ids,
+            &principal,
+        )
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/role/list-menus",
+    tag = "role",
+    summary = "获得角色拥有的菜单编号",
+    description = "获得角色拥有的菜单编号列表",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "角色编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<i64>>),
+        (status = 400, description = "角色不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_role_menu_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<HashSet<i64>> {
+    let role = RoleService::new(tenant).get_role(params.id).await?;
+    api_json_ok(
+        PermissionService::new(tenant)
+            .get_role_menu_list_by_role_id([role.id], [role.code])
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/role/list-by-user",
+    tag = "role",
+    summary = "获得用户拥有的角色",
+    description = "获得指定用户拥有的角色列表",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("userId" = i64, Query, description = "用户编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<RoleRespVo>>),
+        (status = 400, description = "用户不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_user_role_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<PermissionUserRolesReqVo>,
+) -> ApiJsonResult<Vec<RoleRespVo>> {
+    // 校验用户存在
+    AdminUserService::new(tenant)
+        .get_user(params.user_id)
+        .await?;
+    let role_ids = PermissionService::new(tenant)
+        .get_user_role_id_list_by_user_id(params.user_id)
+        .await?;
+    api_json_ok(
+        RoleService::new(tenant)
+            .get_role_list(role_ids)
+            .await?
+            .into_iter()
+            .filter(|role| role.deleted == 0)
+            .map(RoleRespVo::from)
+            .collect(),
+    )
+}
@@ -0,0 +1,278 @@
+use crate::service::tenant::TenantService;
+use crate::vo::tenant::tenant_page_req_vo::TenantPageReqVo;
+use crate::vo::tenant::tenant_resp_vo::TenantRespVo;
+use crate::vo::tenant::tenant_save_req_vo::TenantSaveReqVo;
+use crate::vo::tenant::tenant_simple_resp_vo::TenantSimpleRespVo;
+use axum::extract::Request;
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 租户";
+
+/// 租户管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_tenant_page,
+        get_tenant,
+        get_simple_tenant_list,
+        create_tenant,
+        update_tenant,
+        delete_tenant,
+    ),
+    components(
+        schemas(
+            TenantRespVo,
+            TenantSaveReqVo,
+            TenantSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "tenant", description = "租户管理 API")
+    )
+)]
+pub struct TenantApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_tenant_page))
+        .route("/get", routing::get(get_tenant))
+        .route("/simple-list", routing::get(get_simple_tenant_list))
+        .route(
+            "/create",
+            routing::post(create_tenant).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建租户")),
+        )
+        .route(
+            "/update",
+            routing::put(update_tenant).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新租户")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_tenant)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除租户")),
+        )
+        .route_layer(middleware::from_fn(system_tenant_only))
+}
+
+/// 只允许系统租户的用户访问，避免普通租户修改自己或其它租户的套餐、过期时间与账号额度
+pub(crate) async fn system_tenant_only(
+    Extension(principal): Extension<Principal>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    TenantService::new(TenantContextHolder::set_tenant_id(principal.tenant_id))
+        .validate_system_tenant(principal.tenant_id)
+        .await?;
+    Ok(next.run(request).await)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant/page",
+    tag = "tenant",
+    summary = "获得租户分页",
+    description = "按租户名、联系人、联系手机、状态、创建时间分页查询租户",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "租户名，模糊匹配"),
+        ("contactName" = Option<String>, Query, description = "联系人，模糊匹配"),
+        ("contactMobile" = Option<String>, Query, description = "联系手机，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "租户状态"),
+        ("beginCreateTime" = Option<String>, Query, description = "创建时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "创建时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<TenantRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_tenant_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<TenantPageReqVo>,
+) -> ApiJsonResult<Page<TenantRespVo>> {
+    api_json_ok(TenantService::new(tenant).get_tenant_page(params).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant/get",
+    tag = "tenant",
+    summary = "获得租户",
+    description = "根据编号获得租户详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "租户编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<TenantRespVo>),
+        (status = 400, description = "租户不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_tenant(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<TenantRespVo> {
+    api_json_ok(
+        TenantService::new(tenant)
+            .get_tenant(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant/simple-list",
+    tag = "tenant",
+    summary = "获取租户精简信息列表",
+    description = "只包含被开启的租户，用于租户切换等下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<TenantSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_tenant_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<TenantSimpleRespVo>> {
+    api_json_ok(
+        TenantService::new(tenant)
+            .get_tenant_list_by_status(CommonStatusEnum::Enable.status())
+            .await?
+            .into_iter()
+            .map(TenantSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/tenant/create",
+    tag = "tenant",
+    summary = "创建租户",
+    description = "在一个事务中创建租户、租户管理员角色（授予套餐菜单）与管理员用户",
+    request_body = TenantSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回租户编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_tenant(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<TenantSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        TenantService::new(tenant)
+            .create_tenant(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/tenant/update",
+    tag = "tenant",
+    summary = "更新租户",
+    description = "更新租户信息，套餐变化时同步租户角色的菜单，系统租户不允许修改",
+    request_body = TenantSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_tenant(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<TenantSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    TenantService::new(tenant)
+        .update_tenant(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/tenant/delete",
+    tag = "tenant",
+    summary = "删除租户",
+    description = "逻辑删除租户，系统租户不允许删除",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "租户编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "租户不存在或为系统租户"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_tenant(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    TenantService::new(tenant)
+        .delete_tenant(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,269 @@
+use crate::api::admin::tenant::system_tenant_only;
+use crate::service::tenant_package::TenantPackageService;
+use crate::vo::tenant_package::tenant_package_page_req_vo::TenantPackagePageReqVo;
+use crate::vo::tenant_package::tenant_package_resp_vo::TenantPackageRespVo;
+use crate::vo::tenant_package::tenant_package_save_req_vo::TenantPackageSaveReqVo;
+use crate::vo::tenant_package::tenant_package_simple_resp_vo::TenantPackageSimpleRespVo;
+use axum::middleware;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::web::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 租户套餐";
+
+/// 租户套餐模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_tenant_package_page,
+        get_tenant_package,
+        get_simple_tenant_package_list,
+        create_tenant_package,
+        update_tenant_package,
+        delete_tenant_package,
+    ),
+    components(
+        schemas(
+            TenantPackageRespVo,
+            TenantPackageSaveReqVo,
+            TenantPackageSimpleRespVo,
+        )
+    ),
+    tags(
+        (name = "tenant-package", description = "租户套餐 API")
+    )
+)]
+pub struct TenantPackageApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_tenant_package_page))
+        .route("/get", routing::get(get_tenant_package))
+        .route("/simple-list", routing::get(get_simple_tenant_package_list))
+        .route(
+            "/create",
+            routing::post(create_tenant_package)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建租户套餐")),
+        )
+        .route(
+            "/update",
+            routing::put(update_tenant_package)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新租户套餐")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_tenant_package)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除租户套餐")),
+        )
+        .route_layer(middleware::from_fn(system_tenant_only))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant-package/page",
+    tag = "tenant-package",
+    summary = "获得租户套餐分页",
+    description = "按套餐名、备注、状态、创建时间分页查询租户套餐",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("name" = Option<String>, Query, description = "套餐名，模糊匹配"),
+        ("remark" = Option<String>, Query, description = "备注，模糊匹配"),
+        ("status" = Option<i32>, Query, description = "状态"),
+        ("beginCreateTime" = Option<String>, Query, description = "创建时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "创建时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<TenantPackageRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_tenant_package_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<TenantPackagePageReqVo>,
+) -> ApiJsonResult<Page<TenantPackageRespVo>> {
+    api_json_ok(
+        TenantPackageService::new(tenant)
+            .get_tenant_package_page(params)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant-package/get",
+    tag = "tenant-package",
+    summary = "获得租户套餐",
+    description = "根据编号获得租户套餐详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "租户套餐编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<TenantPackageRespVo>),
+        (status = 400, description = "租户套餐不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_tenant_package(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<TenantPackageRespVo> {
+    api_json_ok(
+        TenantPackageService::new(tenant)
+            .get_tenant_package(params.id)
+            .await?
+            .into(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/tenant-package/simple-list",
+    tag = "tenant-package",
+    summary = "获取租户套餐精简信息列表",
+    description = "只包含被开启的租户套餐，主要用于前端的下拉选项",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<TenantPackageSimpleRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_simple_tenant_package_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+) -> ApiJsonResult<Vec<TenantPackageSimpleRespVo>> {
+    api_json_ok(
+        TenantPackageService::new(tenant)
+            .get_tenant_package_list_by_status(CommonStatusEnum::Enable.status())
+            .await?
+            .into_iter()
+            .map(TenantPackageSimpleRespVo::from)
+            .collect(),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/tenant-package/create",
+    tag = "tenant-package",
+    summary = "创建租户套餐",
+    description = "创建租户套餐，套餐名唯一",
+    request_body = TenantPackageSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "创建成功，返回租户套餐编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_tenant_package(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<TenantPackageSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        TenantPackageService::new(tenant)
+            .create_tenant_package(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/tenant-package/update",
+    tag = "tenant-package",
+    summary = "更新租户套餐",
+    description = "更新租户套餐，菜单变化时同步使用该套餐的租户的角色菜单",
+    request_body = TenantPackageSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_tenant_package(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<TenantPackageSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    TenantPackageService::new(tenant)
+        .update_tenant_package(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/tenant-package/delete",
+    tag = "tenant-package",
+    summary = "删除租户套餐",
+    description = "逻辑删除租户套餐，正在被租户使用时不允许删除",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "租户套餐编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "租户套餐不存在或正在使用"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_tenant_package(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    TenantPackageService::new(tenant)
+        .delete_tenant_package(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -0,0 +1,349 @@
+use crate::service::admin_user::AdminUserService;
+use crate::service::permission::PermissionService;
+use crate::service::role::RoleService;
+use crate::vo::permission::permission_assign_user_role_req_vo::PermissionAssignUserRoleReqVo;
+use crate::vo::user::user_page_req_vo::UserPageReqVo;
+use crate::vo::user::user_resp_vo::UserRespVo;
+use crate::vo::user::user_save_req_vo::UserSaveReqVo;
+use crate::vo::user::user_update_password_req_vo::UserUpdatePasswordReqVo;
+use crate::vo::user::user_update_status_req_vo::UserUpdateStatusReqVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::OperateLogLayer;
+use daoyi_common::app::common::{IdParams, Page};
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::{ValidDictJson, ValidJson, ValidQuery};
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 操作日志的操作模块类型
+const OPERATE_LOG_TYPE: &str = "SYSTEM 用户";
+
+/// 用户管理模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_user_page,
+        get_user,
+        create_user,
+        update_user,
+        delete_user,
+        update_user_status,
+        update_user_password,
+        assign_user_role,
+    ),
+    components(
+        schemas(
+            UserRespVo,
+            UserSaveReqVo,
+            UserUpdateStatusReqVo,
+            UserUpdatePasswordReqVo,
+            PermissionAssignUserRoleReqVo,
+        )
+    ),
+    tags(
+        (name = "user", description = "用户管理 API")
+    )
+)]
+pub struct UserApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/page", routing::get(get_user_page))
+        .route("/get", routing::get(get_user))
+        .route(
+            "/create",
+            routing::post(create_user).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "创建用户")),
+        )
+        .route(
+            "/update",
+            routing::put(update_user).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新用户")),
+        )
+        .route(
+            "/delete",
+            routing::delete(delete_user).layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "删除用户")),
+        )
+        .route(
+            "/update-status",
+            routing::put(update_user_status)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "更新用户状态")),
+        )
+        .route(
+            "/reset-password",
+            routing::put(update_user_password)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "重置用户密码")),
+        )
+        .route(
+            "/assign-roles",
+            routing::put(assign_user_role)
+                .layer(OperateLogLayer::new(OPERATE_LOG_TYPE, "分配用户角色")),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/user/page",
+    tag = "user",
+    summary = "获得用户分页列表",
+    description = "按关键字、部门（含子部门）、状态、创建时间分页查询用户",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("keyword" = Option<String>, Query, description = "关键字，模糊匹配用户账号、昵称、手机号"),
+        ("deptId" = Option<i64>, Query, description = "部门编号，同时包含其所有子部门"),
+        ("status" = Option<i32>, Query, description = "用户状态"),
+        ("beginCreateTime" = Option<String>, Query, description = "创建时间的开始", example = "2024-01-01 00:00:00"),
+        ("endCreateTime" = Option<String>, Query, description = "创建时间的结束", example = "2024-12-31 23:59:59"),
+        ("page" = Option<u64>, Query, description = "页码，从 1 开始", example = 1),
+        ("size" = Option<u64>, Query, description = "每页数量", example = 10)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Page<UserRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_user_page(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<UserPageReqVo>,
+) -> ApiJsonResult<Page<UserRespVo>> {
+    api_json_ok(AdminUserService::new(tenant).get_user_page(params).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/user/get",
+    tag = "user",
+    summary = "获得用户详情",
+    description = "根据编号获得用户详情",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "用户编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<UserRespVo>),
+        (status = 400, description = "用户不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_user(
+    Extension(tenant): Extension<TenantContextHolder>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<UserRespVo> {
+    api_json_ok(
+        AdminUserService::new(tenant)
+            .get_user_resp(params.id)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin-api/system/user/create",
+    tag = "user",
+    summary = "新增用户",
+    description = "新增用户，未传密码时使用系统初始密码",
+    request_body = UserSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "新增成功，返回用户编号", body = ApiJsonResponse<i64>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn create_user(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidDictJson(params): ValidDictJson<UserSaveReqVo>,
+) -> ApiJsonResult<i64> {
+    api_json_ok(
+        AdminUserService::new(tenant)
+            .create_user(params, &principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/user/update",
+    tag = "user",
+    summary = "修改用户",
+    description = "修改用户基本信息，不修改密码",
+    request_body = UserSaveReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_user(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidDictJson(params): ValidDictJson<UserSaveReqVo>,
+) -> ApiJsonResult<bool> {
+    AdminUserService::new(tenant)
+        .update_user(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/user/delete",
+    tag = "user",
+    summary = "删除用户",
+    description = "逻辑删除用户，并清理用户的角色关联",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "用户编号", example = 1)
+    ),
+    responses(
+        (status = 200, description = "删除成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "用户不存在"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_user(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    AdminUserService::new(tenant)
+        .delete_user(params.id, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/user/update-status",
+    tag = "user",
+    summary = "修改用户状态",
+    description = "启用或停用用户",
+    request_body = UserUpdateStatusReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "修改成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_user_status(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidDictJson(params): ValidDictJson<UserUpdateStatusReqVo>,
+) -> ApiJsonResult<bool> {
+    AdminUserService::new(tenant)
+        .update_user_status(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/user/reset-password",
+    tag = "user",
+    summary = "重置用户密码",
+    description = "管理员重置指定用户的密码",
+    request_body = UserUpdatePasswordReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "重置成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn update_user_password(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<UserUpdatePasswordReqVo>,
+) -> ApiJsonResult<bool> {
+    AdminUserService::new(tenant)
+        .update_user_password(params, &principal)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin-api/system/user/assign-roles",
+    tag = "user",
+    summary = "赋予用户角色",
+    description = "覆盖式设置用户拥有的角色",
+    request_body = PermissionAssignUserRoleReqVo,
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "赋予成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "请求参数错误"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn assign_user_role(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidJson(params): ValidJson<PermissionAssignUserRoleReqVo>,
+) -> ApiJsonResult<bool> {
+    // 校验用户和角色存在
+    AdminUserService::new(tenant)
+        .get_user(params.user_id)
+        .await?;
+    RoleService::new(tenant)
+        .validate_role_list(params.role_ids.iter().copied())
+        .await?;
+    PermissionService::new(tenant)
+        .assign_user_role(params.user_id, params.role_ids, &principal)
+        .await?;
+    api_json_ok(true)
+}
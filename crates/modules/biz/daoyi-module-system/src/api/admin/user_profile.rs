@@ -0,0 +1,132 @@
+use crate::service::oauth2_token::OAuth2TokenService;
+use crate::vo::user::user_session_resp_vo::UserSessionRespVo;
+use axum::{Extension, Router, debug_handler, routing};
+use daoyi_common::app::common::IdParams;
+use daoyi_common::app::{AppState, TenantContextHolder, auth::Principal};
+use daoyi_common_support::utils::web::valid::ValidQuery;
+use daoyi_common_support::utils::{
+    errors::error::{ApiJsonResult, api_json_ok},
+    web::response::ApiJsonResponse,
+};
+use utoipa::OpenApi;
+
+/// 用户个人中心模块 OpenAPI 文档
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_user_session_list,
+        delete_user_session,
+        delete_user_sessions,
+    ),
+    components(
+        schemas(
+            UserSessionRespVo,
+        )
+    ),
+    tags(
+        (name = "user-profile", description = "用户个人中心 API")
+    )
+)]
+pub struct UserProfileApiDoc;
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/sessions",
+            routing::get(get_user_session_list).delete(delete_user_session),
+        )
+        .route("/sessions/all", routing::delete(delete_user_sessions))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin-api/system/user/profile/sessions",
+    tag = "user-profile",
+    summary = "获得我的登录会话列表",
+    description = "获得当前用户未过期的登录会话，包括登录 IP、浏览器、操作系统与登录时间，并标记当前会话",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiJsonResponse<Vec<UserSessionRespVo>>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn get_user_session_list(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+) -> ApiJsonResult<Vec<UserSessionRespVo>> {
+    api_json_ok(
+        OAuth2TokenService::new(tenant)
+            .get_user_session_list(&principal)
+            .await?,
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/user/profile/sessions",
+    tag = "user-profile",
+    summary = "撤销我的登录会话",
+    description = "撤销当前用户的指定登录会话，该会话的访问令牌与刷新令牌立即失效",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx"),
+        ("id" = i64, Query, description = "会话编号", example = 1024)
+    ),
+    responses(
+        (status = 200, description = "撤销成功", body = ApiJsonResponse<bool>),
+        (status = 400, description = "登录会话不存在或已过期"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_user_session(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+    ValidQuery(params): ValidQuery<IdParams>,
+) -> ApiJsonResult<bool> {
+    OAuth2TokenService::new(tenant)
+        .remove_user_session(&principal, params.id)
+        .await?;
+    api_json_ok(true)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin-api/system/user/profile/sessions/all",
+    tag = "user-profile",
+    summary = "退出全部登录会话",
+    description = "撤销当前用户的全部登录会话，包括当前会话，所有设备都需要重新登录",
+    params(
+        ("tenant-id" = String, Header, description = "租户ID，例如 1", example = "1"),
+        ("Authorization" = Option<String>, Header, description = "Bearer Token，格式如 `Bearer <token>`，可选", example = "Bearer xxx")
+    ),
+    responses(
+        (status = 200, description = "退出成功", body = ApiJsonResponse<bool>),
+        (status = 401, description = "未授权"),
+    ),
+    security(
+        ("tenant_id" = []),
+        ("bearer_auth" = [])
+    )
+)]
+#[debug_handler]
+async fn delete_user_sessions(
+    Extension(tenant): Extension<TenantContextHolder>,
+    Extension(principal): Extension<Principal>,
+) -> ApiJsonResult<bool> {
+    OAuth2TokenService::new(tenant)
+        .remove_user_sessions(&principal)
+        .await?;
+    api_json_ok(true)
+}
@@ -1,13 +1,41 @@
 use daoyi_common::app::{self, openapi};
 use daoyi_module_system::{
     api,
-    api::admin::auth::AuthApiDoc,
+    api::admin::{
+        auth::AuthApiDoc, dept::DeptApiDoc, dict_data::DictDataApiDoc, dict_type::DictTypeApiDoc,
+        login_log::LoginLogApiDoc, menu::MenuApiDoc, notice::NoticeApiDoc,
+        oauth2_client::OAuth2ClientApiDoc, oauth2_device::OAuth2DeviceApiDoc,
+        oauth2_oidc::OAuth2OidcApiDoc, oauth2_open::OAuth2OpenApiDoc,
+        oauth2_token::OAuth2TokenApiDoc, operate_log::OperateLogApiDoc, post::PostApiDoc,
+        role::RoleApiDoc, tenant::TenantApiDoc, tenant_package::TenantPackageApiDoc,
+        user::UserApiDoc, user_profile::UserProfileApiDoc,
+    },
 };
 use utoipa::OpenApi;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let doc = openapi::build_openapi_with(&[AuthApiDoc::openapi()]);
+    let doc = openapi::build_openapi_with(&[
+        AuthApiDoc::openapi(),
+        UserApiDoc::openapi(),
+        RoleApiDoc::openapi(),
+        MenuApiDoc::openapi(),
+        DeptApiDoc::openapi(),
+        PostApiDoc::openapi(),
+        DictTypeApiDoc::openapi(),
+        DictDataApiDoc::openapi(),
+        NoticeApiDoc::openapi(),
+        TenantApiDoc::openapi(),
+        TenantPackageApiDoc::openapi(),
+        OAuth2ClientApiDoc::openapi(),
+        OAuth2OpenApiDoc::openapi(),
+        OAuth2OidcApiDoc::openapi(),
+        OAuth2DeviceApiDoc::openapi(),
+        OAuth2TokenApiDoc::openapi(),
+        UserProfileApiDoc::openapi(),
+        LoginLogApiDoc::openapi(),
+        OperateLogApiDoc::openapi(),
+    ]);
     openapi::register_openapi(doc);
     app::run(api::create_router()).await
 }
@@ -1,4 +1,5 @@
 use crate::service::admin_user::AdminUserService;
+use crate::service::login_logger::LoginLogService;
 use crate::service::menu::MenuService;
 use crate::service::oauth2_token::OAuth2TokenService;
 use crate::service::permission::PermissionService;
@@ -9,20 +10,23 @@ use crate::vo::auth::auth_login_resp_vo::AuthLoginRespVo;
 use crate::vo::auth::auth_permission_info_resp_vo::AuthPermissionInfoRespVo;
 use crate::vo::auth::auth_register_req_vo::AuthRegisterReqVo;
 use crate::vo::auth::auth_sms_send_req_vo::AuthSmsSendReqVo;
+use crate::vo::logger::login_log_create_req_dto::LoginLogCreateReqDTO;
 use crate::vo::sms::sms_code_send_req_dto::SmsCodeSendReqDTO;
 use crate::vo::user::user_vo::UserVo;
 use daoyi_common::app::TenantContextHolder;
 use daoyi_common::app::auth::Principal;
 use daoyi_common::{config, impl_tenant_instance};
 use daoyi_common_support::utils::enumeration::{
-    CommonStatusEnum, SmsSceneEnum, UserTypeEnum, oauth2_client_constants,
+    CommonStatusEnum, LoginLogTypeEnum, LoginResultEnum, SmsSceneEnum, UserTypeEnum,
+    oauth2_client_constants,
 };
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
 use daoyi_common_support::utils::errors::{
     AUTH_LOGIN_BAD_CREDENTIALS, AUTH_LOGIN_USER_DISABLED, AUTH_MOBILE_NOT_EXISTS,
     AUTH_REGISTER_CAPTCHA_CODE_ERROR,
 };
-use daoyi_common_support::utils::{RANDOM_PASSWORD, enumeration, verify_password};
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_common_support::utils::{RANDOM_PASSWORD, verify_password};
 use daoyi_entities_system::entity::system_users;
 
 pub struct AdminAuthService {
@@ -55,7 +59,11 @@ impl AdminAuthService {
             .await?;
         Ok(())
     }
-    pub async fn register(&self, req_vo: AuthRegisterReqVo) -> ApiResult<AuthLoginRespVo> {
+    pub async fn register(
+        &self,
+        req_vo: AuthRegisterReqVo,
+        client_info: &ClientInfo,
+    ) -> ApiResult<AuthLoginRespVo> {
         // 1. 校验验证码
         self.validate_captcha(req_vo.captcha_verification.as_deref())
             .await?;
@@ -66,7 +74,8 @@ impl AdminAuthService {
         self.create_token_after_login_success(
             user_id,
             &req_vo.username,
-            enumeration::LoginLogTypeEnum::LoginUsername,
+            LoginLogTypeEnum::LoginUsername,
+            client_info,
         )
         .await
     }
@@ -87,28 +96,57 @@ impl AdminAuthService {
         Ok(())
     }
 
-    pub async fn refresh_token(&self, refresh_token: String) -> ApiResult<AuthLoginRespVo> {
+    pub async fn refresh_token(
+        &self,
+        refresh_token: String,
+        client_info: &ClientInfo,
+    ) -> ApiResult<AuthLoginRespVo> {
         let token = OAuth2TokenService::new(self.tenant.clone())
-            .refresh_access_token(refresh_token, oauth2_client_constants::CLIENT_ID_DEFAULT)
+            .refresh_access_token(
+                refresh_token,
+                oauth2_client_constants::CLIENT_ID_DEFAULT,
+                client_info,
+            )
             .await?;
         // 构建返回结果
         Ok(token.into())
     }
 
-    pub async fn logout(&self, principal: Principal) -> ApiResult<()> {
+    pub async fn logout(&self, principal: Principal, client_info: &ClientInfo) -> ApiResult<()> {
         OAuth2TokenService::new(self.tenant.clone())
             .remove_access_token(&principal.token)
-            .await
+            .await?;
+        // 记录登出日志，用户可能已被删除
+        let username = AdminUserService::new(self.tenant)
+            .get_user_list([principal.user_id])
+            .await?
+            .into_iter()
+            .next()
+            .map(|user| user.username)
+            .unwrap_or_default();
+        self.create_login_log(
+            principal.user_id,
+            &username,
+            LoginLogTypeEnum::LogoutSelf,
+            LoginResultEnum::Success,
+            client_info,
+        )
+        .await
     }
 
-    pub async fn login(&self, req_vo: AuthLoginReqVo) -> ApiResult<AuthLoginRespVo> {
+    pub async fn login(
+        &self,
+        req_vo: AuthLoginReqVo,
+        client_info: &ClientInfo,
+    ) -> ApiResult<AuthLoginRespVo> {
         let user = self
-            .authenticate(&req_vo.username, &req_vo.password)
+            .authenticate(&req_vo.username, &req_vo.password, client_info)
             .await?;
         self.create_token_after_login_success(
             user.id,
             &user.username,
-            enumeration::LoginLogTypeEnum::LoginUsername,
+            LoginLogTypeEnum::LoginUsername,
+            client_info,
         )
         .await
     }
@@ -116,10 +154,10 @@ impl AdminAuthService {
     pub async fn create_token_after_login_success(
         &self,
         user_id: i64,
-        _username: &str,
-        _login_type: enumeration::LoginLogTypeEnum,
+        username: &str,
+        login_type: LoginLogTypeEnum,
+        client_info: &ClientInfo,
     ) -> ApiResult<AuthLoginRespVo> {
-        // 插入登陆日志
         // 创建访问令牌
         let token = OAuth2TokenService::new(self.tenant.clone())
             .create_access_token(
@@ -127,35 +165,95 @@ impl AdminAuthService {
                 UserTypeEnum::Admin.value(),
                 oauth2_client_constants::CLIENT_ID_DEFAULT,
                 vec![],
+                client_info,
             )
             .await?;
+        // 插入登陆日志
+        self.create_login_log(
+            user_id,
+            username,
+            login_type,
+            LoginResultEnum::Success,
+            client_info,
+        )
+        .await?;
         // 构建返回结果
         Ok(token.into())
     }
 
+    /// 校验账号密码，校验失败时记录登录日志
     pub async fn authenticate(
         &self,
         username: &str,
         password: &str,
+        client_info: &ClientInfo,
     ) -> ApiResult<system_users::Model> {
+        let login_type = LoginLogTypeEnum::LoginUsername;
         let user = AdminUserService::new(self.tenant.clone())
             .get_user_by_username(username)
             .await?;
         if user.is_none() {
             // 模拟校验密码，密码错误，模拟耗时
             let _ = verify_password(password, &RANDOM_PASSWORD);
+            self.create_login_log(
+                0,
+                username,
+                login_type,
+                LoginResultEnum::BadCredentials,
+                client_info,
+            )
+            .await?;
             return Err(ApiError::BizCode(AUTH_LOGIN_BAD_CREDENTIALS));
         }
         let user = user.unwrap();
         let result = verify_password(password, &user.password)?;
         if !result {
+            self.create_login_log(
+                user.id,
+                username,
+                login_type,
+                LoginResultEnum::BadCredentials,
+                client_info,
+            )
+            .await?;
             return Err(ApiError::BizCode(AUTH_LOGIN_BAD_CREDENTIALS));
         }
         if CommonStatusEnum::is_disable(user.status) {
+            self.create_login_log(
+                user.id,
+                username,
+                login_type,
+                LoginResultEnum::UserDisabled,
+                client_info,
+            )
+            .await?;
             return Err(ApiError::BizCode(AUTH_LOGIN_USER_DISABLED));
         }
         Ok(user)
     }
+
+    /// 记录管理后台用户的登录日志
+    pub async fn create_login_log(
+        &self,
+        user_id: i64,
+        username: &str,
+        log_type: LoginLogTypeEnum,
+        result: LoginResultEnum,
+        client_info: &ClientInfo,
+    ) -> ApiResult<()> {
+        LoginLogService::new(self.tenant)
+            .create_login_log(LoginLogCreateReqDTO {
+                log_type,
+                result,
+                trace_id: client_info.trace_id.clone(),
+                user_agent: client_info.user_agent.clone(),
+                user_id,
+                user_ip: client_info.ip.clone(),
+                user_type: UserTypeEnum::Admin.value(),
+                username: username.to_string(),
+            })
+            .await
+    }
     pub async fn get_permission_info(
         &self,
         principal: Principal,
@@ -1,22 +1,35 @@
 use crate::service::dept::DeptService;
+use crate::service::permission::PermissionService;
 use crate::service::post::PostService;
 use crate::service::tenant::TenantService;
 use crate::vo::auth::auth_register_req_vo::AuthRegisterReqVo;
+use crate::vo::user::user_page_req_vo::UserPageReqVo;
+use crate::vo::user::user_resp_vo::UserRespVo;
+use crate::vo::user::user_save_req_vo::UserSaveReqVo;
+use crate::vo::user::user_update_password_req_vo::UserUpdatePasswordReqVo;
+use crate::vo::user::user_update_status_req_vo::UserUpdateStatusReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
 use daoyi_common::app::{TenantContextHolder, database};
 use daoyi_common::impl_tenant_instance;
 use daoyi_common::service::ConfigApi;
-use daoyi_common_support::support::orm::create_with_common_fields;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_change_log,
+};
 use daoyi_common_support::utils::encode_password;
 use daoyi_common_support::utils::enumeration::CommonStatusEnum;
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
 use daoyi_common_support::utils::errors::{
-    USER_COUNT_MAX, USER_EMAIL_EXISTS, USER_MOBILE_EXISTS, USER_NOT_EXISTS, USER_REGISTER_DISABLED,
-    USER_USERNAME_EXISTS,
+    USER_COUNT_MAX, USER_EMAIL_EXISTS, USER_IMPORT_INIT_PASSWORD, USER_MOBILE_EXISTS,
+    USER_NOT_EXISTS, USER_REGISTER_DISABLED, USER_USERNAME_EXISTS,
 };
 use daoyi_entities_system::entity::prelude::SystemUsers;
 use daoyi_entities_system::entity::system_users;
-use sea_orm::Set;
 use sea_orm::entity::prelude::*;
+use sea_orm::{
+    Condition, IntoActiveModel, QueryOrder, QueryTrait, Set, TransactionTrait, Unchanged,
+};
+use std::collections::HashMap;
 
 pub struct AdminUserService {
     tenant: TenantContextHolder,
@@ -31,7 +44,7 @@ impl AdminUserService {
     }
 }
 
-const _USER_INIT_PASSWORD_KEY: &str = "system.user.init-password";
+const USER_INIT_PASSWORD_KEY: &str = "system.user.init-password";
 
 const USER_REGISTER_ENABLED_KEY: &str = "system.user.register-enabled";
 
@@ -53,16 +66,7 @@ impl AdminUserService {
             return Err(ApiError::BizCode(USER_REGISTER_DISABLED));
         }
         // 1.2 校验账户配合
-        let user_count = self.base_query().count(database::get()?).await?;
-        let tenant = TenantService::new(self.tenant.clone())
-            .get_current()
-            .await?;
-        if user_count >= tenant.account_count as u64 {
-            return Err(ApiError::BizCodeWithArgs(
-                USER_COUNT_MAX,
-                vec![format!("{}", tenant.account_count)],
-            ));
-        }
+        self.validate_user_count().await?;
         // 1.3 校验正确性
         self.validate_user_for_create_or_update(
             None,
@@ -82,6 +86,225 @@ impl AdminUserService {
         Ok(user_id)
     }
 
+    pub async fn create_user(&self, req_vo: UserSaveReqVo, operator: &Principal) -> ApiResult<i64> {
+        // 1.1 校验账户配合
+        self.validate_user_count().await?;
+        // 1.2 校验正确性
+        self.validate_user_for_create_or_update(
+            None,
+            req_vo.username.as_ref(),
+            req_vo.mobile.as_deref().unwrap_or_default(),
+            req_vo.email.as_deref().unwrap_or_default(),
+            req_vo.dept_id,
+            req_vo.post_ids.iter().copied().collect(),
+        )
+        .await?;
+        // 2. 插入用户，未传密码时使用初始密码
+        let password = match req_vo.password.clone() {
+            Some(password) => password,
+            None => ConfigApi
+                .get_config_value_by_key(USER_INIT_PASSWORD_KEY)
+                .await?
+                .filter(|password| !password.is_empty())
+                .ok_or_else(|| ApiError::BizCode(USER_IMPORT_INIT_PASSWORD))?,
+        };
+        let post_ids = req_vo.post_ids.clone();
+        let active_model = system_users::ActiveModel::from(req_vo);
+        let mut active_model = create_with_common_fields(
+            active_model,
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        active_model.status = Set(CommonStatusEnum::Enable.status());
+        active_model.password = Set(encode_password(password.as_str())?);
+        let txn = database::get()?.begin().await?;
+        let user_id = active_model.insert(&txn).await?.id;
+        // 3. 插入关联岗位
+        PostService::new(self.tenant)
+            .assign_user_posts(&txn, user_id, post_ids, operator)
+            .await?;
+        txn.commit().await?;
+        Ok(user_id)
+    }
+
+    pub async fn update_user(&self, req_vo: UserSaveReqVo, operator: &Principal) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(USER_NOT_EXISTS))?;
+        // 1. 校验正确性
+        let user = self
+            .validate_user_for_create_or_update(
+                Some(id),
+                req_vo.username.as_ref(),
+                req_vo.mobile.as_deref().unwrap_or_default(),
+                req_vo.email.as_deref().unwrap_or_default(),
+                req_vo.dept_id,
+                req_vo.post_ids.iter().copied().collect(),
+            )
+            .await?
+            .ok_or_else(|| ApiError::BizCode(USER_NOT_EXISTS))?;
+        // 2.1 更新用户
+        let post_ids = req_vo.post_ids.clone();
+        let mut active_model = system_users::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_change_log(&user, active_model, Some(operator.user_id.to_string())).await?;
+        let txn = database::get()?.begin().await?;
+        active_model.update(&txn).await?;
+        // 2.2 更新岗位
+        PostService::new(self.tenant)
+            .assign_user_posts(&txn, id, post_ids, operator)
+            .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    pub async fn delete_user(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 1. 校验用户存在
+        let user = self.get_user(id).await?;
+        // 2.1 删除用户
+        let txn = database::get()?.begin().await?;
+        let active_model = soft_delete_with_common_fields(
+            user.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(&txn).await?;
+        // 2.2 删除用户关联数据
+        let permission_service = PermissionService::new(self.tenant);
+        permission_service.process_user_deleted(&txn, id).await?;
+        PostService::new(self.tenant)
+            .process_user_deleted(&txn, id)
+            .await?;
+        txn.commit().await?;
+        permission_service.clear_user_permission_cache([id]).await
+    }
+
+    pub async fn update_user_status(
+        &self,
+        req_vo: UserUpdateStatusReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 1. 校验用户存在
+        let user = self.get_user(req_vo.id).await?;
+        // 2. 更新状态
+        let mut active_model = user.clone().into_active_model();
+        active_model.status = Set(req_vo.status);
+        let active_model =
+            update_with_change_log(&user, active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn update_user_password(
+        &self,
+        req_vo: UserUpdatePasswordReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 1. 校验用户存在
+        let user = self.get_user(req_vo.id).await?;
+        // 2. 更新密码
+        let mut active_model = user.clone().into_active_model();
+        active_model.password = Set(encode_password(req_vo.password.as_str())?);
+        let active_model =
+            update_with_change_log(&user, active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn get_user_page(&self, req_vo: UserPageReqVo) -> ApiResult<Page<UserRespVo>> {
+        // 按部门过滤时，包含其所有子部门
+        let dept_ids = match req_vo.dept_id {
+            Some(dept_id) => {
+                let mut dept_ids = DeptService::new(self.tenant)
+                    .get_child_dept_ids(dept_id)
+                    .await?;
+                dept_ids.insert(dept_id);
+                Some(dept_ids)
+            }
+            None => None,
+        };
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.keyword.as_ref().filter(|k| !k.is_empty()),
+                |query, keyword| {
+                    query.filter(
+                        Condition::any()
+                            .add(system_users::Column::Username.contains(keyword))
+                            .add(system_users::Column::Nickname.contains(keyword))
+                            .add(system_users::Column::Mobile.contains(keyword)),
+                    )
+                },
+            )
+            .apply_if(dept_ids, |query, dept_ids| {
+                query.filter(system_users::Column::DeptId.is_in(dept_ids))
+            })
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_users::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_users::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_users::Column::CreateTime.lte(end))
+            })
+            .order_by_desc(system_users::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let users = paginator.fetch_page(req_vo.pagination.page - 1).await?;
+        let items = self.build_user_resp_list(users).await?;
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_user_resp(&self, id: i64) -> ApiResult<UserRespVo> {
+        let user = self.get_user(id).await?;
+        self.build_user_resp_list(vec![user])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| ApiError::BizCode(USER_NOT_EXISTS))
+    }
+
+    async fn build_user_resp_list(
+        &self,
+        users: Vec<system_users::Model>,
+    ) -> ApiResult<Vec<UserRespVo>> {
+        // 拼接岗位编号
+        let mut user_post_ids = PostService::new(self.tenant)
+            .get_user_post_ids_map(users.iter().map(|user| user.id))
+            .await?;
+        // 拼接部门名称
+        let dept_names = DeptService::new(self.tenant)
+            .get_dept_list(users.iter().filter_map(|user| user.dept_id))
+            .await?
+            .into_iter()
+            .map(|dept| (dept.id, dept.name))
+            .collect::<HashMap<_, _>>();
+        Ok(users
+            .into_iter()
+            .map(|user| {
+                let mut resp = UserRespVo::from(user);
+                resp.post_ids = user_post_ids.remove(&resp.id).unwrap_or_default();
+                resp.dept_name = resp.dept_id.and_then(|id| dept_names.get(&id).cloned());
+                resp
+            })
+            .collect())
+    }
+
+    pub async fn validate_user_count(&self) -> ApiResult<()> {
+        let user_count = self.base_query().count(database::get()?).await?;
+        let tenant = TenantService::new(self.tenant).get_current().await?;
+        if user_count >= tenant.account_count as u64 {
+            return Err(ApiError::BizCodeWithArgs(
+                USER_COUNT_MAX,
+                vec![format!("{}", tenant.account_count)],
+            ));
+        }
+        Ok(())
+    }
+
     pub async fn get_user_by_username(
         &self,
         username: &str,
@@ -102,6 +325,17 @@ impl AdminUserService {
             .ok_or_else(|| ApiError::BizCode(USER_NOT_EXISTS))?)
     }
 
+    pub async fn get_user_list(
+        &self,
+        ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<Vec<system_users::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_users::Column::Id.is_in(ids))
+            .all(database::get()?)
+            .await?)
+    }
+
     pub async fn validate_user_for_create_or_update(
         &self,
         id: Option<i64>,
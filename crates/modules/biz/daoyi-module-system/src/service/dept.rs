@@ -1,12 +1,29 @@
-use daoyi_common::app::database;
+use crate::service::admin_user::AdminUserService;
+use crate::vo::dept::dept_list_req_vo::DeptListReqVo;
+use crate::vo::dept::dept_save_req_vo::DeptSaveReqVo;
+use crate::vo::dept::dept_tree_resp_vo::DeptTreeRespVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::{database, redis_util};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
 use daoyi_common_support::support::tenant::TenantContextHolder;
 use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::enumeration::redis_key_constants::DEPT_CHILDREN_ID_LIST;
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
-use daoyi_common_support::utils::errors::{DEPT_NOT_ENABLE, DEPT_NOT_FOUND};
+use daoyi_common_support::utils::errors::{
+    DEPT_EXITS_CHILDREN, DEPT_NAME_DUPLICATE, DEPT_NOT_ENABLE, DEPT_NOT_FOUND, DEPT_PARENT_ERROR,
+    DEPT_PARENT_IS_CHILD, DEPT_PARENT_NOT_EXITS, USER_IS_DISABLE,
+};
 use daoyi_entities_system::entity::prelude::SystemDept;
 use daoyi_entities_system::entity::system_dept;
+use indextree::{Arena, NodeId};
 use sea_orm::*;
+use std::collections::{HashMap, HashSet};
+
+/// 根部门的父编号
+const PARENT_ID_ROOT: i64 = 0;
 
 pub struct DeptService {
     tenant: TenantContextHolder,
@@ -21,6 +38,215 @@ impl DeptService {
     }
 }
 impl DeptService {
+    pub async fn create_dept(&self, req_vo: DeptSaveReqVo, operator: &Principal) -> ApiResult<i64> {
+        // 1.1 校验父部门的有效性
+        self.validate_parent_dept(None, req_vo.parent_id).await?;
+        // 1.2 校验部门名的唯一性
+        self.validate_dept_name_unique(None, req_vo.parent_id, &req_vo.name)
+            .await?;
+        // 1.3 校验负责人
+        self.validate_leader_user(req_vo.leader_user_id).await?;
+        // 2. 插入部门
+        let active_model = create_with_common_fields(
+            system_dept::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        let dept_id = active_model.insert(database::get()?).await?.id;
+        // 3. 部门树发生变化，清理子部门缓存
+        self.clear_child_dept_ids_cache().await?;
+        Ok(dept_id)
+    }
+
+    pub async fn update_dept(&self, req_vo: DeptSaveReqVo, operator: &Principal) -> ApiResult<()> {
+        let id = req_vo.id.ok_or_else(|| ApiError::BizCode(DEPT_NOT_FOUND))?;
+        // 1.1 校验自己存在
+        self.get_dept(id).await?;
+        // 1.2 校验父部门的有效性
+        self.validate_parent_dept(Some(id), req_vo.parent_id)
+            .await?;
+        // 1.3 校验部门名的唯一性
+        self.validate_dept_name_unique(Some(id), req_vo.parent_id, &req_vo.name)
+            .await?;
+        // 1.4 校验负责人
+        self.validate_leader_user(req_vo.leader_user_id).await?;
+        // 2. 更新部门
+        let mut active_model = system_dept::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        // 3. 部门树发生变化，清理子部门缓存
+        self.clear_child_dept_ids_cache().await
+    }
+
+    pub async fn delete_dept(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 1.1 校验是否存在
+        let dept = self.get_dept(id).await?;
+        // 1.2 校验是否有子部门
+        let children = self
+            .base_query()
+            .filter(system_dept::Column::ParentId.eq(id))
+            .count(database::get()?)
+            .await?;
+        if children > 0 {
+            return Err(ApiError::BizCode(DEPT_EXITS_CHILDREN));
+        }
+        // 2. 标记删除
+        let active_model = soft_delete_with_common_fields(
+            dept.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(database::get()?).await?;
+        // 3. 部门树发生变化，清理子部门缓存
+        self.clear_child_dept_ids_cache().await
+    }
+
+    pub async fn get_dept(&self, id: i64) -> ApiResult<system_dept::Model> {
+        self.base_query()
+            .filter(system_dept::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DEPT_NOT_FOUND))
+    }
+
+    pub async fn get_dept_list_by_condition(
+        &self,
+        req_vo: DeptListReqVo,
+    ) -> ApiResult<Vec<system_dept::Model>> {
+        Ok(self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_dept::Column::Name.contains(name)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_dept::Column::Status.eq(status))
+            })
+            .order_by_asc(system_dept::Column::Sort)
+            .order_by_asc(system_dept::Column::Id)
+            .all(database::get()?)
+            .await?)
+    }
+
+    pub async fn get_dept_list(
+        &self,
+        dept_ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<Vec<system_dept::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_dept::Column::Id.is_in(dept_ids))
+            .all(database::get()?)
+            .await?)
+    }
+
+    /// 获得指定部门的所有子部门编号，不包含自身，从缓存中
+    ///
+    /// 缓存按租户隔离，避免其它租户查询到的空集合被当前租户读取
+    pub async fn get_child_dept_ids(&self, dept_id: i64) -> ApiResult<HashSet<i64>> {
+        let cache_key = format!("{DEPT_CHILDREN_ID_LIST}:{}:{dept_id}", self.tenant_id());
+        if let Some(cached) = redis_util::cache_get_json::<HashSet<i64>>(&cache_key).await? {
+            return Ok(cached);
+        }
+        let parent_ids = self
+            .base_query()
+            .all(database::get()?)
+            .await?
+            .into_iter()
+            .map(|dept| (dept.id, dept.parent_id))
+            .collect::<Vec<_>>();
+        let child_dept_ids = Self::collect_child_ids(&parent_ids, dept_id);
+        redis_util::cache_set_json(&cache_key, &child_dept_ids).await?;
+        Ok(child_dept_ids)
+    }
+
+    /// 清理当前租户所有部门的子部门编号缓存，部门树发生变化时使用
+    async fn clear_child_dept_ids_cache(&self) -> ApiResult<()> {
+        redis_util::cache_del_pattern(&format!("{DEPT_CHILDREN_ID_LIST}:{}:*", self.tenant_id()))
+            .await?;
+        Ok(())
+    }
+
+    /// 广度优先遍历 (部门编号, 父部门编号) 列表，收集所有子孙部门编号
+    fn collect_child_ids(parent_ids: &[(i64, i64)], dept_id: i64) -> HashSet<i64> {
+        let mut children_map: HashMap<i64, Vec<i64>> = HashMap::new();
+        for (id, parent_id) in parent_ids {
+            children_map.entry(*parent_id).or_default().push(*id);
+        }
+        let mut result = HashSet::new();
+        let mut queue = vec![dept_id];
+        while let Some(current) = queue.pop() {
+            for child in children_map.get(&current).into_iter().flatten() {
+                // 遇到脏数据成环时，避免死循环
+                if *child != dept_id && result.insert(*child) {
+                    queue.push(*child);
+                }
+            }
+        }
+        result
+    }
+
+    async fn validate_parent_dept(&self, id: Option<i64>, parent_id: i64) -> ApiResult<()> {
+        if parent_id == PARENT_ID_ROOT {
+            return Ok(());
+        }
+        // 1. 不能设置自己为父部门
+        if Some(parent_id) == id {
+            return Err(ApiError::BizCode(DEPT_PARENT_ERROR));
+        }
+        // 2. 父部门不存在
+        self.base_query()
+            .filter(system_dept::Column::Id.eq(parent_id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DEPT_PARENT_NOT_EXITS))?;
+        // 3. 不能设置自己的子部门为父部门
+        let Some(id) = id else {
+            return Ok(());
+        };
+        if self.get_child_dept_ids(id).await?.contains(&parent_id) {
+            return Err(ApiError::BizCode(DEPT_PARENT_IS_CHILD));
+        }
+        Ok(())
+    }
+
+    async fn validate_dept_name_unique(
+        &self,
+        id: Option<i64>,
+        parent_id: i64,
+        name: &str,
+    ) -> ApiResult<()> {
+        let dept = self
+            .base_query()
+            .filter(system_dept::Column::ParentId.eq(parent_id))
+            .filter(system_dept::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if dept.is_some_and(|dept| Some(dept.id) != id) {
+            return Err(ApiError::BizCode(DEPT_NAME_DUPLICATE));
+        }
+        Ok(())
+    }
+
+    /// 校验负责人存在且处于开启状态
+    async fn validate_leader_user(&self, leader_user_id: Option<i64>) -> ApiResult<()> {
+        let Some(leader_user_id) = leader_user_id else {
+            return Ok(());
+        };
+        let user = AdminUserService::new(self.tenant)
+            .get_user(leader_user_id)
+            .await?;
+        if CommonStatusEnum::is_disable(user.status) {
+            return Err(ApiError::BizCodeWithArgs(
+                USER_IS_DISABLE,
+                vec![user.nickname],
+            ));
+        }
+        Ok(())
+    }
+
     pub async fn validate_dept_list(&self, dept_ids: Vec<i64>) -> ApiResult<()> {
         if dept_ids.is_empty() {
             return Ok(());
@@ -42,3 +268,86 @@ impl DeptService {
         Ok(())
     }
 }
+
+impl DeptService {
+    pub fn build_tree(list: Vec<system_dept::Model>) -> Vec<DeptTreeRespVo> {
+        // 创建 Arena 来存储节点
+        let mut arena = Arena::new();
+        let mut node_map: HashMap<i64, NodeId> = HashMap::new();
+        let mut root_nodes: Vec<NodeId> = Vec::new();
+
+        // 创建所有节点
+        for dept in &list {
+            let node_id = arena.new_node(dept.clone());
+            node_map.insert(dept.id, node_id);
+        }
+
+        // 建立父子关系，找不到父节点时作为顶级节点处理
+        for dept in &list {
+            let current_node = node_map[&dept.id];
+            match node_map.get(&dept.parent_id) {
+                Some(parent_node_id) if dept.parent_id != PARENT_ID_ROOT => {
+                    // 脏数据成环时父节点已是当前节点的子孙，无法挂载，同样作为顶级节点处理
+                    if parent_node_id
+                        .checked_append(current_node, &mut arena)
+                        .is_err()
+                    {
+                        root_nodes.push(current_node);
+                    }
+                }
+                _ => root_nodes.push(current_node),
+            }
+        }
+
+        root_nodes
+            .into_iter()
+            .map(|root_node| Self::node_to_tree_vo(root_node, &arena))
+            .collect()
+    }
+
+    fn node_to_tree_vo(node_id: NodeId, arena: &Arena<system_dept::Model>) -> DeptTreeRespVo {
+        let mut tree_vo = DeptTreeRespVo::from(arena[node_id].get().clone());
+        for child_id in node_id.children(arena) {
+            tree_vo
+                .children
+                .push(Self::node_to_tree_vo(child_id, arena));
+        }
+        tree_vo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dept(id: i64, parent_id: i64) -> system_dept::Model {
+        system_dept::Model {
+            id,
+            name: format!("部门{id}"),
+            parent_id,
+            sort: 0,
+            leader_user_id: None,
+            phone: None,
+            email: None,
+            status: CommonStatusEnum::Enable.status(),
+            creator: None,
+            create_time: Default::default(),
+            updater: None,
+            update_time: Default::default(),
+            deleted: 0,
+            tenant_id: 1,
+        }
+    }
+
+    #[test]
+    fn test_build_tree() {
+        // 1 -> 2 -> 3
+        let tree = DeptService::build_tree(vec![dept(1, PARENT_ID_ROOT), dept(2, 1), dept(3, 2)]);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children[0].children[0].id, 3);
+        // 脏数据成环时不 panic，无法挂载的节点作为顶级节点
+        let tree = DeptService::build_tree(vec![dept(5, 6), dept(6, 5)]);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children.len(), 1);
+    }
+}
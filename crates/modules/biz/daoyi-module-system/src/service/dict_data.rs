@@ -0,0 +1,164 @@
+use crate::service::dict_type::DictTypeService;
+use crate::vo::dict::dict_data_page_req_vo::DictDataPageReqVo;
+use crate::vo::dict::dict_data_resp_vo::DictDataRespVo;
+use crate::vo::dict::dict_data_save_req_vo::DictDataSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::database;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common::service::DictApi;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    DICT_DATA_NOT_EXISTS, DICT_DATA_VALUE_DUPLICATE, DICT_TYPE_NOT_ENABLE, DICT_TYPE_NOT_EXISTS,
+};
+use daoyi_entities_system::entity::prelude::SystemDictData;
+use daoyi_entities_system::entity::system_dict_data;
+use sea_orm::*;
+
+pub struct DictDataService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(DictDataService);
+impl DictDataService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemDictData> {
+        SystemDictData::find().filter(system_dict_data::Column::Deleted.eq(0))
+    }
+}
+
+impl DictDataService {
+    pub async fn create_dict_data(
+        &self,
+        req_vo: DictDataSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        // 校验正确性
+        self.validate_dict_data_for_create_or_update(None, &req_vo.value, &req_vo.dict_type)
+            .await?;
+        // 插入字典数据
+        let active_model = create_with_common_fields(
+            system_dict_data::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        let id = active_model.insert(database::get()?).await?.id;
+        DictApi.evict_cache().await?;
+        Ok(id)
+    }
+
+    pub async fn update_dict_data(
+        &self,
+        req_vo: DictDataSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(DICT_DATA_NOT_EXISTS))?;
+        // 校验正确性
+        self.validate_dict_data_for_create_or_update(Some(id), &req_vo.value, &req_vo.dict_type)
+            .await?;
+        // 更新字典数据
+        let mut active_model = system_dict_data::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        DictApi.evict_cache().await
+    }
+
+    pub async fn delete_dict_data(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验是否存在
+        let dict_data = self.get_dict_data(id).await?;
+        // 删除字典数据
+        let active_model = soft_delete_with_common_fields(
+            dict_data.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(database::get()?).await?;
+        DictApi.evict_cache().await
+    }
+
+    pub async fn get_dict_data(&self, id: i64) -> ApiResult<system_dict_data::Model> {
+        self.base_query()
+            .filter(system_dict_data::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DICT_DATA_NOT_EXISTS))
+    }
+
+    pub async fn get_dict_data_page(
+        &self,
+        req_vo: DictDataPageReqVo,
+    ) -> ApiResult<Page<DictDataRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.label.as_ref().filter(|l| !l.is_empty()),
+                |query, label| query.filter(system_dict_data::Column::Label.contains(label)),
+            )
+            .apply_if(
+                req_vo.dict_type.as_ref().filter(|t| !t.is_empty()),
+                |query, dict_type| query.filter(system_dict_data::Column::DictType.eq(dict_type)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_dict_data::Column::Status.eq(status))
+            })
+            .order_by_desc(system_dict_data::Column::DictType)
+            .order_by_asc(system_dict_data::Column::Sort)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(DictDataRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_dict_data_count_by_dict_type(&self, dict_type: &str) -> ApiResult<u64> {
+        Ok(self
+            .base_query()
+            .filter(system_dict_data::Column::DictType.eq(dict_type))
+            .count(database::get()?)
+            .await?)
+    }
+
+    async fn validate_dict_data_for_create_or_update(
+        &self,
+        id: Option<i64>,
+        value: &str,
+        dict_type: &str,
+    ) -> ApiResult<()> {
+        // 校验自己存在
+        if let Some(id) = id {
+            self.get_dict_data(id).await?;
+        }
+        // 校验字典类型有效
+        let dict_type_model = DictTypeService::new(self.tenant)
+            .get_dict_type_by_type(dict_type)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DICT_TYPE_NOT_EXISTS))?;
+        if !CommonStatusEnum::is_enable(dict_type_model.status) {
+            return Err(ApiError::BizCode(DICT_TYPE_NOT_ENABLE));
+        }
+        // 校验字典数据的值的唯一性
+        let dict_data = self
+            .base_query()
+            .filter(system_dict_data::Column::DictType.eq(dict_type))
+            .filter(system_dict_data::Column::Value.eq(value))
+            .one(database::get()?)
+            .await?;
+        if dict_data.is_some_and(|dict_data| Some(dict_data.id) != id) {
+            return Err(ApiError::BizCode(DICT_DATA_VALUE_DUPLICATE));
+        }
+        Ok(())
+    }
+}
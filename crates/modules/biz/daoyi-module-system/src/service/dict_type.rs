@@ -0,0 +1,200 @@
+use crate::service::dict_data::DictDataService;
+use crate::vo::dict::dict_type_page_req_vo::DictTypePageReqVo;
+use crate::vo::dict::dict_type_resp_vo::DictTypeRespVo;
+use crate::vo::dict::dict_type_save_req_vo::DictTypeSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::database;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common::service::DictApi;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    DICT_TYPE_HAS_CHILDREN, DICT_TYPE_NAME_DUPLICATE, DICT_TYPE_NOT_EXISTS,
+    DICT_TYPE_TYPE_DUPLICATE,
+};
+use daoyi_entities_system::entity::prelude::{SystemDictData, SystemDictType};
+use daoyi_entities_system::entity::{system_dict_data, system_dict_type};
+use sea_orm::prelude::Expr;
+use sea_orm::sqlx::types::chrono::Local;
+use sea_orm::*;
+
+pub struct DictTypeService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(DictTypeService);
+impl DictTypeService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemDictType> {
+        SystemDictType::find().filter(system_dict_type::Column::Deleted.eq(0))
+    }
+}
+
+impl DictTypeService {
+    pub async fn create_dict_type(
+        &self,
+        req_vo: DictTypeSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        // 校验字典类型的名字、类型的唯一性
+        self.validate_dict_type_for_create_or_update(None, &req_vo.name, &req_vo.r#type)
+            .await?;
+        // 插入字典类型
+        let active_model = create_with_common_fields(
+            system_dict_type::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_dict_type(
+        &self,
+        req_vo: DictTypeSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(DICT_TYPE_NOT_EXISTS))?;
+        // 校验正确性
+        let old_type = self
+            .validate_dict_type_for_create_or_update(Some(id), &req_vo.name, &req_vo.r#type)
+            .await?
+            .map(|dict_type| dict_type.r#type);
+        let new_type = req_vo.r#type.clone();
+        // 更新字典类型，类型标识变更时同步更新字典数据
+        let txn = database::get()?.begin().await?;
+        let mut active_model = system_dict_type::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(&txn).await?;
+        if let Some(old_type) = old_type.filter(|old_type| *old_type != new_type) {
+            SystemDictData::update_many()
+                .col_expr(system_dict_data::Column::DictType, Expr::value(new_type))
+                .filter(system_dict_data::Column::DictType.eq(old_type))
+                .filter(system_dict_data::Column::Deleted.eq(0))
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await?;
+        DictApi.evict_cache().await
+    }
+
+    pub async fn delete_dict_type(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验是否存在
+        let dict_type = self.get_dict_type(id).await?;
+        // 校验是否有字典数据
+        if DictDataService::new(self.tenant)
+            .get_dict_data_count_by_dict_type(&dict_type.r#type)
+            .await?
+            > 0
+        {
+            return Err(ApiError::BizCode(DICT_TYPE_HAS_CHILDREN));
+        }
+        // 删除字典类型
+        let mut active_model = soft_delete_with_common_fields(
+            dict_type.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.deleted_time = Set(Some(Local::now().naive_local()));
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn get_dict_type(&self, id: i64) -> ApiResult<system_dict_type::Model> {
+        self.base_query()
+            .filter(system_dict_type::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(DICT_TYPE_NOT_EXISTS))
+    }
+
+    pub async fn get_dict_type_by_type(
+        &self,
+        r#type: &str,
+    ) -> ApiResult<Option<system_dict_type::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_dict_type::Column::Type.eq(r#type))
+            .one(database::get()?)
+            .await?)
+    }
+
+    pub async fn get_dict_type_page(
+        &self,
+        req_vo: DictTypePageReqVo,
+    ) -> ApiResult<Page<DictTypeRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_dict_type::Column::Name.contains(name)),
+            )
+            .apply_if(
+                req_vo.r#type.as_ref().filter(|t| !t.is_empty()),
+                |query, r#type| query.filter(system_dict_type::Column::Type.contains(r#type)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_dict_type::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_dict_type::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_dict_type::Column::CreateTime.lte(end))
+            })
+            .order_by_desc(system_dict_type::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(DictTypeRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_dict_type_list(&self) -> ApiResult<Vec<system_dict_type::Model>> {
+        Ok(self
+            .base_query()
+            .order_by_asc(system_dict_type::Column::Id)
+            .all(database::get()?)
+            .await?)
+    }
+
+    /// 校验字典类型，更新时返回原字典类型
+    async fn validate_dict_type_for_create_or_update(
+        &self,
+        id: Option<i64>,
+        name: &str,
+        r#type: &str,
+    ) -> ApiResult<Option<system_dict_type::Model>> {
+        // 校验自己存在
+        let old = match id {
+            Some(id) => Some(self.get_dict_type(id).await?),
+            None => None,
+        };
+        // 校验字典类型的名字的唯一性
+        let dict_type = self
+            .base_query()
+            .filter(system_dict_type::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if dict_type.is_some_and(|dict_type| Some(dict_type.id) != id) {
+            return Err(ApiError::BizCode(DICT_TYPE_NAME_DUPLICATE));
+        }
+        // 校验字典类型的类型的唯一性
+        let dict_type = self.get_dict_type_by_type(r#type).await?;
+        if dict_type.is_some_and(|dict_type| Some(dict_type.id) != id) {
+            return Err(ApiError::BizCode(DICT_TYPE_TYPE_DUPLICATE));
+        }
+        Ok(old)
+    }
+}
@@ -1,7 +1,165 @@
-use daoyi_common::app::TenantContextHolder;
+use crate::vo::logger::login_log_create_req_dto::LoginLogCreateReqDTO;
+use crate::vo::logger::login_log_page_req_vo::LoginLogPageReqVo;
+use crate::vo::logger::login_log_resp_vo::LoginLogRespVo;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::{TenantContextHolder, database};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::create_with_common_fields;
+use daoyi_common_support::utils::enumeration::{LoginLogTypeEnum, LoginResultEnum, UserTypeEnum};
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::prelude::SystemLoginLog;
+use daoyi_entities_system::entity::system_login_log;
+use sea_orm::{prelude::*, *};
+
+/// 导出的 CSV 文件的 UTF-8 BOM，避免 Excel 打开时中文乱码
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
 
 pub struct LoginLogService {
     tenant: TenantContextHolder,
 }
 impl_tenant_instance!(LoginLogService);
+impl LoginLogService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemLoginLog> {
+        SystemLoginLog::find()
+            .filter(system_login_log::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_login_log::Column::Deleted.eq(0))
+    }
+}
+
+impl LoginLogService {
+    pub async fn create_login_log(&self, req_dto: LoginLogCreateReqDTO) -> ApiResult<()> {
+        let active_model = system_login_log::ActiveModel {
+            log_type: Set(req_dto.log_type.type_value() as i64),
+            trace_id: Set(req_dto.trace_id),
+            user_id: Set(req_dto.user_id),
+            user_type: Set(req_dto.user_type),
+            username: Set(req_dto.username),
+            result: Set(req_dto.result.result()),
+            user_ip: Set(req_dto.user_ip),
+            user_agent: Set(req_dto.user_agent),
+            ..Default::default()
+        };
+        create_with_common_fields(
+            active_model,
+            Some(req_dto.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?
+        .insert(database::get()?)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_login_log_page(
+        &self,
+        req_vo: LoginLogPageReqVo,
+    ) -> ApiResult<Page<LoginLogRespVo>> {
+        let paginator = self
+            .filter_query(&req_vo)
+            .order_by_desc(system_login_log::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(LoginLogRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    /// 导出登录日志为 CSV 文件，忽略分页参数，导出全部符合条件的日志
+    pub async fn export_login_log(&self, req_vo: LoginLogPageReqVo) -> ApiResult<Vec<u8>> {
+        let logs = self
+            .filter_query(&req_vo)
+            .order_by_desc(system_login_log::Column::Id)
+            .all(database::get()?)
+            .await?;
+        let mut writer = csv::Writer::from_writer(UTF8_BOM.to_vec());
+        writer
+            .write_record([
+                "日志编号",
+                "日志类型",
+                "用户编号",
+                "用户类型",
+                "用户账号",
+                "登录结果",
+                "用户 IP",
+                "浏览器 UA",
+                "链路追踪编号",
+                "登录时间",
+            ])
+            .map_err(anyhow::Error::from)?;
+        for log in logs {
+            let log_type = LoginLogTypeEnum::from_type_value(log.log_type as i32)
+                .map(|log_type| log_type.name().to_string())
+                .unwrap_or_else(|| log.log_type.to_string());
+            let user_type = UserTypeEnum::from_value(log.user_type)
+                .map(|user_type| user_type.name().to_string())
+                .unwrap_or_else(|| log.user_type.to_string());
+            let result = LoginResultEnum::from_result(log.result)
+                .map(|result| result.name().to_string())
+                .unwrap_or_else(|| log.result.to_string());
+            writer
+                .write_record(
+                    [
+                        log.id.to_string(),
+                        log_type,
+                        log.user_id.to_string(),
+                        user_type,
+                        log.username,
+                        result,
+                        log.user_ip,
+                        log.user_agent,
+                        log.trace_id,
+                        log.create_time.format(datetime_format::FORMAT).to_string(),
+                    ]
+                    .map(escape_csv_cell),
+                )
+                .map_err(anyhow::Error::from)?;
+        }
+        let content = writer.into_inner().map_err(|e| anyhow::anyhow!("{e}"))?;
+        Ok(content)
+    }
+
+    fn filter_query(&self, req_vo: &LoginLogPageReqVo) -> Select<SystemLoginLog> {
+        self.base_query()
+            .apply_if(req_vo.log_type, |query, log_type| {
+                query.filter(system_login_log::Column::LogType.eq(log_type))
+            })
+            .apply_if(req_vo.result, |query, result| {
+                query.filter(system_login_log::Column::Result.eq(result))
+            })
+            .apply_if(
+                req_vo.trace_id.as_ref().filter(|s| !s.is_empty()),
+                |query, trace_id| query.filter(system_login_log::Column::TraceId.eq(trace_id)),
+            )
+            .apply_if(
+                req_vo.user_ip.as_ref().filter(|s| !s.is_empty()),
+                |query, user_ip| query.filter(system_login_log::Column::UserIp.contains(user_ip)),
+            )
+            .apply_if(
+                req_vo.username.as_ref().filter(|s| !s.is_empty()),
+                |query, username| {
+                    query.filter(system_login_log::Column::Username.contains(username))
+                },
+            )
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_login_log::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_login_log::Column::CreateTime.lte(end))
+            })
+    }
+}
+
+/// 转义 CSV 单元格，以公式字符开头的内容加上单引号前缀，避免用户输入在表格软件中被当作公式执行
+fn escape_csv_cell(cell: String) -> String {
+    if cell.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{cell}")
+    } else {
+        cell
+    }
+}
@@ -1,14 +1,28 @@
+use crate::service::permission::PermissionService;
+use crate::vo::menu::menu_list_req_vo::MenuListReqVo;
+use crate::vo::menu::menu_save_req_vo::MenuSaveReqVo;
 use crate::vo::menu::menu_vo::MenuVo;
+use daoyi_common::app::auth::Principal;
 use daoyi_common::app::database;
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
 use daoyi_common_support::support::tenant::TenantContextHolder;
 use daoyi_common_support::utils::enumeration::MenuType;
-use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    MENU_COMPONENT_NAME_DUPLICATE, MENU_EXISTS_CHILDREN, MENU_NAME_DUPLICATE, MENU_NOT_EXISTS,
+    MENU_PARENT_ERROR, MENU_PARENT_NOT_DIR_OR_MENU, MENU_PARENT_NOT_EXISTS,
+};
 use daoyi_entities_system::entity::prelude::SystemMenu;
 use daoyi_entities_system::entity::system_menu;
 use indextree::{Arena, NodeId};
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
-use std::collections::HashMap;
+use sea_orm::*;
+use std::collections::{HashMap, HashSet};
+
+/// 根菜单的父编号
+const ID_ROOT: i64 = 0;
 
 pub struct MenuService {
     tenant: TenantContextHolder,
@@ -16,19 +30,244 @@ pub struct MenuService {
 
 impl_tenant_instance!(MenuService);
 impl MenuService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemMenu> {
+        SystemMenu::find().filter(system_menu::Column::Deleted.eq(0))
+    }
+}
+
+impl MenuService {
+    pub async fn create_menu(&self, req_vo: MenuSaveReqVo, operator: &Principal) -> ApiResult<i64> {
+        // 1.1 校验父菜单存在
+        self.validate_parent_menu(req_vo.parent_id, None).await?;
+        // 1.2 校验菜单（自己）
+        self.validate_menu_name(&req_vo.name, req_vo.parent_id, None)
+            .await?;
+        self.validate_menu_component_name(req_vo.component_name.as_deref(), None)
+            .await?;
+        // 2. 插入数据库
+        let active_model = Self::init_menu_property(system_menu::ActiveModel::from(req_vo));
+        let active_model = create_with_common_fields(
+            active_model,
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        let menu_id = active_model.insert(database::get()?).await?.id;
+        // 3. 菜单授权接口变化，清理权限缓存
+        PermissionService::new(self.tenant)
+            .clear_all_user_permission_cache()
+            .await?;
+        Ok(menu_id)
+    }
+
+    pub async fn update_menu(&self, req_vo: MenuSaveReqVo, operator: &Principal) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(MENU_NOT_EXISTS))?;
+        // 1.1 校验更新的菜单是否存在
+        self.get_menu(id).await?;
+        // 1.2 校验父菜单存在
+        self.validate_parent_menu(req_vo.parent_id, Some(id))
+            .await?;
+        // 1.3 校验菜单（自己）
+        self.validate_menu_name(&req_vo.name, req_vo.parent_id, Some(id))
+            .await?;
+        self.validate_menu_component_name(req_vo.component_name.as_deref(), Some(id))
+            .await?;
+        // 2. 更新到数据库
+        let mut active_model = Self::init_menu_property(system_menu::ActiveModel::from(req_vo));
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        // 3. 菜单授权接口变化，清理权限缓存
+        PermissionService::new(self.tenant)
+            .clear_all_user_permission_cache()
+            .await
+    }
+
+    pub async fn delete_menu(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 1.1 校验是否还有子菜单
+        let children = self
+            .base_query()
+            .filter(system_menu::Column::ParentId.eq(id))
+            .count(database::get()?)
+            .await?;
+        if children > 0 {
+            return Err(ApiError::BizCode(MENU_EXISTS_CHILDREN));
+        }
+        // 1.2 校验删除的菜单是否存在
+        let menu = self.get_menu(id).await?;
+        // 2.1 标记删除
+        let txn = database::get()?.begin().await?;
+        let active_model = soft_delete_with_common_fields(
+            menu.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(&txn).await?;
+        // 2.2 删除授予给角色的权限
+        let permission_service = PermissionService::new(self.tenant);
+        permission_service.process_menu_deleted(&txn, id).await?;
+        txn.commit().await?;
+        // 3. 菜单授权接口变化，清理权限缓存
+        permission_service.clear_all_user_permission_cache().await
+    }
+
+    pub async fn get_menu(&self, id: i64) -> ApiResult<system_menu::Model> {
+        self.base_query()
+            .filter(system_menu::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(MENU_NOT_EXISTS))
+    }
+
+    pub async fn get_menu_list_by_condition(
+        &self,
+        req_vo: MenuListReqVo,
+    ) -> ApiResult<Vec<system_menu::Model>> {
+        Ok(self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_menu::Column::Name.contains(name)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_menu::Column::Status.eq(status))
+            })
+            .order_by_asc(system_menu::Column::Sort)
+            .order_by_asc(system_menu::Column::Id)
+            .all(database::get()?)
+            .await?)
+    }
+
     pub async fn get_all_menu_list(&self) -> ApiResult<Vec<system_menu::Model>> {
-        Ok(SystemMenu::find().all(database::get()?).await?)
+        Ok(self.base_query().all(database::get()?).await?)
     }
 
     pub async fn get_menu_list(
         &self,
         menu_ids: impl IntoIterator<Item = i64>,
     ) -> ApiResult<Vec<system_menu::Model>> {
-        Ok(SystemMenu::find()
+        Ok(self
+            .base_query()
             .filter(system_menu::Column::Id.is_in(menu_ids))
             .all(database::get()?)
             .await?)
     }
+
+    /// 校验父菜单是否合法
+    ///
+    /// 1. 不能设置自己为父菜单
+    /// 2. 父菜单不存在
+    /// 3. 父菜单必须是 [MenuType::Dir] 或 [MenuType::Menu] 类型
+    /// 4. 不能设置自己的子孙菜单为父菜单
+    async fn validate_parent_menu(&self, parent_id: i64, child_id: Option<i64>) -> ApiResult<()> {
+        if parent_id == ID_ROOT {
+            return Ok(());
+        }
+        // 不能设置自己为父菜单
+        if Some(parent_id) == child_id {
+            return Err(ApiError::BizCode(MENU_PARENT_ERROR));
+        }
+        // 父菜单不存在
+        let parent = self
+            .base_query()
+            .filter(system_menu::Column::Id.eq(parent_id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(MENU_PARENT_NOT_EXISTS))?;
+        // 父菜单必须是目录或者菜单类型
+        if parent.r#type != MenuType::Dir.get_type() && parent.r#type != MenuType::Menu.get_type() {
+            return Err(ApiError::BizCode(MENU_PARENT_NOT_DIR_OR_MENU));
+        }
+        // 新增时不存在子菜单，无需校验是否成环
+        let Some(child_id) = child_id else {
+            return Ok(());
+        };
+        // 沿着父菜单向上查找，如果遇到自己，说明父菜单是自己的子孙菜单
+        let parent_ids = self
+            .base_query()
+            .all(database::get()?)
+            .await?
+            .into_iter()
+            .map(|menu| (menu.id, menu.parent_id))
+            .collect::<HashMap<_, _>>();
+        if Self::is_descendant(&parent_ids, parent_id, child_id) {
+            return Err(ApiError::BizCode(MENU_PARENT_ERROR));
+        }
+        Ok(())
+    }
+
+    /// 判断 `menu_id` 是否为 `ancestor_id` 自身或其子孙菜单，遇到脏数据成环时同样视为成环
+    fn is_descendant(parent_ids: &HashMap<i64, i64>, menu_id: i64, ancestor_id: i64) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = menu_id;
+        while current != ID_ROOT {
+            if current == ancestor_id || !visited.insert(current) {
+                return true;
+            }
+            match parent_ids.get(&current) {
+                Some(parent_id) => current = *parent_id,
+                None => break,
+            }
+        }
+        false
+    }
+
+    /// 校验同一个父菜单下，菜单名是否唯一
+    async fn validate_menu_name(
+        &self,
+        name: &str,
+        parent_id: i64,
+        id: Option<i64>,
+    ) -> ApiResult<()> {
+        let menu = self
+            .base_query()
+            .filter(system_menu::Column::ParentId.eq(parent_id))
+            .filter(system_menu::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if menu.is_some_and(|menu| Some(menu.id) != id) {
+            return Err(ApiError::BizCode(MENU_NAME_DUPLICATE));
+        }
+        Ok(())
+    }
+
+    /// 校验组件名是否唯一
+    async fn validate_menu_component_name(
+        &self,
+        component_name: Option<&str>,
+        id: Option<i64>,
+    ) -> ApiResult<()> {
+        let Some(component_name) = component_name.filter(|name| !name.is_empty()) else {
+            return Ok(());
+        };
+        let menu = self
+            .base_query()
+            .filter(system_menu::Column::ComponentName.eq(component_name))
+            .one(database::get()?)
+            .await?;
+        if menu.is_some_and(|menu| Some(menu.id) != id) {
+            return Err(ApiError::BizCode(MENU_COMPONENT_NAME_DUPLICATE));
+        }
+        Ok(())
+    }
+
+    /// 初始化菜单的通用属性
+    ///
+    /// 例如说，只有目录或者菜单类型的菜单，才设置 icon
+    fn init_menu_property(mut active_model: system_menu::ActiveModel) -> system_menu::ActiveModel {
+        // 菜单为按钮类型时，无需 component、icon、path 属性，进行置空
+        if active_model.r#type.as_ref() == &MenuType::Button.get_type() {
+            active_model.component = Set(None);
+            active_model.component_name = Set(None);
+            active_model.icon = Set(None);
+            active_model.path = Set(None);
+        }
+        active_model
+    }
 }
 
 impl MenuService {
@@ -95,3 +334,21 @@ impl MenuService {
         Some(menu_vo)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_descendant() {
+        // 1 -> 2 -> 3，4 为独立的根菜单
+        let parent_ids = HashMap::from([(1, ID_ROOT), (2, 1), (3, 2), (4, ID_ROOT)]);
+        assert!(MenuService::is_descendant(&parent_ids, 3, 1));
+        assert!(MenuService::is_descendant(&parent_ids, 2, 2));
+        assert!(!MenuService::is_descendant(&parent_ids, 1, 3));
+        assert!(!MenuService::is_descendant(&parent_ids, 4, 1));
+        // 脏数据成环时，视为成环
+        let parent_ids = HashMap::from([(5, 6), (6, 5)]);
+        assert!(MenuService::is_descendant(&parent_ids, 5, 1));
+    }
+}
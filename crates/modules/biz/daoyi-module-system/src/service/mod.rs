@@ -1,13 +1,22 @@
 pub mod admin_auth;
 pub mod admin_user;
 pub mod dept;
+pub mod dict_data;
+pub mod dict_type;
 pub mod login_logger;
 pub mod menu;
+pub mod notice;
+pub mod oauth2_approve;
 pub mod oauth2_client;
+pub mod oauth2_code;
+pub mod oauth2_device;
+pub mod oauth2_grant;
+pub mod oauth2_oidc;
+pub mod oauth2_open;
 pub mod oauth2_token;
 pub mod operate_logger;
 pub mod permission;
-mod post;
+pub mod post;
 pub mod role;
 pub mod sms_channel;
 pub mod sms_code;
@@ -17,3 +26,4 @@ pub mod sms_send;
 pub mod sms_template;
 pub mod social_user;
 pub mod tenant;
+pub mod tenant_package;
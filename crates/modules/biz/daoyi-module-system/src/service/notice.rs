@@ -0,0 +1,268 @@
+use crate::vo::notice::notice_my_page_req_vo::NoticeMyPageReqVo;
+use crate::vo::notice::notice_my_resp_vo::NoticeMyRespVo;
+use crate::vo::notice::notice_page_req_vo::NoticePageReqVo;
+use crate::vo::notice::notice_resp_vo::NoticeRespVo;
+use crate::vo::notice::notice_save_req_vo::NoticeSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::database;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::enumeration::NoticeStatusEnum;
+use daoyi_common_support::utils::errors::NOTICE_NOT_FOUND;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_entities_system::entity::prelude::{SystemNotice, SystemNoticeRead};
+use daoyi_entities_system::entity::{system_notice, system_notice_read};
+use sea_orm::sea_query::{Query, SelectStatement};
+use sea_orm::*;
+use std::collections::HashSet;
+
+pub struct NoticeService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(NoticeService);
+impl NoticeService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemNotice> {
+        SystemNotice::find()
+            .filter(system_notice::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_notice::Column::Deleted.eq(0))
+    }
+
+    // 已发布的通知公告，用于登录用户的公告列表
+    fn published_query(&self) -> Select<SystemNotice> {
+        self.base_query()
+            .filter(system_notice::Column::Status.eq(NoticeStatusEnum::Published.value()))
+    }
+
+    // 用户已读的通知公告编号子查询
+    fn read_notice_ids_query(&self, user: &Principal) -> SelectStatement {
+        Query::select()
+            .column(system_notice_read::Column::NoticeId)
+            .from(SystemNoticeRead)
+            .and_where(system_notice_read::Column::TenantId.eq(self.tenant_id()))
+            .and_where(system_notice_read::Column::UserId.eq(user.user_id))
+            .and_where(system_notice_read::Column::UserType.eq(user.user_type.value()))
+            .to_owned()
+    }
+}
+
+impl NoticeService {
+    pub async fn create_notice(
+        &self,
+        req_vo: NoticeSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        let active_model = create_with_common_fields(
+            system_notice::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_notice(
+        &self,
+        req_vo: NoticeSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(NOTICE_NOT_FOUND))?;
+        // 校验是否存在
+        self.get_notice(id).await?;
+        // 更新通知公告
+        let mut active_model = system_notice::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn delete_notice(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验是否存在
+        let notice = self.get_notice(id).await?;
+        // 删除通知公告，并清理已读记录
+        let txn = database::get()?.begin().await?;
+        let active_model = soft_delete_with_common_fields(
+            notice.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(&txn).await?;
+        SystemNoticeRead::delete_many()
+            .filter(system_notice_read::Column::NoticeId.eq(id))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_notice(&self, id: i64) -> ApiResult<system_notice::Model> {
+        self.base_query()
+            .filter(system_notice::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(NOTICE_NOT_FOUND))
+    }
+
+    pub async fn get_notice_page(&self, req_vo: NoticePageReqVo) -> ApiResult<Page<NoticeRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.title.as_ref().filter(|t| !t.is_empty()),
+                |query, title| query.filter(system_notice::Column::Title.contains(title)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_notice::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.r#type, |query, r#type| {
+                query.filter(system_notice::Column::Type.eq(r#type))
+            })
+            .order_by_desc(system_notice::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(NoticeRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+}
+
+impl NoticeService {
+    /// 获得当前用户的已发布通知公告分页，并标记是否已读
+    pub async fn get_my_notice_page(
+        &self,
+        req_vo: NoticeMyPageReqVo,
+        user: &Principal,
+    ) -> ApiResult<Page<NoticeMyRespVo>> {
+        let paginator = self
+            .published_query()
+            .apply_if(req_vo.read_status, |query, read_status| {
+                if read_status {
+                    query.filter(
+                        system_notice::Column::Id.in_subquery(self.read_notice_ids_query(user)),
+                    )
+                } else {
+                    query.filter(
+                        system_notice::Column::Id.not_in_subquery(self.read_notice_ids_query(user)),
+                    )
+                }
+            })
+            .order_by_desc(system_notice::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let notices = paginator.fetch_page(req_vo.pagination.page - 1).await?;
+        let read_ids = self
+            .get_read_notice_ids(notices.iter().map(|notice| notice.id), user)
+            .await?;
+        let items = notices
+            .into_iter()
+            .map(|notice| {
+                let read_status = read_ids.contains(&notice.id);
+                NoticeMyRespVo::new(notice, read_status)
+            })
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    /// 获得当前用户未读的已发布通知公告数量
+    pub async fn get_unread_notice_count(&self, user: &Principal) -> ApiResult<u64> {
+        Ok(self
+            .published_query()
+            .filter(system_notice::Column::Id.not_in_subquery(self.read_notice_ids_query(user)))
+            .count(database::get()?)
+            .await?)
+    }
+
+    /// 标记指定通知公告为已读，忽略不存在或未发布的公告
+    pub async fn update_notice_read(&self, ids: Vec<i64>, user: &Principal) -> ApiResult<()> {
+        let notice_ids = self
+            .published_query()
+            .filter(system_notice::Column::Id.is_in(ids))
+            .select_only()
+            .column(system_notice::Column::Id)
+            .into_tuple::<i64>()
+            .all(database::get()?)
+            .await?;
+        self.insert_notice_read(notice_ids, user).await
+    }
+
+    /// 标记全部已发布的通知公告为已读
+    pub async fn update_all_notice_read(&self, user: &Principal) -> ApiResult<()> {
+        let notice_ids = self
+            .published_query()
+            .filter(system_notice::Column::Id.not_in_subquery(self.read_notice_ids_query(user)))
+            .select_only()
+            .column(system_notice::Column::Id)
+            .into_tuple::<i64>()
+            .all(database::get()?)
+            .await?;
+        self.insert_notice_read(notice_ids, user).await
+    }
+
+    async fn get_read_notice_ids(
+        &self,
+        notice_ids: impl IntoIterator<Item = i64>,
+        user: &Principal,
+    ) -> ApiResult<HashSet<i64>> {
+        Ok(SystemNoticeRead::find()
+            .filter(system_notice_read::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_notice_read::Column::UserId.eq(user.user_id))
+            .filter(system_notice_read::Column::UserType.eq(user.user_type.value()))
+            .filter(system_notice_read::Column::NoticeId.is_in(notice_ids))
+            .all(database::get()?)
+            .await?
+            .into_iter()
+            .map(|read| read.notice_id)
+            .collect())
+    }
+
+    async fn insert_notice_read(&self, notice_ids: Vec<i64>, user: &Principal) -> ApiResult<()> {
+        // 过滤已读的通知公告
+        let read_ids = self
+            .get_read_notice_ids(notice_ids.iter().copied(), user)
+            .await?;
+        let mut models = Vec::new();
+        for notice_id in notice_ids.into_iter().filter(|id| !read_ids.contains(id)) {
+            models.push(
+                create_with_common_fields(
+                    system_notice_read::ActiveModel {
+                        notice_id: Set(notice_id),
+                        user_id: Set(user.user_id),
+                        user_type: Set(user.user_type.value()),
+                        ..Default::default()
+                    },
+                    Some(user.user_id.to_string()),
+                    &self.tenant,
+                )
+                .await?,
+            );
+        }
+        if models.is_empty() {
+            return Ok(());
+        }
+        SystemNoticeRead::insert_many(models)
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    system_notice_read::Column::NoticeId,
+                    system_notice_read::Column::UserId,
+                    system_notice_read::Column::UserType,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec(database::get()?)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::service::oauth2_client::OAuth2ClientService;
+use daoyi_common::app::{TenantContextHolder, database};
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{create_with_common_fields, update_with_common_fields};
+use daoyi_common_support::utils::enumeration::EMPTY_VEC_STR;
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_entities_system::entity::prelude::SystemOauth2Approve;
+use daoyi_entities_system::entity::system_oauth2_approve;
+use sea_orm::sqlx::types::chrono::Local;
+use sea_orm::*;
+use std::collections::{HashMap, HashSet};
+use std::ops::Add;
+use std::time::Duration;
+
+/// 批准的过期时间，默认 30 天
+const TIMEOUT_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+pub struct OAuth2ApproveService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2ApproveService);
+impl OAuth2ApproveService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemOauth2Approve> {
+        SystemOauth2Approve::find()
+            .filter(system_oauth2_approve::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_oauth2_approve::Column::Deleted.eq(0))
+    }
+}
+
+impl OAuth2ApproveService {
+    /// 获得指定用户，针对指定客户端的授权范围是否都已经批准，用于自动授权
+    ///
+    /// 客户端配置了自动通过的授权范围时，直接记录批准
+    pub async fn check_for_pre_approval(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+        requested_scopes: &[String],
+    ) -> ApiResult<bool> {
+        // 第一步，基于 Client 的自动授权计算，如果 scopes 都在自动授权中，则返回 true 通过
+        let client = OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache(client_id)
+            .await?;
+        let auto_approve_scopes = serde_json::from_str::<Vec<String>>(
+            client
+                .auto_approve_scopes
+                .as_deref()
+                .unwrap_or(EMPTY_VEC_STR),
+        )?;
+        if requested_scopes
+            .iter()
+            .all(|scope| auto_approve_scopes.contains(scope))
+        {
+            // 记录批准，便于用户后续查看授权记录
+            for scope in requested_scopes {
+                self.save_approve(user_id, user_type, client_id, scope, true)
+                    .await?;
+            }
+            return Ok(true);
+        }
+        // 第二步，算上用户已经批准的授权，如果 scopes 都包含，则返回 true
+        let approved_scopes = self
+            .get_approve_list(user_id, user_type, client_id)
+            .await?
+            .into_iter()
+            .filter(|approve| approve.approved)
+            .map(|approve| approve.scope)
+            .collect::<HashSet<_>>();
+        Ok(requested_scopes
+            .iter()
+            .all(|scope| approved_scopes.contains(scope)))
+    }
+
+    /// 在用户发起批准时，基于 scopes 的选项，计算最终是否通过
+    pub async fn update_after_approval(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+        requested_scopes: &HashMap<String, bool>,
+    ) -> ApiResult<bool> {
+        // 如果 requestedScopes 为空，说明没有要求，则返回 true 通过
+        if requested_scopes.is_empty() {
+            return Ok(true);
+        }
+        // 更新批准的信息，需要至少有一个同意
+        let mut success = false;
+        for (scope, approved) in requested_scopes {
+            if *approved {
+                success = true;
+            }
+            self.save_approve(user_id, user_type, client_id, scope, *approved)
+                .await?;
+        }
+        Ok(success)
+    }
+
+    /// 获得用户针对指定客户端未过期的批准列表
+    pub async fn get_approve_list(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+    ) -> ApiResult<Vec<system_oauth2_approve::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_oauth2_approve::Column::UserId.eq(user_id))
+            .filter(system_oauth2_approve::Column::UserType.eq(user_type))
+            .filter(system_oauth2_approve::Column::ClientId.eq(client_id))
+            .filter(system_oauth2_approve::Column::ExpiresTime.gt(Local::now().naive_local()))
+            .all(database::get()?)
+            .await?)
+    }
+
+    /// 保存批准记录，已存在时更新批准结果与过期时间
+    async fn save_approve(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+        scope: &str,
+        approved: bool,
+    ) -> ApiResult<()> {
+        let expires_time = Local::now()
+            .add(Duration::from_secs(TIMEOUT_SECONDS))
+            .naive_local();
+        let approve = self
+            .base_query()
+            .filter(system_oauth2_approve::Column::UserId.eq(user_id))
+            .filter(system_oauth2_approve::Column::UserType.eq(user_type))
+            .filter(system_oauth2_approve::Column::ClientId.eq(client_id))
+            .filter(system_oauth2_approve::Column::Scope.eq(scope))
+            .one(database::get()?)
+            .await?;
+        match approve {
+            Some(approve) => {
+                let mut active_model = approve.into_active_model();
+                active_model.approved = Set(approved);
+                active_model.expires_time = Set(expires_time);
+                update_with_common_fields(active_model, Some(user_id.to_string()))
+                    .await?
+                    .update(database::get()?)
+                    .await?;
+            }
+            None => {
+                let active_model = system_oauth2_approve::ActiveModel {
+                    user_id: Set(user_id),
+                    user_type: Set(user_type),
+                    client_id: Set(client_id.to_string()),
+                    scope: Set(scope.to_string()),
+                    approved: Set(approved),
+                    expires_time: Set(expires_time),
+                    ..Default::default()
+                };
+                create_with_common_fields(active_model, Some(user_id.to_string()), &self.tenant)
+                    .await?
+                    .insert(database::get()?)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
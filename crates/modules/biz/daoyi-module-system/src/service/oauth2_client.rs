@@ -1,23 +1,178 @@
+use crate::vo::oauth2::oauth2_client_page_req_vo::OAuth2ClientPageReqVo;
+use crate::vo::oauth2::oauth2_client_resp_vo::OAuth2ClientRespVo;
+use crate::vo::oauth2::oauth2_client_save_req_vo::OAuth2ClientSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
 use daoyi_common::app::{TenantContextHolder, database, redis_util};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
 use daoyi_common_support::utils::enumeration::{
     CommonStatusEnum, EMPTY_VEC_STR, redis_key_constants::OAUTH_CLIENT,
 };
 use daoyi_common_support::utils::errors::{
     OAUTH2_CLIENT_AUTHORIZED_GRANT_TYPE_NOT_EXISTS, OAUTH2_CLIENT_CLIENT_SECRET_ERROR,
-    OAUTH2_CLIENT_DISABLE, OAUTH2_CLIENT_NOT_EXISTS, OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH,
-    OAUTH2_CLIENT_SCOPE_OVER,
+    OAUTH2_CLIENT_DISABLE, OAUTH2_CLIENT_EXISTS, OAUTH2_CLIENT_NOT_EXISTS,
+    OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH, OAUTH2_CLIENT_SCOPE_OVER,
     error::{ApiError, ApiResult},
 };
+use daoyi_common_support::utils::id::generate_secret;
 use daoyi_entities_system::entity::{prelude::SystemOauth2Client, system_oauth2_client};
-use sea_orm::prelude::*;
+use sea_orm::*;
+
+/// 自动生成的客户端密钥长度
+const CLIENT_SECRET_LENGTH: usize = 32;
 
 pub struct OAuth2ClientService {
     tenant: TenantContextHolder,
 }
 impl_tenant_instance!(OAuth2ClientService);
+impl OAuth2ClientService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemOauth2Client> {
+        SystemOauth2Client::find().filter(system_oauth2_client::Column::Deleted.eq(0))
+    }
+}
 
 impl OAuth2ClientService {
+    pub async fn create_oauth2_client(
+        &self,
+        req_vo: OAuth2ClientSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        // 校验客户端编号是否重复
+        self.validate_client_id_unique(&req_vo.client_id, None)
+            .await?;
+        // 插入客户端，未指定密钥时自动生成
+        let secret = req_vo
+            .secret
+            .clone()
+            .filter(|secret| !secret.is_empty())
+            .unwrap_or_else(|| generate_secret(CLIENT_SECRET_LENGTH));
+        let mut active_model = system_oauth2_client::ActiveModel::from(req_vo);
+        active_model.secret = Set(secret);
+        let active_model = create_with_common_fields(
+            active_model,
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_oauth2_client(
+        &self,
+        req_vo: OAuth2ClientSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_CLIENT_NOT_EXISTS))?;
+        // 校验存在，以及客户端编号是否重复
+        let client = self.get_oauth2_client(id).await?;
+        self.validate_client_id_unique(&req_vo.client_id, Some(id))
+            .await?;
+        // 更新客户端，未指定密钥时保持不变
+        let secret = req_vo.secret.clone().filter(|secret| !secret.is_empty());
+        let mut active_model = system_oauth2_client::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        if let Some(secret) = secret {
+            active_model.secret = Set(secret);
+        }
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        let new_client = active_model.update(database::get()?).await?;
+        // 清理缓存，客户端编号变化时新旧编号都需要清理
+        self.evict_cache(&client.client_id).await?;
+        self.evict_cache(&new_client.client_id).await
+    }
+
+    pub async fn delete_oauth2_client(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验存在
+        let client = self.get_oauth2_client(id).await?;
+        let client_id = client.client_id.clone();
+        // 删除客户端
+        let active_model = soft_delete_with_common_fields(
+            client.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(database::get()?).await?;
+        self.evict_cache(&client_id).await
+    }
+
+    /// 重新生成客户端密钥，返回新的密钥
+    pub async fn regenerate_oauth2_client_secret(
+        &self,
+        id: i64,
+        operator: &Principal,
+    ) -> ApiResult<String> {
+        // 校验存在
+        let client = self.get_oauth2_client(id).await?;
+        let client_id = client.client_id.clone();
+        // 更新密钥
+        let secret = generate_secret(CLIENT_SECRET_LENGTH);
+        let mut active_model = client.into_active_model();
+        active_model.secret = Set(secret.clone());
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        self.evict_cache(&client_id).await?;
+        Ok(secret)
+    }
+
+    pub async fn get_oauth2_client(&self, id: i64) -> ApiResult<system_oauth2_client::Model> {
+        self.base_query()
+            .filter(system_oauth2_client::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_CLIENT_NOT_EXISTS))
+    }
+
+    pub async fn get_oauth2_client_page(
+        &self,
+        req_vo: OAuth2ClientPageReqVo,
+    ) -> ApiResult<Page<OAuth2ClientRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_oauth2_client::Column::Name.contains(name)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_oauth2_client::Column::Status.eq(status))
+            })
+            .order_by_desc(system_oauth2_client::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(OAuth2ClientRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    async fn validate_client_id_unique(&self, client_id: &str, id: Option<i64>) -> ApiResult<()> {
+        let client = self
+            .base_query()
+            .filter(system_oauth2_client::Column::ClientId.eq(client_id))
+            .one(database::get()?)
+            .await?;
+        if client.is_some_and(|client| Some(client.id) != id) {
+            return Err(ApiError::BizCode(OAUTH2_CLIENT_EXISTS));
+        }
+        Ok(())
+    }
+
+    /// 清理客户端缓存
+    async fn evict_cache(&self, client_id: &str) -> ApiResult<()> {
+        redis_util::cache_del(&format!("{OAUTH_CLIENT}:{client_id}")).await?;
+        Ok(())
+    }
+
     /**
      * 获得 OAuth2 客户端，从缓存中
      *
@@ -33,7 +188,8 @@ impl OAuth2ClientService {
         if cached.is_some() {
             return Ok(cached);
         }
-        let client = SystemOauth2Client::find()
+        let client = self
+            .base_query()
             .filter(system_oauth2_client::Column::ClientId.eq(client_id))
             .one(database::get()?)
             .await?;
@@ -118,7 +274,10 @@ impl OAuth2ClientService {
                 .iter()
                 .any(|uri| redirect_uri.starts_with(uri))
         {
-            return Err(ApiError::BizCode(OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH));
+            return Err(ApiError::BizCodeWithArgs(
+                OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH,
+                vec![redirect_uri.to_string()],
+            ));
         }
         Ok(client)
     }
@@ -0,0 +1,89 @@
+use daoyi_common::app::{TenantContextHolder, database};
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::create_with_common_fields;
+use daoyi_common_support::utils;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{OAUTH2_CODE_EXPIRE, OAUTH2_CODE_NOT_EXISTS};
+use daoyi_common_support::utils::is_expired;
+use daoyi_entities_system::entity::prelude::SystemOauth2Code;
+use daoyi_entities_system::entity::system_oauth2_code;
+use sea_orm::sqlx::types::chrono::Local;
+use sea_orm::*;
+use std::ops::Add;
+use std::time::Duration;
+
+/// 授权码的过期时间，默认 5 分钟
+const TIMEOUT_SECONDS: u64 = 5 * 60;
+
+pub struct OAuth2CodeService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2CodeService);
+impl OAuth2CodeService {
+    /// 创建授权码
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_authorization_code(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+        scopes: &[String],
+        redirect_uri: &str,
+        state: Option<String>,
+        code_challenge: Option<String>,
+        code_challenge_method: Option<String>,
+        nonce: Option<String>,
+    ) -> ApiResult<system_oauth2_code::Model> {
+        let active_model = system_oauth2_code::ActiveModel {
+            code: Set(utils::id::x()),
+            user_id: Set(user_id),
+            user_type: Set(user_type),
+            client_id: Set(client_id.to_string()),
+            scopes: Set(Some(serde_json::to_string(scopes)?)),
+            expires_time: Set(Local::now()
+                .add(Duration::from_secs(TIMEOUT_SECONDS))
+                .naive_local()),
+            redirect_uri: Set(Some(redirect_uri.to_string())),
+            state: Set(state.unwrap_or_default()),
+            code_challenge: Set(code_challenge),
+            code_challenge_method: Set(code_challenge_method),
+            nonce: Set(nonce),
+            ..Default::default()
+        };
+        Ok(
+            create_with_common_fields(active_model, Some(user_id.to_string()), &self.tenant)
+                .await?
+                .insert(database::get()?)
+                .await?,
+        )
+    }
+
+    /// 使用授权码，授权码只能使用一次
+    pub async fn consume_authorization_code(
+        &self,
+        code: &str,
+    ) -> ApiResult<system_oauth2_code::Model> {
+        let db = database::get()?;
+        let code = SystemOauth2Code::find()
+            .filter(system_oauth2_code::Column::Code.eq(code))
+            .filter(system_oauth2_code::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_oauth2_code::Column::Deleted.eq(0))
+            .one(db)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_CODE_NOT_EXISTS))?;
+        // 无论是否过期都删除，避免授权码被重复尝试；并发使用同一授权码时只有删除成功的请求可以继续
+        let result = SystemOauth2Code::delete_many()
+            .filter(system_oauth2_code::Column::Id.eq(code.id))
+            .filter(system_oauth2_code::Column::Code.eq(&code.code))
+            .filter(system_oauth2_code::Column::Deleted.eq(0))
+            .exec(db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(ApiError::BizCode(OAUTH2_CODE_NOT_EXISTS));
+        }
+        if is_expired(&code.expires_time)? {
+            return Err(ApiError::BizCode(OAUTH2_CODE_EXPIRE));
+        }
+        Ok(code)
+    }
+}
@@ -0,0 +1,396 @@
+use crate::service::oauth2_client::OAuth2ClientService;
+use crate::service::oauth2_token::OAuth2TokenService;
+use crate::vo::oauth2::oauth2_authorize_client_vo::OAuth2AuthorizeClientVo;
+use crate::vo::oauth2::oauth2_device_authorization_resp_vo::OAuth2DeviceAuthorizationRespVo;
+use crate::vo::oauth2::oauth2_device_info_resp_vo::OAuth2DeviceInfoRespVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::{TenantContextHolder, redis_util};
+use daoyi_common::config;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::utils::enumeration::redis_key_constants::{
+    OAUTH2_DEVICE_CODE, OAUTH2_DEVICE_POLL, OAUTH2_DEVICE_USER_CODE,
+};
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    OAUTH2_GRANT_CLIENT_ID_MISMATCH, OAUTH2_GRANT_DEVICE_ACCESS_DENIED,
+    OAUTH2_GRANT_DEVICE_AUTHORIZATION_PENDING, OAUTH2_GRANT_DEVICE_CODE_EXPIRE,
+    OAUTH2_GRANT_DEVICE_SLOW_DOWN, OAUTH2_GRANT_DEVICE_USER_CODE_NOT_EXISTS,
+};
+use daoyi_common_support::utils::id;
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use sea_orm::sqlx::types::chrono::Local;
+use serde::{Deserialize, Serialize};
+
+/// 设备授权的过期时间，默认 10 分钟
+const TIMEOUT_SECONDS: u64 = 10 * 60;
+/// 设备轮询令牌端点的最小间隔，单位：秒
+const INTERVAL_SECONDS: u64 = 5;
+/// 轮询过于频繁时，轮询间隔增加的秒数，参见 RFC 8628 3.5
+const SLOW_DOWN_SECONDS: u64 = 5;
+/// 设备码的长度
+const DEVICE_CODE_LENGTH: usize = 40;
+/// 用户码的字符集，去掉元音与易混淆的字符，参见 RFC 8628 6.1
+const USER_CODE_CHARSET: &[u8] = b"BCDFGHJKLMNPQRSTVWXZ";
+/// 用户码的长度，展示时每 4 位使用 - 分隔
+const USER_CODE_LENGTH: usize = 8;
+/// 生成用户码时，遇到重复的最大重试次数
+const USER_CODE_MAX_ATTEMPTS: usize = 5;
+
+/// 设备授权的状态，缓存在 Redis 中，过期后自动删除
+///
+/// 只在申请与用户确认时写入，设备轮询只读取，轮询状态单独缓存在 [`DevicePollState`] 中
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeviceAuthorization {
+    client_id: String,
+    scopes: Vec<String>,
+    user_code: String,
+    tenant_id: i64,
+    /// 过期时间，Unix 时间戳，单位：秒
+    expires_at: i64,
+    /// 用户的确认结果，为 None 时表示用户尚未确认
+    approved: Option<bool>,
+    user_id: Option<i64>,
+    user_type: Option<i32>,
+}
+
+impl DeviceAuthorization {
+    /// 剩余的有效时间，单位：秒
+    fn remaining_seconds(&self) -> u64 {
+        (self.expires_at - Local::now().timestamp()).max(0) as u64
+    }
+
+    /// 记录用户的确认结果
+    fn confirm(&mut self, user_id: i64, user_type: i32, approved: bool) {
+        self.approved = Some(approved);
+        self.user_id = Some(user_id);
+        self.user_type = Some(user_type);
+    }
+}
+
+/// 设备的轮询状态，与设备授权分开缓存，设备轮询时只写入轮询状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct DevicePollState {
+    /// 轮询间隔，单位：秒
+    interval: u64,
+    /// 上次轮询时间，Unix 时间戳，单位：秒
+    last_polled_at: Option<i64>,
+}
+
+impl Default for DevicePollState {
+    fn default() -> Self {
+        Self {
+            interval: INTERVAL_SECONDS,
+            last_polled_at: None,
+        }
+    }
+}
+
+impl DevicePollState {
+    /// 记录本次轮询，轮询过于频繁时增加轮询间隔并返回 true
+    fn poll(&mut self, now: i64) -> bool {
+        let too_frequent = self
+            .last_polled_at
+            .is_some_and(|last_polled_at| now - last_polled_at < self.interval as i64);
+        self.last_polled_at = Some(now);
+        if too_frequent {
+            self.interval += SLOW_DOWN_SECONDS;
+        }
+        too_frequent
+    }
+}
+
+pub struct OAuth2DeviceService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2DeviceService);
+impl OAuth2DeviceService {
+    /// 申请设备授权，生成设备码与用户码，客户端与授权范围由调用方校验
+    pub async fn create_device_authorization(
+        &self,
+        client_id: &str,
+        scopes: Vec<String>,
+    ) -> ApiResult<OAuth2DeviceAuthorizationRespVo> {
+        // 1. 生成不重复的用户码
+        let mut user_code = None;
+        for _ in 0..USER_CODE_MAX_ATTEMPTS {
+            let code = id::generate_code(USER_CODE_CHARSET, USER_CODE_LENGTH);
+            if redis_util::cache_get::<Option<String>>(&Self::user_code_key(&code))
+                .await?
+                .flatten()
+                .is_none()
+            {
+                user_code = Some(code);
+                break;
+            }
+        }
+        let user_code =
+            user_code.ok_or_else(|| ApiError::Biz(String::from("生成用户码失败，请重试")))?;
+        // 2. 缓存设备授权，等待用户确认
+        let device_code = id::generate_secret(DEVICE_CODE_LENGTH);
+        let authorization = DeviceAuthorization {
+            client_id: client_id.to_string(),
+            scopes,
+            user_code: user_code.clone(),
+            tenant_id: self.tenant_id(),
+            expires_at: Local::now().timestamp() + TIMEOUT_SECONDS as i64,
+            approved: None,
+            user_id: None,
+            user_type: None,
+        };
+        redis_util::cache_set_json_ex(
+            &Self::device_code_key(&device_code),
+            &authorization,
+            TIMEOUT_SECONDS,
+        )
+        .await?;
+        redis_util::cache_set_ex(
+            &Self::user_code_key(&user_code),
+            device_code.clone(),
+            TIMEOUT_SECONDS,
+        )
+        .await?;
+        // 3. 拼接验证页地址
+        let verification_uri = config::get().await.oidc().device_verification_uri();
+        let display_user_code = Self::format_user_code(&user_code);
+        let separator = if verification_uri.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        Ok(OAuth2DeviceAuthorizationRespVo {
+            device_code,
+            expires_in: TIMEOUT_SECONDS,
+            interval: INTERVAL_SECONDS,
+            verification_uri_complete: format!(
+                "{verification_uri}{separator}userCode={display_user_code}"
+            ),
+            user_code: display_user_code,
+            verification_uri,
+        })
+    }
+
+    /// 获得验证页的信息，包括客户端与设备申请的授权范围
+    pub async fn get_device_info(&self, user_code: &str) -> ApiResult<OAuth2DeviceInfoRespVo> {
+        let (_, authorization) = self.get_authorization_by_user_code(user_code).await?;
+        let client = OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache(&authorization.client_id)
+            .await?;
+        Ok(OAuth2DeviceInfoRespVo {
+            client: OAuth2AuthorizeClientVo::from(client),
+            scopes: authorization.scopes,
+            user_code: Self::format_user_code(&authorization.user_code),
+        })
+    }
+
+    /// 用户确认或拒绝设备授权，用户码确认后立即失效
+    pub async fn approve_or_deny(
+        &self,
+        principal: &Principal,
+        user_code: &str,
+        approved: bool,
+    ) -> ApiResult<()> {
+        let (device_code, mut authorization) =
+            self.get_authorization_by_user_code(user_code).await?;
+        if authorization.approved.is_some() {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_USER_CODE_NOT_EXISTS));
+        }
+        authorization.confirm(principal.user_id, principal.user_type.value(), approved);
+        self.save_authorization(&device_code, &authorization)
+            .await?;
+        redis_util::cache_del(&Self::user_code_key(&authorization.user_code)).await?;
+        Ok(())
+    }
+
+    /// 设备轮询，用户批准后创建访问令牌，设备码只能使用一次
+    pub async fn grant_device_code(
+        &self,
+        client_id: &str,
+        device_code: &str,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        // 1. 校验设备码
+        let key = Self::device_code_key(device_code);
+        let authorization = redis_util::cache_get_json::<DeviceAuthorization>(&key)
+            .await?
+            .filter(|authorization| authorization.tenant_id == self.tenant_id())
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_GRANT_DEVICE_CODE_EXPIRE))?;
+        if authorization.client_id != client_id {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_CLIENT_ID_MISMATCH));
+        }
+        // 2. 根据用户的确认结果处理，设备授权只读取不写回，避免覆盖轮询期间用户的确认结果
+        match (authorization.approved, authorization.user_id) {
+            (Some(true), Some(user_id)) => {
+                // 并发轮询时只有删除设备码成功的请求才能签发令牌
+                if !redis_util::cache_remove(&key).await? {
+                    return Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_CODE_EXPIRE));
+                }
+                redis_util::cache_del(&Self::device_poll_key(device_code)).await?;
+                OAuth2TokenService::new(self.tenant)
+                    .create_access_token(
+                        user_id,
+                        authorization.user_type.unwrap_or_default(),
+                        client_id,
+                        authorization.scopes,
+                        client_info,
+                    )
+                    .await
+            }
+            (Some(_), _) => {
+                redis_util::cache_del(&key).await?;
+                redis_util::cache_del(&Self::device_poll_key(device_code)).await?;
+                Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_ACCESS_DENIED))
+            }
+            (None, _) => {
+                // 3. 校验轮询频率，过于频繁时增加轮询间隔
+                let too_frequent = self.record_poll(device_code, &authorization).await?;
+                if too_frequent {
+                    Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_SLOW_DOWN))
+                } else {
+                    Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_AUTHORIZATION_PENDING))
+                }
+            }
+        }
+    }
+
+    /// 根据用户码获得设备授权，用户码忽略大小写与分隔符
+    async fn get_authorization_by_user_code(
+        &self,
+        user_code: &str,
+    ) -> ApiResult<(String, DeviceAuthorization)> {
+        let user_code = Self::normalize_user_code(user_code);
+        let device_code = redis_util::cache_get::<Option<String>>(&Self::user_code_key(&user_code))
+            .await?
+            .flatten()
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_GRANT_DEVICE_USER_CODE_NOT_EXISTS))?;
+        let authorization =
+            redis_util::cache_get_json::<DeviceAuthorization>(&Self::device_code_key(&device_code))
+                .await?
+                .filter(|authorization| authorization.tenant_id == self.tenant_id())
+                .ok_or_else(|| ApiError::BizCode(OAUTH2_GRANT_DEVICE_USER_CODE_NOT_EXISTS))?;
+        Ok((device_code, authorization))
+    }
+
+    /// 保存设备授权，过期时间保持不变
+    async fn save_authorization(
+        &self,
+        device_code: &str,
+        authorization: &DeviceAuthorization,
+    ) -> ApiResult<()> {
+        let remaining_seconds = authorization.remaining_seconds();
+        if remaining_seconds == 0 {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_CODE_EXPIRE));
+        }
+        redis_util::cache_set_json_ex(
+            &Self::device_code_key(device_code),
+            authorization,
+            remaining_seconds,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// 记录设备的轮询，只写入轮询状态，过期时间与设备授权保持一致，轮询过于频繁时返回 true
+    async fn record_poll(
+        &self,
+        device_code: &str,
+        authorization: &DeviceAuthorization,
+    ) -> ApiResult<bool> {
+        let remaining_seconds = authorization.remaining_seconds();
+        if remaining_seconds == 0 {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_DEVICE_CODE_EXPIRE));
+        }
+        let key = Self::device_poll_key(device_code);
+        let mut poll_state = redis_util::cache_get_json::<DevicePollState>(&key)
+            .await?
+            .unwrap_or_default();
+        let too_frequent = poll_state.poll(Local::now().timestamp());
+        redis_util::cache_set_json_ex(&key, &poll_state, remaining_seconds).await?;
+        Ok(too_frequent)
+    }
+
+    fn device_code_key(device_code: &str) -> String {
+        format!("{OAUTH2_DEVICE_CODE}:{device_code}")
+    }
+
+    fn device_poll_key(device_code: &str) -> String {
+        format!("{OAUTH2_DEVICE_POLL}:{device_code}")
+    }
+
+    fn user_code_key(user_code: &str) -> String {
+        format!("{OAUTH2_DEVICE_USER_CODE}:{user_code}")
+    }
+
+    /// 用户码去掉分隔符并转为大写，便于用户输入
+    fn normalize_user_code(user_code: &str) -> String {
+        user_code
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// 用户码每 4 位使用 - 分隔，便于用户阅读
+    fn format_user_code(user_code: &str) -> String {
+        let (head, tail) = user_code.split_at(user_code.len() / 2);
+        format!("{head}-{tail}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_code() {
+        let user_code = OAuth2DeviceService::format_user_code("WDJBMJHT");
+        assert_eq!(user_code, "WDJB-MJHT");
+        assert_eq!(
+            OAuth2DeviceService::normalize_user_code(&user_code),
+            "WDJBMJHT"
+        );
+        assert_eq!(
+            OAuth2DeviceService::normalize_user_code(" wdjb mjht "),
+            "WDJBMJHT"
+        );
+    }
+
+    #[test]
+    fn test_poll_state() {
+        let mut poll_state = DevicePollState::default();
+        assert!(!poll_state.poll(100));
+        // 间隔内再次轮询，增加轮询间隔
+        assert!(poll_state.poll(102));
+        assert_eq!(poll_state.interval, INTERVAL_SECONDS + SLOW_DOWN_SECONDS);
+        assert!(!poll_state.poll(102 + (INTERVAL_SECONDS + SLOW_DOWN_SECONDS) as i64));
+    }
+
+    #[test]
+    fn test_approve_during_poll() {
+        // 缓存中的设备授权与轮询状态
+        let mut stored_authorization = DeviceAuthorization {
+            client_id: String::from("default"),
+            scopes: vec![],
+            user_code: String::from("WDJBMJHT"),
+            tenant_id: 1,
+            expires_at: Local::now().timestamp() + TIMEOUT_SECONDS as i64,
+            approved: None,
+            user_id: None,
+            user_type: None,
+        };
+        let mut stored_poll_state = DevicePollState::default();
+        // 设备轮询读取到尚未确认的设备授权
+        let polled = stored_authorization.clone();
+        assert!(polled.approved.is_none());
+        // 轮询处理期间，用户批准了设备授权
+        stored_authorization.confirm(1, 2, true);
+        // 轮询只写回轮询状态，不会覆盖用户的确认结果
+        let mut poll_state = stored_poll_state;
+        poll_state.poll(100);
+        stored_poll_state = poll_state;
+        assert_eq!(stored_poll_state.last_polled_at, Some(100));
+        // 下一次轮询可以读取到用户的确认结果
+        assert_eq!(stored_authorization.approved, Some(true));
+        assert_eq!(stored_authorization.user_id, Some(1));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::service::admin_auth::AdminAuthService;
+use crate::service::oauth2_code::OAuth2CodeService;
+use crate::service::oauth2_device::OAuth2DeviceService;
+use crate::service::oauth2_token::OAuth2TokenService;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use daoyi_common::app::TenantContextHolder;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::utils::enumeration::{
+    EMPTY_VEC_STR, LoginLogTypeEnum, LoginResultEnum, UserTypeEnum,
+};
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    OAUTH2_CLIENT_CLIENT_SECRET_ERROR, OAUTH2_GRANT_CLIENT_ID_MISMATCH,
+    OAUTH2_GRANT_CODE_VERIFIER_MISMATCH, OAUTH2_GRANT_REDIRECT_URI_MISMATCH,
+    OAUTH2_GRANT_STATE_MISMATCH,
+};
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use sha2::{Digest, Sha256};
+
+/// PKCE 支持的 code_challenge_method，仅支持 S256
+pub const CODE_CHALLENGE_METHOD_S256: &str = "S256";
+
+pub struct OAuth2GrantService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2GrantService);
+impl OAuth2GrantService {
+    /// 授权码模式，第一阶段，获得 code 授权码
+    #[allow(clippy::too_many_arguments)]
+    pub async fn grant_authorization_code_for_code(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+        scopes: &[String],
+        redirect_uri: &str,
+        state: Option<String>,
+        code_challenge: Option<String>,
+        nonce: Option<String>,
+    ) -> ApiResult<String> {
+        let code_challenge_method = code_challenge
+            .as_ref()
+            .map(|_| CODE_CHALLENGE_METHOD_S256.to_string());
+        Ok(OAuth2CodeService::new(self.tenant)
+            .create_authorization_code(
+                user_id,
+                user_type,
+                client_id,
+                scopes,
+                redirect_uri,
+                state,
+                code_challenge,
+                code_challenge_method,
+                nonce,
+            )
+            .await?
+            .code)
+    }
+
+    /// 授权码模式，第二阶段，获得 accessToken 访问令牌
+    ///
+    /// 客户端未使用密钥认证时，必须在第一阶段使用 PKCE。
+    /// 返回访问令牌，以及第一阶段传递的 OpenID Connect nonce
+    #[allow(clippy::too_many_arguments)]
+    pub async fn grant_authorization_code_for_access_token(
+        &self,
+        client_id: &str,
+        client_authenticated: bool,
+        code: &str,
+        redirect_uri: Option<&str>,
+        state: Option<&str>,
+        code_verifier: Option<&str>,
+        client_info: &ClientInfo,
+    ) -> ApiResult<(system_oauth2_access_token::Model, Option<String>)> {
+        let code = OAuth2CodeService::new(self.tenant)
+            .consume_authorization_code(code)
+            .await?;
+        // 校验 clientId 是否匹配
+        if code.client_id != client_id {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_CLIENT_ID_MISMATCH));
+        }
+        // 校验 redirectUri 是否匹配
+        if code
+            .redirect_uri
+            .as_deref()
+            .is_some_and(|uri| !uri.is_empty() && Some(uri) != redirect_uri)
+        {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_REDIRECT_URI_MISMATCH));
+        }
+        // 校验 state 是否匹配，state 参数是可选的，传递时才校验
+        if state.is_some_and(|state| !state.is_empty() && state != code.state) {
+            return Err(ApiError::BizCode(OAUTH2_GRANT_STATE_MISMATCH));
+        }
+        // 校验 PKCE 的 code_verifier，未使用 PKCE 时客户端必须使用密钥认证
+        if let Some(code_challenge) = code.code_challenge.as_deref() {
+            if !code_verifier
+                .is_some_and(|verifier| Self::verify_code_challenge(verifier, code_challenge))
+            {
+                return Err(ApiError::BizCode(OAUTH2_GRANT_CODE_VERIFIER_MISMATCH));
+            }
+        } else if !client_authenticated {
+            return Err(ApiError::BizCodeWithArgs(
+                OAUTH2_CLIENT_CLIENT_SECRET_ERROR,
+                vec![String::new()],
+            ));
+        }
+        // 创建访问令牌
+        let scopes = serde_json::from_str::<Vec<String>>(
+            code.scopes
+                .as_deref()
+                .filter(|scopes| !scopes.is_empty())
+                .unwrap_or(EMPTY_VEC_STR),
+        )?;
+        let access_token = OAuth2TokenService::new(self.tenant)
+            .create_access_token(code.user_id, code.user_type, client_id, scopes, client_info)
+            .await?;
+        Ok((access_token, code.nonce))
+    }
+
+    /// 密码模式，使用用户名、密码获得访问令牌
+    pub async fn grant_password(
+        &self,
+        username: &str,
+        password: &str,
+        client_id: &str,
+        scopes: Vec<String>,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        // 使用账号 + 密码进行登录
+        let auth_service = AdminAuthService::new(self.tenant);
+        let user = auth_service
+            .authenticate(username, password, client_info)
+            .await?;
+        // 创建访问令牌
+        let access_token = OAuth2TokenService::new(self.tenant)
+            .create_access_token(
+                user.id,
+                UserTypeEnum::Admin.value(),
+                client_id,
+                scopes,
+                client_info,
+            )
+            .await?;
+        // 插入登陆日志
+        auth_service
+            .create_login_log(
+                user.id,
+                &user.username,
+                LoginLogTypeEnum::LoginUsername,
+                LoginResultEnum::Success,
+                client_info,
+            )
+            .await?;
+        Ok(access_token)
+    }
+
+    /// 设备授权模式，设备使用设备码轮询，用户批准后获得访问令牌
+    pub async fn grant_device_code(
+        &self,
+        client_id: &str,
+        device_code: &str,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        OAuth2DeviceService::new(self.tenant)
+            .grant_device_code(client_id, device_code, client_info)
+            .await
+    }
+
+    /// 刷新模式，使用刷新令牌获得新的访问令牌
+    pub async fn grant_refresh_token(
+        &self,
+        refresh_token: &str,
+        client_id: &str,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        OAuth2TokenService::new(self.tenant)
+            .refresh_access_token(refresh_token.to_string(), client_id, client_info)
+            .await
+    }
+
+    /// 客户端模式，使用客户端自身的身份获得访问令牌
+    pub async fn grant_client_credentials(
+        &self,
+        client_id: &str,
+        scopes: Vec<String>,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        OAuth2TokenService::new(self.tenant)
+            .create_client_access_token(client_id, scopes, client_info)
+            .await
+    }
+
+    /// 校验 PKCE 的 code_verifier，即 BASE64URL(SHA256(code_verifier)) == code_challenge
+    fn verify_code_challenge(code_verifier: &str, code_challenge: &str) -> bool {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes())) == code_challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_code_challenge() {
+        // RFC 7636 附录 B 的示例
+        let code_verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let code_challenge = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+        assert!(OAuth2GrantService::verify_code_challenge(
+            code_verifier,
+            code_challenge
+        ));
+        assert!(!OAuth2GrantService::verify_code_challenge(
+            code_challenge,
+            code_challenge
+        ));
+    }
+}
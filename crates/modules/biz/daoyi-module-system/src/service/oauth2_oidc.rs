@@ -0,0 +1,281 @@
+use crate::service::admin_user::AdminUserService;
+use crate::service::dept::DeptService;
+use crate::service::oauth2_grant::CODE_CHALLENGE_METHOD_S256;
+use crate::service::oauth2_token::OAuth2TokenService;
+use crate::vo::oauth2::oauth2_error_resp_vo::OAuth2ErrorRespVo;
+use crate::vo::oauth2::oidc_discovery_resp_vo::OidcDiscoveryRespVo;
+use crate::vo::oauth2::oidc_user_info_resp_vo::OidcUserInfoRespVo;
+use anyhow::Context;
+use daoyi_common::app::TenantContextHolder;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::config;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::utils::enumeration::oauth2_error_constants::{
+    INSUFFICIENT_SCOPE, INVALID_TOKEN,
+};
+use daoyi_common_support::utils::enumeration::{EMPTY_VEC_STR, OAuth2GrantTypeEnum, UserTypeEnum};
+use daoyi_common_support::utils::errors::DEPT_NOT_FOUND;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use jsonwebtoken::jwk::{Jwk, JwkSet, PublicKeyUse, ThumbprintHash};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::EncodeRsaPrivateKey;
+use rsa::rand_core::OsRng;
+use sea_orm::sqlx::types::chrono::Local;
+use serde::Serialize;
+use tokio::sync::OnceCell;
+
+/// OpenID Connect 的授权范围，申请后才会签发 id_token
+pub const SCOPE_OPENID: &str = "openid";
+/// 用户基本信息的授权范围：昵称、账号、头像
+pub const SCOPE_PROFILE: &str = "profile";
+/// 用户邮箱的授权范围
+pub const SCOPE_EMAIL: &str = "email";
+/// 用户手机号码的授权范围
+pub const SCOPE_PHONE: &str = "phone";
+/// 用户部门的授权范围，非 OpenID Connect 标准
+pub const SCOPE_DEPT: &str = "dept";
+
+/// id_token 的签名算法
+const ID_TOKEN_ALGORITHM: Algorithm = Algorithm::RS256;
+/// 未配置私钥时，随机生成的 RSA 私钥长度
+const GENERATED_KEY_BITS: usize = 2048;
+
+/// id_token 的签名密钥，首次使用时加载
+static SIGNING_KEY: OnceCell<SigningKey> = OnceCell::const_new();
+
+struct SigningKey {
+    encoding_key: EncodingKey,
+    /// 签名公钥，kid 为公钥的 SHA-256 指纹
+    jwk: Jwk,
+}
+
+impl SigningKey {
+    async fn get() -> ApiResult<&'static Self> {
+        SIGNING_KEY.get_or_try_init(Self::load).await
+    }
+
+    async fn load() -> ApiResult<Self> {
+        let private_key_path = config::get()
+            .await
+            .oidc()
+            .private_key_path()
+            .map(String::from);
+        let encoding_key = match private_key_path {
+            Some(path) => {
+                let pem = tokio::fs::read(&path)
+                    .await
+                    .with_context(|| format!("读取 OpenID Connect 签名私钥失败: {path}"))?;
+                EncodingKey::from_rsa_pem(&pem)?
+            }
+            None => {
+                tracing::warn!(
+                    "未配置 OpenID Connect 签名私钥，使用随机生成的私钥，重启或多实例部署时 id_token 将无法校验"
+                );
+                // 生成 RSA 私钥比较耗时，避免阻塞异步运行时
+                let der = tokio::task::spawn_blocking(|| -> anyhow::Result<Vec<u8>> {
+                    let private_key = RsaPrivateKey::new(&mut OsRng, GENERATED_KEY_BITS)?;
+                    Ok(private_key.to_pkcs1_der()?.as_bytes().to_vec())
+                })
+                .await
+                .map_err(anyhow::Error::from)??;
+                EncodingKey::from_rsa_der(&der)
+            }
+        };
+        let mut jwk = Jwk::from_encoding_key(&encoding_key, ID_TOKEN_ALGORITHM)?;
+        jwk.common.public_key_use = Some(PublicKeyUse::Signature);
+        jwk.common.key_id = Some(jwk.thumbprint(ThumbprintHash::SHA256));
+        Ok(Self { encoding_key, jwk })
+    }
+}
+
+/// id_token 的声明，参见 OpenID Connect Core 1.0 2. ID Token
+#[derive(Debug, Serialize)]
+struct IdTokenClaims<'a> {
+    iss: &'a str,
+    sub: String,
+    aud: &'a str,
+    exp: i64,
+    iat: i64,
+    auth_time: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<&'a str>,
+    tenant_id: i64,
+}
+
+pub struct OAuth2OidcService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2OidcService);
+impl OAuth2OidcService {
+    /// 获得 OpenID Connect 发现文档
+    pub async fn get_openid_configuration(&self) -> OidcDiscoveryRespVo {
+        let config = config::get().await;
+        let oidc = config.oidc();
+        let issuer = oidc.issuer();
+        let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+        OidcDiscoveryRespVo {
+            authorization_endpoint: oidc.authorization_endpoint(),
+            claims_supported: strings(&[
+                "sub",
+                "iss",
+                "aud",
+                "exp",
+                "iat",
+                "auth_time",
+                "nonce",
+                "nickname",
+                "preferred_username",
+                "picture",
+                "email",
+                "phone_number",
+                "dept_id",
+                "dept_name",
+            ]),
+            code_challenge_methods_supported: strings(&[CODE_CHALLENGE_METHOD_S256]),
+            device_authorization_endpoint: format!("{issuer}/device/authorize"),
+            grant_types_supported: strings(&[
+                OAuth2GrantTypeEnum::AuthorizationCode.grant_type(),
+                OAuth2GrantTypeEnum::Password.grant_type(),
+                OAuth2GrantTypeEnum::ClientCredentials.grant_type(),
+                OAuth2GrantTypeEnum::RefreshToken.grant_type(),
+                OAuth2GrantTypeEnum::DeviceCode.grant_type(),
+            ]),
+            id_token_signing_alg_values_supported: strings(&["RS256"]),
+            introspection_endpoint: format!("{issuer}/introspect"),
+            issuer: issuer.to_string(),
+            jwks_uri: format!("{issuer}/jwks"),
+            response_types_supported: strings(&["code"]),
+            revocation_endpoint: format!("{issuer}/revoke"),
+            scopes_supported: strings(&[
+                SCOPE_OPENID,
+                SCOPE_PROFILE,
+                SCOPE_EMAIL,
+                SCOPE_PHONE,
+                SCOPE_DEPT,
+            ]),
+            subject_types_supported: strings(&["public"]),
+            token_endpoint: format!("{issuer}/token"),
+            token_endpoint_auth_methods_supported: strings(&[
+                "client_secret_basic",
+                "client_secret_post",
+                // 仅限使用 PKCE 的授权码模式、设备码模式
+                "none",
+            ]),
+            userinfo_endpoint: format!("{issuer}/userinfo"),
+        }
+    }
+
+    /// 获得签名 id_token 的公钥集合
+    pub async fn get_jwks(&self) -> ApiResult<JwkSet> {
+        let signing_key = SigningKey::get().await?;
+        Ok(JwkSet {
+            keys: vec![signing_key.jwk.clone()],
+        })
+    }
+
+    /// 签发 id_token，仅当访问令牌关联用户，且授权范围包含 openid 时签发
+    pub async fn create_id_token(
+        &self,
+        access_token: &system_oauth2_access_token::Model,
+        nonce: Option<&str>,
+    ) -> ApiResult<Option<String>> {
+        // 客户端模式的访问令牌不关联用户，没有刷新令牌
+        if access_token.refresh_token.is_empty()
+            || !Self::parse_scopes(access_token).contains(&SCOPE_OPENID.to_string())
+        {
+            return Ok(None);
+        }
+        let signing_key = SigningKey::get().await?;
+        let config = config::get().await;
+        let to_timestamp = |time: &sea_orm::prelude::DateTime| {
+            time.and_local_timezone(Local)
+                .earliest()
+                .map(|time| time.timestamp())
+                .unwrap_or_default()
+        };
+        let claims = IdTokenClaims {
+            iss: config.oidc().issuer(),
+            sub: access_token.user_id.to_string(),
+            aud: &access_token.client_id,
+            exp: to_timestamp(&access_token.expires_time),
+            iat: Local::now().timestamp(),
+            auth_time: to_timestamp(&access_token.create_time),
+            nonce: nonce.filter(|nonce| !nonce.is_empty()),
+            tenant_id: access_token.tenant_id,
+        };
+        let mut header = Header::new(ID_TOKEN_ALGORITHM);
+        header.kid = signing_key.jwk.common.key_id.clone();
+        let id_token = jsonwebtoken::encode(&header, &claims, &signing_key.encoding_key)?;
+        Ok(Some(id_token))
+    }
+
+    /// 获得访问令牌对应用户的信息，按照访问令牌的授权范围返回
+    pub async fn get_user_info(
+        &self,
+        principal: &Principal,
+    ) -> Result<OidcUserInfoRespVo, OAuth2ErrorRespVo> {
+        // 1. 校验访问令牌，必须包含 openid 授权范围
+        let access_token = OAuth2TokenService::new(self.tenant)
+            .check_access_token(&principal.token)
+            .await?
+            .ok_or_else(|| OAuth2ErrorRespVo::new(INVALID_TOKEN, "访问令牌不存在或已过期"))?;
+        let scopes = Self::parse_scopes(&access_token);
+        if !scopes.contains(&SCOPE_OPENID.to_string()) {
+            return Err(OAuth2ErrorRespVo::new(
+                INSUFFICIENT_SCOPE,
+                "访问令牌未授权 openid 范围",
+            ));
+        }
+        if access_token.refresh_token.is_empty() || !UserTypeEnum::is_admin(access_token.user_type)
+        {
+            return Err(OAuth2ErrorRespVo::new(
+                INVALID_TOKEN,
+                "访问令牌未关联管理后台用户",
+            ));
+        }
+        // 2. 获得用户信息，用户可能属于其它租户，以访问令牌的租户为准
+        let tenant = TenantContextHolder::set_tenant_id(access_token.tenant_id);
+        let user = AdminUserService::new(tenant)
+            .get_user(access_token.user_id)
+            .await?;
+        let granted = |scope: &str| scopes.iter().any(|granted| granted == scope);
+        let mut user_info = OidcUserInfoRespVo {
+            dept_id: None,
+            dept_name: None,
+            email: None,
+            nickname: None,
+            phone_number: None,
+            picture: None,
+            preferred_username: None,
+            sub: user.id.to_string(),
+        };
+        // 3. 按照授权范围，填充用户声明
+        if granted(SCOPE_PROFILE) {
+            user_info.nickname = Some(user.nickname);
+            user_info.picture = user.avatar.filter(|avatar| !avatar.is_empty());
+            user_info.preferred_username = Some(user.username);
+        }
+        if granted(SCOPE_EMAIL) {
+            user_info.email = user.email.filter(|email| !email.is_empty());
+        }
+        if granted(SCOPE_PHONE) {
+            user_info.phone_number = user.mobile.filter(|mobile| !mobile.is_empty());
+        }
+        if let Some(dept_id) = user.dept_id.filter(|_| granted(SCOPE_DEPT)) {
+            user_info.dept_id = Some(dept_id);
+            user_info.dept_name = match DeptService::new(tenant).get_dept(dept_id).await {
+                Ok(dept) => Some(dept.name),
+                Err(ApiError::BizCode(error_code)) if error_code == DEPT_NOT_FOUND => None,
+                Err(error) => return Err(error.into()),
+            };
+        }
+        Ok(user_info)
+    }
+
+    fn parse_scopes(access_token: &system_oauth2_access_token::Model) -> Vec<String> {
+        serde_json::from_str::<Vec<String>>(access_token.scopes.as_deref().unwrap_or(EMPTY_VEC_STR))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,448 @@
+use crate::service::oauth2_approve::OAuth2ApproveService;
+use crate::service::oauth2_client::OAuth2ClientService;
+use crate::service::oauth2_device::OAuth2DeviceService;
+use crate::service::oauth2_grant::{CODE_CHALLENGE_METHOD_S256, OAuth2GrantService};
+use crate::service::oauth2_oidc::OAuth2OidcService;
+use crate::service::oauth2_token::OAuth2TokenService;
+use crate::vo::oauth2::oauth2_access_token_resp_vo::OAuth2AccessTokenRespVo;
+use crate::vo::oauth2::oauth2_authorize_client_vo::OAuth2AuthorizeClientVo;
+use crate::vo::oauth2::oauth2_authorize_info_resp_vo::OAuth2AuthorizeInfoRespVo;
+use crate::vo::oauth2::oauth2_authorize_req_vo::OAuth2AuthorizeReqVo;
+use crate::vo::oauth2::oauth2_authorize_scope_vo::OAuth2AuthorizeScopeVo;
+use crate::vo::oauth2::oauth2_device_authorization_req_vo::OAuth2DeviceAuthorizationReqVo;
+use crate::vo::oauth2::oauth2_device_authorization_resp_vo::OAuth2DeviceAuthorizationRespVo;
+use crate::vo::oauth2::oauth2_error_resp_vo::OAuth2ErrorRespVo;
+use crate::vo::oauth2::oauth2_introspect_req_vo::OAuth2IntrospectReqVo;
+use crate::vo::oauth2::oauth2_introspect_resp_vo::OAuth2IntrospectRespVo;
+use crate::vo::oauth2::oauth2_revoke_req_vo::OAuth2RevokeReqVo;
+use crate::vo::oauth2::oauth2_token_req_vo::OAuth2TokenReqVo;
+use axum::http::{HeaderMap, header};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use daoyi_common::app::TenantContextHolder;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::utils::enumeration::oauth2_error_constants::{
+    INVALID_CLIENT, INVALID_REQUEST, UNSUPPORTED_GRANT_TYPE,
+};
+use daoyi_common_support::utils::enumeration::{EMPTY_VEC_STR, OAuth2GrantTypeEnum};
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::web::client_info::ClientInfo;
+use daoyi_entities_system::entity::system_oauth2_client;
+use std::collections::{BTreeMap, HashMap};
+
+/// 授权码模式的响应类型
+const RESPONSE_TYPE_CODE: &str = "code";
+/// 撤销令牌时，刷新令牌的类型提示
+const TOKEN_TYPE_HINT_REFRESH_TOKEN: &str = "refresh_token";
+
+pub struct OAuth2OpenService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(OAuth2OpenService);
+impl OAuth2OpenService {
+    /// 获得授权页的信息，包括客户端与用户对各授权范围的批准情况
+    pub async fn get_authorize_info(
+        &self,
+        principal: &Principal,
+        client_id: &str,
+    ) -> ApiResult<OAuth2AuthorizeInfoRespVo> {
+        // 1. 获得 Client 客户端的信息
+        let client = OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache(client_id)
+            .await?;
+        // 2. 获得用户已经批准的授权范围
+        let approves = OAuth2ApproveService::new(self.tenant)
+            .get_approve_list(principal.user_id, principal.user_type.value(), client_id)
+            .await?
+            .into_iter()
+            .map(|approve| (approve.scope, approve.approved))
+            .collect::<HashMap<_, _>>();
+        let scopes =
+            serde_json::from_str::<Vec<String>>(client.scopes.as_deref().unwrap_or(EMPTY_VEC_STR))?
+                .into_iter()
+                .map(|scope| OAuth2AuthorizeScopeVo {
+                    value: approves.get(&scope).copied().unwrap_or(false),
+                    key: scope,
+                })
+                .collect();
+        Ok(OAuth2AuthorizeInfoRespVo {
+            client: OAuth2AuthorizeClientVo::from(client),
+            scopes,
+        })
+    }
+
+    /// 申请授权，返回携带授权码的重定向地址
+    ///
+    /// 自动授权且用户尚未批准全部授权范围时返回 None，由前端展示授权页让用户确认
+    pub async fn approve_or_deny(
+        &self,
+        principal: &Principal,
+        req_vo: OAuth2AuthorizeReqVo,
+    ) -> ApiResult<Option<String>> {
+        // 0. 校验响应类型与 PKCE 参数
+        if req_vo.response_type != RESPONSE_TYPE_CODE {
+            return Err(ApiError::Validation(String::from(
+                "response_type 参数值只允许 code",
+            )));
+        }
+        if req_vo.code_challenge.is_some()
+            && req_vo.code_challenge_method.as_deref() != Some(CODE_CHALLENGE_METHOD_S256)
+        {
+            return Err(ApiError::Validation(String::from(
+                "code_challenge_method 参数值只允许 S256",
+            )));
+        }
+        // 1. 校验 redirectUri 重定向域名是否合法 + 校验 scope 是否在 Client 授权范围内
+        let scopes = req_vo
+            .scope
+            .as_deref()
+            .filter(|scope| !scope.is_empty())
+            .map(serde_json::from_str::<BTreeMap<String, bool>>)
+            .transpose()?
+            .unwrap_or_default();
+        OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache2(
+                &req_vo.client_id,
+                "",
+                OAuth2GrantTypeEnum::AuthorizationCode.grant_type(),
+                scopes.keys().map(String::as_str).collect(),
+                &req_vo.redirect_uri,
+            )
+            .await?;
+        // 2.1 假设 approved 为 true，说明是自动授权，计算是否已经全部批准
+        let user_type = principal.user_type.value();
+        let approve_service = OAuth2ApproveService::new(self.tenant);
+        let approved_scopes = if req_vo.auto_approve {
+            let requested_scopes = scopes.keys().cloned().collect::<Vec<_>>();
+            if !approve_service
+                .check_for_pre_approval(
+                    principal.user_id,
+                    user_type,
+                    &req_vo.client_id,
+                    &requested_scopes,
+                )
+                .await?
+            {
+                return Ok(None);
+            }
+            requested_scopes
+        } else {
+            // 2.2 假设 approved 为 false，说明是用户在授权页的选择，记录批准结果
+            let requested_scopes = scopes.iter().map(|(k, v)| (k.clone(), *v)).collect();
+            if !approve_service
+                .update_after_approval(
+                    principal.user_id,
+                    user_type,
+                    &req_vo.client_id,
+                    &requested_scopes,
+                )
+                .await?
+            {
+                return Ok(Some(Self::build_redirect_uri(
+                    &req_vo.redirect_uri,
+                    &[
+                        ("error", Some("access_denied")),
+                        ("error_description", Some("User denied access")),
+                        ("state", req_vo.state.as_deref()),
+                    ],
+                )?));
+            }
+            scopes
+                .into_iter()
+                .filter(|(_, approved)| *approved)
+                .map(|(scope, _)| scope)
+                .collect()
+        };
+        // 3. 生成授权码，拼接重定向地址
+        let code = OAuth2GrantService::new(self.tenant)
+            .grant_authorization_code_for_code(
+                principal.user_id,
+                user_type,
+                &req_vo.client_id,
+                &approved_scopes,
+                &req_vo.redirect_uri,
+                req_vo.state.clone(),
+                req_vo.code_challenge,
+                req_vo.nonce,
+            )
+            .await?;
+        Ok(Some(Self::build_redirect_uri(
+            &req_vo.redirect_uri,
+            &[("code", Some(&code)), ("state", req_vo.state.as_deref())],
+        )?))
+    }
+
+    /// 获得访问令牌，客户端可以使用 Basic 认证或表单参数传递客户端编号与密钥
+    ///
+    /// 遵循 RFC 6749 规范，失败时返回 OAuth2 规范的错误
+    pub async fn post_access_token(
+        &self,
+        headers: &HeaderMap,
+        client_info: &ClientInfo,
+        req_vo: OAuth2TokenReqVo,
+    ) -> Result<OAuth2AccessTokenRespVo, OAuth2ErrorRespVo> {
+        // 1.1 校验授权类型
+        let grant_type = OAuth2GrantTypeEnum::from_grant_type(&req_vo.grant_type)
+            .filter(|grant_type| *grant_type != OAuth2GrantTypeEnum::Implicit)
+            .ok_or_else(|| {
+                OAuth2ErrorRespVo::new(
+                    UNSUPPORTED_GRANT_TYPE,
+                    format!("不支持的授权类型: {}", req_vo.grant_type),
+                )
+            })?;
+        // 1.2 获得客户端编号与密钥
+        let (client_id, client_secret) = Self::obtain_client_credentials(
+            headers,
+            req_vo.client_id.as_deref(),
+            req_vo.client_secret.as_deref(),
+        )?;
+        // 只有使用 PKCE 的授权码模式、设备码模式允许公开客户端，其它授权类型必须使用密钥认证
+        let public_client_allowed = matches!(
+            grant_type,
+            OAuth2GrantTypeEnum::AuthorizationCode | OAuth2GrantTypeEnum::DeviceCode
+        );
+        if !public_client_allowed && client_secret.is_empty() {
+            return Err(OAuth2ErrorRespVo::new(
+                INVALID_CLIENT,
+                "client_secret 不能为空",
+            ));
+        }
+        // 1.3 校验客户端，包括密钥、授权类型、授权范围
+        let scopes = Self::split_scopes(req_vo.scope.as_deref());
+        OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache2(
+                &client_id,
+                &client_secret,
+                grant_type.grant_type(),
+                scopes.iter().map(String::as_str).collect(),
+                req_vo.redirect_uri.as_deref().unwrap_or_default(),
+            )
+            .await?;
+        // 2. 根据授权类型，获得访问令牌
+        let grant_service = OAuth2GrantService::new(self.tenant);
+        let (access_token, nonce) = match grant_type {
+            OAuth2GrantTypeEnum::AuthorizationCode => {
+                let code = Self::required_param(req_vo.code.as_deref(), "code")?;
+                grant_service
+                    .grant_authorization_code_for_access_token(
+                        &client_id,
+                        !client_secret.is_empty(),
+                        code,
+                        req_vo.redirect_uri.as_deref(),
+                        req_vo.state.as_deref(),
+                        req_vo.code_verifier.as_deref(),
+                        client_info,
+                    )
+                    .await?
+            }
+            OAuth2GrantTypeEnum::Password => {
+                let username = Self::required_param(req_vo.username.as_deref(), "username")?;
+                let password = Self::required_param(req_vo.password.as_deref(), "password")?;
+                let access_token = grant_service
+                    .grant_password(username, password, &client_id, scopes, client_info)
+                    .await?;
+                (access_token, None)
+            }
+            OAuth2GrantTypeEnum::RefreshToken => {
+                let refresh_token =
+                    Self::required_param(req_vo.refresh_token.as_deref(), "refresh_token")?;
+                let access_token = grant_service
+                    .grant_refresh_token(refresh_token, &client_id, client_info)
+                    .await?;
+                (access_token, None)
+            }
+            OAuth2GrantTypeEnum::ClientCredentials => {
+                let access_token = grant_service
+                    .grant_client_credentials(&client_id, scopes, client_info)
+                    .await?;
+                (access_token, None)
+            }
+            OAuth2GrantTypeEnum::DeviceCode => {
+                let device_code =
+                    Self::required_param(req_vo.device_code.as_deref(), "device_code")?;
+                let access_token = grant_service
+                    .grant_device_code(&client_id, device_code, client_info)
+                    .await?;
+                (access_token, None)
+            }
+            OAuth2GrantTypeEnum::Implicit => unreachable!("简化模式不通过令牌端点授权"),
+        };
+        // 3. 申请 openid 授权范围时，签发 id_token
+        let id_token = OAuth2OidcService::new(self.tenant)
+            .create_id_token(&access_token, nonce.as_deref())
+            .await?;
+        Ok(OAuth2AccessTokenRespVo {
+            id_token,
+            ..OAuth2AccessTokenRespVo::from(access_token)
+        })
+    }
+
+    /// 申请设备授权，遵循 RFC 8628 规范，公开客户端可以不传递客户端密钥
+    pub async fn create_device_authorization(
+        &self,
+        headers: &HeaderMap,
+        req_vo: OAuth2DeviceAuthorizationReqVo,
+    ) -> Result<OAuth2DeviceAuthorizationRespVo, OAuth2ErrorRespVo> {
+        // 1. 校验客户端，包括密钥、授权类型、授权范围
+        let (client_id, client_secret) = Self::obtain_client_credentials(
+            headers,
+            req_vo.client_id.as_deref(),
+            req_vo.client_secret.as_deref(),
+        )?;
+        let scopes = Self::split_scopes(req_vo.scope.as_deref());
+        OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache2(
+                &client_id,
+                &client_secret,
+                OAuth2GrantTypeEnum::DeviceCode.grant_type(),
+                scopes.iter().map(String::as_str).collect(),
+                "",
+            )
+            .await?;
+        // 2. 生成设备码与用户码
+        let device_authorization = OAuth2DeviceService::new(self.tenant)
+            .create_device_authorization(&client_id, scopes)
+            .await?;
+        Ok(device_authorization)
+    }
+
+    /// 校验访问令牌，遵循 RFC 7662 规范，令牌无效时返回 active = false
+    pub async fn introspect_token(
+        &self,
+        headers: &HeaderMap,
+        req_vo: OAuth2IntrospectReqVo,
+    ) -> Result<OAuth2IntrospectRespVo, OAuth2ErrorRespVo> {
+        // 1. 校验客户端
+        self.authenticate_client(
+            headers,
+            req_vo.client_id.as_deref(),
+            req_vo.client_secret.as_deref(),
+        )
+        .await?;
+        // 2. 校验访问令牌，刷新令牌不支持校验
+        let access_token = OAuth2TokenService::new(self.tenant)
+            .check_access_token(&req_vo.token)
+            .await?;
+        Ok(access_token
+            .map(OAuth2IntrospectRespVo::from)
+            .unwrap_or_else(OAuth2IntrospectRespVo::inactive))
+    }
+
+    /// 撤销令牌，遵循 RFC 7009 规范，令牌无效或不属于该客户端时同样视为成功
+    pub async fn revoke_token(
+        &self,
+        headers: &HeaderMap,
+        req_vo: OAuth2RevokeReqVo,
+    ) -> Result<(), OAuth2ErrorRespVo> {
+        // 1. 校验客户端
+        let client = self
+            .authenticate_client(
+                headers,
+                req_vo.client_id.as_deref(),
+                req_vo.client_secret.as_deref(),
+            )
+            .await?;
+        // 2. 撤销令牌，级联撤销刷新令牌派生的访问令牌
+        let refresh_token_first =
+            req_vo.token_type_hint.as_deref() == Some(TOKEN_TYPE_HINT_REFRESH_TOKEN);
+        let revoked = OAuth2TokenService::new(self.tenant)
+            .revoke_token(&req_vo.token, &client.client_id, refresh_token_first)
+            .await?;
+        if !revoked {
+            tracing::debug!("客户端({})撤销的令牌无效，忽略", client.client_id);
+        }
+        Ok(())
+    }
+
+    /// 认证客户端，必须使用客户端密钥，用于校验令牌与撤销令牌
+    async fn authenticate_client(
+        &self,
+        headers: &HeaderMap,
+        client_id: Option<&str>,
+        client_secret: Option<&str>,
+    ) -> Result<system_oauth2_client::Model, OAuth2ErrorRespVo> {
+        let (client_id, client_secret) =
+            Self::obtain_client_credentials(headers, client_id, client_secret)?;
+        if client_secret.is_empty() {
+            return Err(OAuth2ErrorRespVo::new(
+                INVALID_CLIENT,
+                "client_secret 不能为空",
+            ));
+        }
+        let client = OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache2(&client_id, &client_secret, "", vec![], "")
+            .await?;
+        Ok(client)
+    }
+
+    /// 获得客户端编号与密钥，优先使用 Basic 认证，其次使用表单参数
+    fn obtain_client_credentials(
+        headers: &HeaderMap,
+        client_id: Option<&str>,
+        client_secret: Option<&str>,
+    ) -> Result<(String, String), OAuth2ErrorRespVo> {
+        let (client_id, client_secret) = match Self::obtain_basic_authorization(headers)? {
+            Some(credentials) => credentials,
+            None => (
+                client_id.unwrap_or_default().to_string(),
+                client_secret.unwrap_or_default().to_string(),
+            ),
+        };
+        if client_id.is_empty() {
+            return Err(OAuth2ErrorRespVo::new(INVALID_CLIENT, "client_id 不能为空"));
+        }
+        Ok((client_id, client_secret))
+    }
+
+    /// 解析空格分隔的授权范围
+    fn split_scopes(scope: Option<&str>) -> Vec<String> {
+        scope
+            .map(|scope| scope.split_whitespace().map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// 校验令牌端点的必传参数
+    fn required_param<'a>(
+        value: Option<&'a str>,
+        name: &str,
+    ) -> Result<&'a str, OAuth2ErrorRespVo> {
+        value
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| OAuth2ErrorRespVo::new(INVALID_REQUEST, format!("{name} 不能为空")))
+    }
+
+    /// 解析 Basic 认证的客户端编号与密钥，未使用 Basic 认证时返回 None
+    fn obtain_basic_authorization(
+        headers: &HeaderMap,
+    ) -> Result<Option<(String, String)>, OAuth2ErrorRespVo> {
+        let Some(authorization) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Basic "))
+        else {
+            return Ok(None);
+        };
+        let invalid = || OAuth2ErrorRespVo::new(INVALID_CLIENT, "Basic 认证信息不正确");
+        let decoded = STANDARD
+            .decode(authorization.trim())
+            .map_err(|_| invalid())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+        let (client_id, client_secret) = decoded.split_once(':').ok_or_else(invalid)?;
+        Ok(Some((client_id.to_string(), client_secret.to_string())))
+    }
+
+    /// 在重定向地址上追加查询参数，值为 None 的参数忽略
+    fn build_redirect_uri(
+        redirect_uri: &str,
+        params: &[(&str, Option<&str>)],
+    ) -> ApiResult<String> {
+        let params = params
+            .iter()
+            .filter_map(|(key, value)| value.map(|value| (*key, value)))
+            .collect::<Vec<_>>();
+        let query = serde_urlencoded::to_string(params).map_err(anyhow::Error::from)?;
+        let separator = if redirect_uri.contains('?') { '&' } else { '?' };
+        Ok(format!("{redirect_uri}{separator}{query}"))
+    }
+}
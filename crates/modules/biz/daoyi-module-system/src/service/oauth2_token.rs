@@ -1,15 +1,29 @@
 use crate::service::admin_user::AdminUserService;
+use crate::service::login_logger::LoginLogService;
 use crate::service::oauth2_client::OAuth2ClientService;
+use crate::vo::logger::login_log_create_req_dto::LoginLogCreateReqDTO;
+use crate::vo::oauth2::oauth2_token_page_req_vo::OAuth2TokenPageReqVo;
+use crate::vo::oauth2::oauth2_token_resp_vo::OAuth2TokenRespVo;
+use crate::vo::user::user_session_resp_vo::UserSessionRespVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
 use daoyi_common::app::{TenantContextHolder, database, redis_util};
-use daoyi_common::impl_tenant_instance;
 use daoyi_common::security::login_user::LoginUser;
-use daoyi_common_support::support::orm::create_with_common_fields;
+use daoyi_common::{config, impl_tenant_instance};
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields,
+};
 use daoyi_common_support::utils;
 use daoyi_common_support::utils::enumeration::{
-    UserTypeEnum, redis_key_constants::OAUTH2_ACCESS_TOKEN,
+    LoginLogTypeEnum, LoginResultEnum, SessionLimitPolicy, UserTypeEnum,
+    redis_key_constants::{ACCESS_TOKEN, OAUTH2_ACCESS_TOKEN},
 };
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    AUTH_LOGIN_SESSION_LIMIT, OAUTH2_ACCESS_TOKEN_NOT_EXISTS, OAUTH2_SESSION_NOT_EXISTS,
+};
 use daoyi_common_support::utils::is_expired;
+use daoyi_common_support::utils::web::client_info::ClientInfo;
 use daoyi_entities_system::entity::prelude::{SystemOauth2AccessToken, SystemOauth2RefreshToken};
 use daoyi_entities_system::entity::{
     system_oauth2_access_token, system_oauth2_client, system_oauth2_refresh_token,
@@ -20,27 +34,56 @@ use std::collections::HashMap;
 use std::ops::Add;
 use std::time::Duration;
 
+/// 客户端模式的访问令牌使用的用户编号，表示不关联用户
+const CLIENT_USER_ID: i64 = 0;
+
 pub struct OAuth2TokenService {
     tenant: TenantContextHolder,
 }
 impl_tenant_instance!(OAuth2TokenService);
 impl OAuth2TokenService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemOauth2AccessToken> {
+        SystemOauth2AccessToken::find()
+            .filter(system_oauth2_access_token::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_oauth2_access_token::Column::Deleted.eq(0))
+    }
+}
+
+impl OAuth2TokenService {
+    /// 使用刷新令牌获得新的访问令牌
+    ///
+    /// 开启刷新令牌轮换时，每次刷新都会签发新的刷新令牌，旧的刷新令牌标记为已删除。
+    /// 已轮换的刷新令牌被再次使用时，说明令牌可能已被盗用，撤销整个令牌家族并记录登录日志
+    ///
+    /// 同一个刷新令牌被并发使用时，例如同一客户端的多个标签页同时刷新，只有一个请求能轮换成功，
+    /// 其它请求同样视为重用，撤销整个令牌家族，用户需要重新登录。这是为了及时发现令牌盗用做出的取舍，
+    /// 客户端应在多个标签页之间共享刷新结果，避免并发刷新
     pub async fn refresh_access_token(
         &self,
         refresh_token: String,
         client_id: &str,
+        client_info: &ClientInfo,
     ) -> ApiResult<system_oauth2_access_token::Model> {
         let db = database::get()?;
-        // 查询访问令牌
+        // 查询刷新令牌，包含已轮换的刷新令牌
         let refresh_token = SystemOauth2RefreshToken::find()
             .filter(system_oauth2_refresh_token::Column::RefreshToken.eq(refresh_token))
             .one(db)
             .await?
             .ok_or_else(|| ApiError::InvalidRefreshToken)?;
+        if refresh_token.deleted != 0 {
+            self.revoke_refresh_token_family(&refresh_token, client_info)
+                .await?;
+            return Err(ApiError::InvalidRefreshToken);
+        }
         // 校验 Client 匹配
         let client = OAuth2ClientService::new(self.tenant.clone())
             .valid_oauth_client_from_cache(client_id)
             .await?;
+        if refresh_token.client_id != client.client_id {
+            return Err(ApiError::InvalidRefreshToken);
+        }
         // 移除相关的访问令牌
         let access_tokens = SystemOauth2AccessToken::find()
             .filter(
@@ -49,14 +92,7 @@ impl OAuth2TokenService {
             .all(db)
             .await?;
         for access_token in access_tokens {
-            redis_util::del(&format!(
-                "{OAUTH2_ACCESS_TOKEN}:{}",
-                access_token.access_token
-            ))
-            .await?;
-            SystemOauth2AccessToken::delete_by_id(access_token.id)
-                .exec(db)
-                .await?;
+            self.delete_access_token(access_token).await?;
         }
         // 已过期的情况下，删除刷新令牌
         if is_expired(&refresh_token.expires_time)? {
@@ -65,49 +101,528 @@ impl OAuth2TokenService {
                 .await?;
             return Err(ApiError::Unauthenticated(String::from("刷新令牌已过期")));
         }
+        // 轮换刷新令牌，并发刷新时只有一个请求能轮换成功，其它请求视为重用
+        let refresh_token = if config::get().await.auth().refresh_token_rotation() {
+            match self.rotate_refresh_token(&refresh_token).await? {
+                Some(rotated) => rotated,
+                None => {
+                    self.revoke_refresh_token_family(&refresh_token, client_info)
+                        .await?;
+                    return Err(ApiError::InvalidRefreshToken);
+                }
+            }
+        } else {
+            refresh_token
+        };
         // 创建访问令牌
         self.create_oauth2access_token(&refresh_token, &client)
             .await
     }
 
-    pub async fn remove_access_token(&self, access_token: &str) -> ApiResult<()> {
+    /// 轮换刷新令牌：签发同一家族的新刷新令牌，过期时间沿用旧的刷新令牌，旧的刷新令牌标记为已删除
+    ///
+    /// 旧的刷新令牌已被其它请求标记为已删除时返回 None
+    async fn rotate_refresh_token(
+        &self,
+        refresh_token: &system_oauth2_refresh_token::Model,
+    ) -> ApiResult<Option<system_oauth2_refresh_token::Model>> {
         let db = database::get()?;
+        let operator = Some(refresh_token.user_id.to_string());
+        // 只删除未删除的旧刷新令牌，没有删除任何记录说明已被其它请求轮换
+        let result = SystemOauth2RefreshToken::update_many()
+            .set(
+                soft_delete_with_common_fields(
+                    system_oauth2_refresh_token::ActiveModel::new(),
+                    operator.clone(),
+                )
+                .await?,
+            )
+            .filter(system_oauth2_refresh_token::Column::Id.eq(refresh_token.id))
+            .filter(system_oauth2_refresh_token::Column::Deleted.eq(0))
+            .exec(db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Ok(None);
+        }
+        let active_model = system_oauth2_refresh_token::ActiveModel {
+            refresh_token: Set(utils::id::x()),
+            user_id: Set(refresh_token.user_id),
+            user_type: Set(refresh_token.user_type),
+            client_id: Set(refresh_token.client_id.to_owned()),
+            scopes: Set(refresh_token.scopes.to_owned()),
+            expires_time: Set(refresh_token.expires_time),
+            user_ip: Set(refresh_token.user_ip.to_owned()),
+            user_agent: Set(refresh_token.user_agent.to_owned()),
+            family_id: Set(Some(Self::family_id(refresh_token).to_string())),
+            ..Default::default()
+        };
+        let model = create_with_common_fields(active_model, operator, &self.tenant)
+            .await?
+            .insert(db)
+            .await?;
+        Ok(Some(model))
+    }
+
+    /// 刷新令牌所在的家族编号，轮换前未记录家族的刷新令牌以自身作为家族
+    fn family_id(refresh_token: &system_oauth2_refresh_token::Model) -> &str {
+        refresh_token
+            .family_id
+            .as_deref()
+            .unwrap_or(&refresh_token.refresh_token)
+    }
+
+    /// 刷新令牌是否属于指定的令牌家族
+    fn in_family(refresh_token: &system_oauth2_refresh_token::Model, family_id: &str) -> bool {
+        Self::family_id(refresh_token) == family_id
+    }
+
+    /// 撤销刷新令牌所在家族的全部刷新令牌，并记录刷新令牌重用的登录日志
+    async fn revoke_refresh_token_family(
+        &self,
+        refresh_token: &system_oauth2_refresh_token::Model,
+        client_info: &ClientInfo,
+    ) -> ApiResult<()> {
+        tracing::warn!(
+            "刷新令牌被重用，撤销令牌家族，用户编号: {}，IP: {}",
+            refresh_token.user_id,
+            client_info.ip
+        );
+        // 家族的首个刷新令牌可能是轮换前未记录家族的刷新令牌，其编号即为家族编号
+        let family_id = Self::family_id(refresh_token);
+        let family = SystemOauth2RefreshToken::find()
+            .filter(
+                Condition::any()
+                    .add(system_oauth2_refresh_token::Column::FamilyId.eq(family_id))
+                    .add(system_oauth2_refresh_token::Column::RefreshToken.eq(family_id)),
+            )
+            .all(database::get()?)
+            .await?;
+        for member in family
+            .iter()
+            .filter(|member| Self::in_family(member, family_id))
+        {
+            self.remove_refresh_token(&member.refresh_token).await?;
+        }
+        self.create_login_log(
+            refresh_token.user_id,
+            refresh_token.user_type,
+            LoginLogTypeEnum::RefreshTokenReuse,
+            LoginResultEnum::RefreshTokenReused,
+            client_info,
+        )
+        .await
+    }
+
+    /// 记录令牌相关的登录日志，用户可能已被删除，此时账号为空
+    async fn create_login_log(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        log_type: LoginLogTypeEnum,
+        result: LoginResultEnum,
+        client_info: &ClientInfo,
+    ) -> ApiResult<()> {
+        let username = if UserTypeEnum::is_admin(user_type) {
+            AdminUserService::new(self.tenant)
+                .get_user_list([user_id])
+                .await?
+                .into_iter()
+                .next()
+                .map(|user| user.username)
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+        LoginLogService::new(self.tenant)
+            .create_login_log(LoginLogCreateReqDTO {
+                log_type,
+                result,
+                trace_id: client_info.trace_id.clone(),
+                user_agent: client_info.user_agent.clone(),
+                user_id,
+                user_ip: client_info.ip.clone(),
+                user_type,
+                username,
+            })
+            .await
+    }
+
+    /// 获得访问令牌，优先从缓存中获取
+    pub async fn get_access_token(
+        &self,
+        access_token: &str,
+    ) -> ApiResult<Option<system_oauth2_access_token::Model>> {
+        let cached = redis_util::cache_get_json::<system_oauth2_access_token::Model>(&format!(
+            "{OAUTH2_ACCESS_TOKEN}:{access_token}"
+        ))
+        .await?;
+        if cached.is_some() {
+            return Ok(cached);
+        }
         let token = SystemOauth2AccessToken::find()
             .filter(system_oauth2_access_token::Column::AccessToken.eq(access_token))
-            .one(db)
+            .one(database::get()?)
             .await?;
-        if token.is_none() {
-            return Ok(());
+        Ok(token)
+    }
+
+    /// 校验访问令牌，不存在或已过期时返回 None
+    pub async fn check_access_token(
+        &self,
+        access_token: &str,
+    ) -> ApiResult<Option<system_oauth2_access_token::Model>> {
+        match self.get_access_token(access_token).await? {
+            Some(token) if !is_expired(&token.expires_time)? => Ok(Some(token)),
+            _ => Ok(None),
         }
-        let token = token.unwrap();
-        redis_util::del(&format!("{OAUTH2_ACCESS_TOKEN}:{access_token}")).await?;
-        SystemOauth2AccessToken::delete_many()
-            .filter(system_oauth2_access_token::Column::AccessToken.eq(access_token))
-            .exec(db)
+    }
+
+    /// 撤销客户端的令牌，令牌可以是访问令牌或刷新令牌
+    ///
+    /// 撤销访问令牌时，一并撤销其刷新令牌，以及刷新令牌派生的全部访问令牌。
+    /// 令牌不存在或不属于该客户端时忽略，返回是否撤销成功
+    pub async fn revoke_token(
+        &self,
+        token: &str,
+        client_id: &str,
+        refresh_token_first: bool,
+    ) -> ApiResult<bool> {
+        let db = database::get()?;
+        let find_access_token = async {
+            SystemOauth2AccessToken::find()
+                .filter(system_oauth2_access_token::Column::AccessToken.eq(token))
+                .one(db)
+                .await
+        };
+        let find_refresh_token = async {
+            SystemOauth2RefreshToken::find()
+                .filter(system_oauth2_refresh_token::Column::RefreshToken.eq(token))
+                .filter(system_oauth2_refresh_token::Column::Deleted.eq(0))
+                .one(db)
+                .await
+        };
+        // 1. 按照提示的令牌类型优先查找，找不到时再查找另一种类型
+        let (access_token, refresh_token) = if refresh_token_first {
+            match find_refresh_token.await? {
+                Some(refresh_token) => (None, Some(refresh_token)),
+                None => (find_access_token.await?, None),
+            }
+        } else {
+            match find_access_token.await? {
+                Some(access_token) => (Some(access_token), None),
+                None => (None, find_refresh_token.await?),
+            }
+        };
+        // 2.1 撤销访问令牌，客户端模式的访问令牌没有刷新令牌
+        if let Some(access_token) = access_token {
+            if access_token.client_id != client_id {
+                return Ok(false);
+            }
+            self.remove_session(access_token).await?;
+            return Ok(true);
+        }
+        // 2.2 撤销刷新令牌
+        match refresh_token {
+            Some(refresh_token) if refresh_token.client_id == client_id => {
+                self.remove_refresh_token(&refresh_token.refresh_token)
+                    .await?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// 移除刷新令牌，以及刷新令牌派生的全部访问令牌
+    async fn remove_refresh_token(&self, refresh_token: &str) -> ApiResult<()> {
+        let db = database::get()?;
+        let access_tokens = SystemOauth2AccessToken::find()
+            .filter(system_oauth2_access_token::Column::RefreshToken.eq(refresh_token))
+            .all(db)
             .await?;
+        for access_token in access_tokens {
+            self.delete_access_token(access_token).await?;
+        }
         SystemOauth2RefreshToken::delete_many()
-            .filter(system_oauth2_refresh_token::Column::RefreshToken.eq(token.refresh_token))
+            .filter(system_oauth2_refresh_token::Column::RefreshToken.eq(refresh_token))
             .exec(db)
             .await?;
         Ok(())
     }
+
+    /// 删除访问令牌，同时清理访问令牌与登录用户的缓存
+    async fn delete_access_token(
+        &self,
+        access_token: system_oauth2_access_token::Model,
+    ) -> ApiResult<()> {
+        let token = &access_token.access_token;
+        redis_util::cache_del(&format!("{OAUTH2_ACCESS_TOKEN}:{token}")).await?;
+        redis_util::cache_del(&format!("{ACCESS_TOKEN}:{token}")).await?;
+        SystemOauth2AccessToken::delete_by_id(access_token.id)
+            .exec(database::get()?)
+            .await?;
+        Ok(())
+    }
+
+    /// 移除访问令牌，用于用户退出登录，令牌不存在时忽略
+    pub async fn remove_access_token(&self, access_token: &str) -> ApiResult<()> {
+        let token = SystemOauth2AccessToken::find()
+            .filter(system_oauth2_access_token::Column::AccessToken.eq(access_token))
+            .one(database::get()?)
+            .await?;
+        if let Some(token) = token {
+            self.remove_session(token).await?;
+        }
+        Ok(())
+    }
+
+    /// 获得在线用户分页，即当前租户未过期的访问令牌
+    pub async fn get_access_token_page(
+        &self,
+        req_vo: OAuth2TokenPageReqVo,
+    ) -> ApiResult<Page<OAuth2TokenRespVo>> {
+        let paginator = self
+            .base_query()
+            .filter(system_oauth2_access_token::Column::ExpiresTime.gt(Local::now().naive_local()))
+            .apply_if(
+                req_vo.client_id.as_ref().filter(|c| !c.is_empty()),
+                |query, client_id| {
+                    query.filter(system_oauth2_access_token::Column::ClientId.eq(client_id))
+                },
+            )
+            .apply_if(req_vo.user_id, |query, user_id| {
+                query.filter(system_oauth2_access_token::Column::UserId.eq(user_id))
+            })
+            .apply_if(req_vo.user_type, |query, user_type| {
+                query.filter(system_oauth2_access_token::Column::UserType.eq(user_type))
+            })
+            .order_by_desc(system_oauth2_access_token::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let tokens = paginator.fetch_page(req_vo.pagination.page - 1).await?;
+        // 拼接管理后台用户的账号
+        let users = AdminUserService::new(self.tenant)
+            .get_user_list(
+                tokens
+                    .iter()
+                    .filter(|token| !token.refresh_token.is_empty())
+                    .filter(|token| UserTypeEnum::is_admin(token.user_type))
+                    .map(|token| token.user_id),
+            )
+            .await?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect::<HashMap<_, _>>();
+        let items = tokens
+            .into_iter()
+            .map(|token| {
+                let user = UserTypeEnum::is_admin(token.user_type)
+                    .then(|| users.get(&token.user_id))
+                    .flatten();
+                let mut resp = OAuth2TokenRespVo::from(token);
+                resp.username = user.map(|user| user.username.clone());
+                resp
+            })
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    /// 强制退出，移除访问令牌所属的会话，令牌必须属于当前租户
+    pub async fn force_logout(&self, id: i64, client_info: &ClientInfo) -> ApiResult<()> {
+        let token = self
+            .base_query()
+            .filter(system_oauth2_access_token::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_ACCESS_TOKEN_NOT_EXISTS))?;
+        let (user_id, user_type) = (token.user_id, token.user_type);
+        // 客户端模式的访问令牌不关联用户，不记录登录日志
+        let has_user = !token.refresh_token.is_empty();
+        self.remove_session(token).await?;
+        if has_user {
+            self.create_login_log(
+                user_id,
+                user_type,
+                LoginLogTypeEnum::LogoutDelete,
+                LoginResultEnum::Success,
+                client_info,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// 获得用户自己的登录会话列表，即未过期的刷新令牌，并标记当前会话
+    pub async fn get_user_session_list(
+        &self,
+        principal: &Principal,
+    ) -> ApiResult<Vec<UserSessionRespVo>> {
+        let current_refresh_token = self
+            .get_access_token(&principal.token)
+            .await?
+            .map(|access_token| access_token.refresh_token);
+        let sessions = self
+            .user_session_query(principal.user_id, principal.user_type.value())
+            .order_by_desc(system_oauth2_refresh_token::Column::Id)
+            .all(database::get()?)
+            .await?;
+        Ok(sessions
+            .into_iter()
+            .map(|session| {
+                let current =
+                    current_refresh_token.as_deref() == Some(session.refresh_token.as_str());
+                UserSessionRespVo {
+                    current,
+                    ..UserSessionRespVo::from(session)
+                }
+            })
+            .collect())
+    }
+
+    /// 用户撤销自己的登录会话，该会话的全部访问令牌立即失效
+    pub async fn remove_user_session(&self, principal: &Principal, id: i64) -> ApiResult<()> {
+        let session = self
+            .user_session_query(principal.user_id, principal.user_type.value())
+            .filter(system_oauth2_refresh_token::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(OAUTH2_SESSION_NOT_EXISTS))?;
+        self.remove_refresh_token(&session.refresh_token).await
+    }
+
+    /// 用户退出全部登录会话，包括当前会话
+    pub async fn remove_user_sessions(&self, principal: &Principal) -> ApiResult<()> {
+        let sessions = self
+            .user_session_query(principal.user_id, principal.user_type.value())
+            .all(database::get()?)
+            .await?;
+        for session in sessions {
+            self.remove_refresh_token(&session.refresh_token).await?;
+        }
+        Ok(())
+    }
+
+    /// 用户的登录会话，即当前租户下该用户未过期的刷新令牌
+    fn user_session_query(&self, user_id: i64, user_type: i32) -> Select<SystemOauth2RefreshToken> {
+        SystemOauth2RefreshToken::find()
+            .filter(system_oauth2_refresh_token::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_oauth2_refresh_token::Column::Deleted.eq(0))
+            .filter(system_oauth2_refresh_token::Column::UserId.eq(user_id))
+            .filter(system_oauth2_refresh_token::Column::UserType.eq(user_type))
+            .filter(system_oauth2_refresh_token::Column::ExpiresTime.gt(Local::now().naive_local()))
+    }
+
+    /// 移除访问令牌所属的会话，有刷新令牌时一并移除刷新令牌，以及刷新令牌派生的全部访问令牌
+    async fn remove_session(
+        &self,
+        access_token: system_oauth2_access_token::Model,
+    ) -> ApiResult<()> {
+        // 客户端模式的访问令牌没有刷新令牌
+        if access_token.refresh_token.is_empty() {
+            self.delete_access_token(access_token).await
+        } else {
+            self.remove_refresh_token(&access_token.refresh_token).await
+        }
+    }
+
     pub async fn create_access_token(
         &self,
         user_id: i64,
         user_type: i32,
         client_id: &str,
         scopes: Vec<String>,
+        client_info: &ClientInfo,
     ) -> ApiResult<system_oauth2_access_token::Model> {
         let client = OAuth2ClientService::new(self.tenant.clone())
             .valid_oauth_client_from_cache(client_id)
             .await?;
+        // 校验同时在线的会话数，达到上限时拒绝登录或踢出最早的会话
+        self.check_session_limit(user_id, user_type, &client.client_id)
+            .await?;
         let refresh_token = self
-            .create_oauth2refresh_token(user_id, user_type, &client, scopes)
+            .create_oauth2refresh_token(user_id, user_type, &client, scopes, client_info)
             .await?;
         self.create_oauth2access_token(&refresh_token, &client)
             .await
     }
 
+    /// 创建客户端模式的访问令牌，令牌代表客户端自身，不关联用户，也不创建刷新令牌
+    pub async fn create_client_access_token(
+        &self,
+        client_id: &str,
+        scopes: Vec<String>,
+        client_info: &ClientInfo,
+    ) -> ApiResult<system_oauth2_access_token::Model> {
+        let client = OAuth2ClientService::new(self.tenant)
+            .valid_oauth_client_from_cache(client_id)
+            .await?;
+        let active_model = system_oauth2_access_token::ActiveModel {
+            access_token: Set(utils::id::x()),
+            user_id: Set(CLIENT_USER_ID),
+            user_type: Set(UserTypeEnum::Admin.value()),
+            user_info: Set(String::from("{}")),
+            client_id: Set(client.client_id.to_owned()),
+            scopes: Set(Some(serde_json::to_string(&scopes)?)),
+            refresh_token: Set(String::new()),
+            expires_time: Set(Local::now()
+                .add(Duration::from_secs(
+                    client.access_token_validity_seconds as u64,
+                ))
+                .naive_local()),
+            user_ip: Set(Some(client_info.ip.clone())),
+            user_agent: Set(Some(client_info.user_agent.clone())),
+            ..Default::default()
+        };
+        let model = create_with_common_fields(active_model, Some(client.client_id), &self.tenant)
+            .await?
+            .insert(database::get()?)
+            .await?;
+        if client.access_token_validity_seconds > 0 {
+            redis_util::cache_set_json_ex(
+                &format!("{OAUTH2_ACCESS_TOKEN}:{}", model.access_token),
+                &model,
+                client.access_token_validity_seconds as u64,
+            )
+            .await?;
+        }
+        Ok(model)
+    }
+
+    async fn check_session_limit(
+        &self,
+        user_id: i64,
+        user_type: i32,
+        client_id: &str,
+    ) -> ApiResult<()> {
+        let config = config::get().await;
+        let max_sessions = config.auth().max_sessions();
+        if max_sessions == 0 {
+            return Ok(());
+        }
+        let sessions = self
+            .user_session_query(user_id, user_type)
+            .filter(system_oauth2_refresh_token::Column::ClientId.eq(client_id))
+            .order_by_asc(system_oauth2_refresh_token::Column::Id)
+            .all(database::get()?)
+            .await?;
+        if (sessions.len() as u64) < max_sessions {
+            return Ok(());
+        }
+        match config.auth().session_limit_policy() {
+            SessionLimitPolicy::Reject => Err(ApiError::BizCodeWithArgs(
+                AUTH_LOGIN_SESSION_LIMIT,
+                vec![max_sessions.to_string()],
+            )),
+            SessionLimitPolicy::EvictOldest => {
+                // 为新的会话腾出一个位置
+                let evict_count = sessions.len() - max_sessions as usize + 1;
+                for session in sessions.into_iter().take(evict_count) {
+                    self.remove_refresh_token(&session.refresh_token).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     async fn create_oauth2access_token(
         &self,
         refresh_token: &system_oauth2_refresh_token::Model,
@@ -128,6 +643,9 @@ impl OAuth2TokenService {
                     client.access_token_validity_seconds as u64,
                 ))
                 .naive_local()),
+            // 刷新时沿用登录时记录的客户端信息
+            user_ip: Set(refresh_token.user_ip.to_owned()),
+            user_agent: Set(refresh_token.user_agent.to_owned()),
             ..Default::default()
         };
         let model = create_with_common_fields(
@@ -155,6 +673,7 @@ impl OAuth2TokenService {
         user_type: i32,
         client: &system_oauth2_client::Model,
         scopes: Vec<String>,
+        client_info: &ClientInfo,
     ) -> ApiResult<system_oauth2_refresh_token::Model> {
         let active_model = system_oauth2_refresh_token::ActiveModel {
             refresh_token: Set(utils::id::x()),
@@ -167,6 +686,9 @@ impl OAuth2TokenService {
                     client.refresh_token_validity_seconds as u64,
                 ))
                 .naive_local()),
+            user_ip: Set(Some(client_info.ip.clone())),
+            user_agent: Set(Some(client_info.user_agent.clone())),
+            family_id: Set(Some(utils::id::x())),
             ..Default::default()
         };
         let model =
@@ -199,3 +721,69 @@ impl OAuth2TokenService {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refresh_token(token: &str, family_id: Option<&str>) -> system_oauth2_refresh_token::Model {
+        system_oauth2_refresh_token::Model {
+            id: 0,
+            user_id: 1,
+            refresh_token: token.to_string(),
+            user_type: UserTypeEnum::Admin.value(),
+            client_id: String::from("default"),
+            scopes: None,
+            expires_time: Default::default(),
+            user_ip: None,
+            user_agent: None,
+            family_id: family_id.map(str::to_string),
+            creator: None,
+            create_time: Default::default(),
+            updater: None,
+            update_time: Default::default(),
+            deleted: 0,
+            tenant_id: 1,
+        }
+    }
+
+    /// 与撤销令牌家族时的查询条件一致，返回需要撤销的刷新令牌
+    fn revoked_tokens(
+        reused: &system_oauth2_refresh_token::Model,
+        tokens: &[system_oauth2_refresh_token::Model],
+    ) -> Vec<String> {
+        let family_id = OAuth2TokenService::family_id(reused);
+        tokens
+            .iter()
+            .filter(|token| OAuth2TokenService::in_family(token, family_id))
+            .map(|token| token.refresh_token.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_revoke_rotated_token_family() {
+        // a 为首个刷新令牌，依次轮换为 b、c，x 属于其它家族
+        let tokens = [
+            refresh_token("a", Some("a")),
+            refresh_token("b", Some("a")),
+            refresh_token("c", Some("a")),
+            refresh_token("x", Some("x")),
+        ];
+        // 重用已轮换的 b，撤销整个家族
+        assert_eq!(revoked_tokens(&tokens[1], &tokens), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_revoke_legacy_token_family() {
+        // legacy 为轮换前未记录家族的刷新令牌，轮换出的 b 以 legacy 作为家族
+        let tokens = [
+            refresh_token("legacy", None),
+            refresh_token("b", Some("legacy")),
+            refresh_token("x", None),
+        ];
+        assert_eq!(OAuth2TokenService::family_id(&tokens[0]), "legacy");
+        // 重用 legacy 或 b，都撤销 legacy 与 b
+        assert_eq!(revoked_tokens(&tokens[0], &tokens), ["legacy", "b"]);
+        assert_eq!(revoked_tokens(&tokens[1], &tokens), ["legacy", "b"]);
+    }
+}
@@ -1,7 +1,112 @@
-use daoyi_common::app::TenantContextHolder;
+use crate::service::admin_user::AdminUserService;
+use crate::vo::logger::operate_log_page_req_vo::OperateLogPageReqVo;
+use crate::vo::logger::operate_log_resp_vo::OperateLogRespVo;
+use crate::vo::logger::operate_log_timeline_req_vo::OperateLogTimelineReqVo;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::{TenantContextHolder, database};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::utils::enumeration::UserTypeEnum;
+use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_entities_system::entity::prelude::SystemOperateLog;
+use daoyi_entities_system::entity::system_operate_log;
+use sea_orm::{prelude::*, *};
+use std::collections::HashMap;
 
 pub struct OperateLogService {
     tenant: TenantContextHolder,
 }
 impl_tenant_instance!(OperateLogService);
+impl OperateLogService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemOperateLog> {
+        SystemOperateLog::find()
+            .filter(system_operate_log::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_operate_log::Column::Deleted.eq(0))
+    }
+}
+
+impl OperateLogService {
+    pub async fn get_operate_log_page(
+        &self,
+        req_vo: OperateLogPageReqVo,
+    ) -> ApiResult<Page<OperateLogRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(req_vo.user_id, |query, user_id| {
+                query.filter(system_operate_log::Column::UserId.eq(user_id))
+            })
+            .apply_if(
+                req_vo.r#type.as_ref().filter(|t| !t.is_empty()),
+                |query, r#type| query.filter(system_operate_log::Column::Type.eq(r#type)),
+            )
+            .apply_if(
+                req_vo.sub_type.as_ref().filter(|s| !s.is_empty()),
+                |query, sub_type| {
+                    query.filter(system_operate_log::Column::SubType.contains(sub_type))
+                },
+            )
+            .apply_if(req_vo.biz_id, |query, biz_id| {
+                query.filter(system_operate_log::Column::BizId.eq(biz_id))
+            })
+            .apply_if(req_vo.success, |query, success| {
+                query.filter(system_operate_log::Column::Success.eq(success))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_operate_log::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_operate_log::Column::CreateTime.lte(end))
+            })
+            .order_by_desc(system_operate_log::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let logs = paginator.fetch_page(req_vo.pagination.page - 1).await?;
+        let items = self.build_operate_log_resp_list(logs).await?;
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    /// 获得业务数据的变更历史，按操作时间正序排列，变更的字段记录在拓展字段的 `changes` 中
+    pub async fn get_operate_log_timeline(
+        &self,
+        req_vo: OperateLogTimelineReqVo,
+    ) -> ApiResult<Vec<OperateLogRespVo>> {
+        let logs = self
+            .base_query()
+            .filter(system_operate_log::Column::Type.eq(req_vo.r#type))
+            .filter(system_operate_log::Column::BizId.eq(req_vo.biz_id))
+            .order_by_asc(system_operate_log::Column::Id)
+            .all(database::get()?)
+            .await?;
+        self.build_operate_log_resp_list(logs).await
+    }
+
+    /// 构建操作日志的响应列表，补充管理员用户的昵称
+    pub async fn build_operate_log_resp_list(
+        &self,
+        logs: Vec<system_operate_log::Model>,
+    ) -> ApiResult<Vec<OperateLogRespVo>> {
+        let user_ids = logs
+            .iter()
+            .filter(|log| UserTypeEnum::is_admin(log.user_type))
+            .map(|log| log.user_id)
+            .collect::<Vec<_>>();
+        let user_names = AdminUserService::new(self.tenant)
+            .get_user_list(user_ids)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, user.nickname))
+            .collect::<HashMap<_, _>>();
+        Ok(logs
+            .into_iter()
+            .map(|log| {
+                let user_name = UserTypeEnum::is_admin(log.user_type)
+                    .then(|| user_names.get(&log.user_id).cloned())
+                    .flatten();
+                OperateLogRespVo {
+                    user_name,
+                    ..OperateLogRespVo::from(log)
+                }
+            })
+            .collect())
+    }
+}
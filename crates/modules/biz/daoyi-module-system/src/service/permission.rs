@@ -1,8 +1,12 @@
 use crate::service::menu::MenuService;
-use daoyi_common::app::database;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::{database, redis_util};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common::service::TenantApi;
+use daoyi_common_support::support::orm::create_with_common_fields;
 use daoyi_common_support::support::tenant::TenantContextHolder;
 use daoyi_common_support::utils::enumeration::RoleCode;
+use daoyi_common_support::utils::enumeration::redis_key_constants::USER_HAS_PERMISSION;
 use daoyi_common_support::utils::errors::error::ApiResult;
 use daoyi_entities_system::entity::prelude::{SystemRoleMenu, SystemUserRole};
 use daoyi_entities_system::entity::{system_role_menu, system_user_role};
@@ -34,20 +38,241 @@ impl PermissionService {
             return Ok(HashSet::new());
         }
         // 如果是管理员的情况下，获取全部菜单编号
-        if RoleCode::has_super_admin(role_codes) {
-            return Ok(MenuService::new(self.tenant.clone())
+        let menu_ids = if RoleCode::has_super_admin(role_codes) {
+            MenuService::new(self.tenant)
                 .get_all_menu_list()
                 .await?
                 .into_iter()
                 .map(|item| item.id)
-                .collect::<HashSet<_>>());
+                .collect::<HashSet<_>>()
+        } else {
+            SystemRoleMenu::find()
+                .filter(system_role_menu::Column::RoleId.is_in(peekable_role_ids))
+                .all(database::get()?)
+                .await?
+                .into_iter()
+                .map(|item| item.menu_id)
+                .collect::<HashSet<_>>()
+        };
+        // 租户用户只能使用租户套餐内的菜单
+        match TenantApi.get_tenant_menu_ids(&self.tenant).await? {
+            Some(package_menu_ids) => Ok(menu_ids
+                .intersection(&package_menu_ids)
+                .copied()
+                .collect::<HashSet<_>>()),
+            None => Ok(menu_ids),
+        }
+    }
+
+    pub async fn assign_user_role(
+        &self,
+        user_id: i64,
+        role_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let txn = database::get()?.begin().await?;
+        self.save_user_role(&txn, user_id, role_ids, operator)
+            .await?;
+        txn.commit().await?;
+        self.clear_user_permission_cache([user_id]).await
+    }
+
+    /// 覆盖式设置用户的角色，由调用方管理事务与缓存
+    pub async fn save_user_role<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        user_id: i64,
+        role_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 获得用户拥有的角色编号
+        let db_role_ids = SystemUserRole::find()
+            .filter(system_user_role::Column::UserId.eq(user_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|item| item.role_id)
+            .collect::<HashSet<_>>();
+        // 计算新增和删除的角色编号
+        let create_role_ids = role_ids
+            .difference(&db_role_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        let delete_role_ids = db_role_ids
+            .difference(&role_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        // 执行新增和删除。对于已经授权的角色，不用做任何处理
+        if !create_role_ids.is_empty() {
+            let mut models = Vec::with_capacity(create_role_ids.len());
+            for role_id in create_role_ids {
+                models.push(
+                    create_with_common_fields(
+                        system_user_role::ActiveModel {
+                            user_id: Set(user_id),
+                            role_id: Set(role_id),
+                            ..Default::default()
+                        },
+                        Some(operator.user_id.to_string()),
+                        &self.tenant,
+                    )
+                    .await?,
+                );
+            }
+            SystemUserRole::insert_many(models).exec(db).await?;
         }
-        Ok(SystemRoleMenu::find()
-            .filter(system_role_menu::Column::RoleId.is_in(peekable_role_ids))
+        if !delete_role_ids.is_empty() {
+            SystemUserRole::delete_many()
+                .filter(system_user_role::Column::UserId.eq(user_id))
+                .filter(system_user_role::Column::RoleId.is_in(delete_role_ids))
+                .exec(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_user_id_list_by_role_id(
+        &self,
+        role_ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<HashSet<i64>> {
+        Ok(SystemUserRole::find()
+            .filter(system_user_role::Column::RoleId.is_in(role_ids))
             .all(database::get()?)
             .await?
             .into_iter()
-            .map(|item| item.menu_id)
+            .map(|item| item.user_id)
             .collect::<HashSet<_>>())
     }
+
+    pub async fn assign_role_menu(
+        &self,
+        role_id: i64,
+        menu_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let txn = database::get()?.begin().await?;
+        self.save_role_menu(&txn, role_id, menu_ids, operator)
+            .await?;
+        txn.commit().await?;
+        self.clear_role_permission_cache(role_id).await
+    }
+
+    /// 覆盖式设置角色的菜单，由调用方管理事务与缓存
+    pub async fn save_role_menu<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        role_id: i64,
+        menu_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 获得角色拥有菜单编号
+        let db_menu_ids = SystemRoleMenu::find()
+            .filter(system_role_menu::Column::RoleId.eq(role_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|item| item.menu_id)
+            .collect::<HashSet<_>>();
+        // 计算新增和删除的菜单编号
+        let create_menu_ids = menu_ids
+            .difference(&db_menu_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        let delete_menu_ids = db_menu_ids
+            .difference(&menu_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        // 执行新增和删除。对于已经授权的菜单，不用做任何处理
+        if !create_menu_ids.is_empty() {
+            let mut models = Vec::with_capacity(create_menu_ids.len());
+            for menu_id in create_menu_ids {
+                models.push(
+                    create_with_common_fields(
+                        system_role_menu::ActiveModel {
+                            role_id: Set(role_id),
+                            menu_id: Set(menu_id),
+                            ..Default::default()
+                        },
+                        Some(operator.user_id.to_string()),
+                        &self.tenant,
+                    )
+                    .await?,
+                );
+            }
+            SystemRoleMenu::insert_many(models).exec(db).await?;
+        }
+        if !delete_menu_ids.is_empty() {
+            SystemRoleMenu::delete_many()
+                .filter(system_role_menu::Column::RoleId.eq(role_id))
+                .filter(system_role_menu::Column::MenuId.is_in(delete_menu_ids))
+                .exec(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn process_role_deleted<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        role_id: i64,
+    ) -> ApiResult<()> {
+        // 删除 UserRole
+        SystemUserRole::delete_many()
+            .filter(system_user_role::Column::RoleId.eq(role_id))
+            .exec(db)
+            .await?;
+        // 删除 RoleMenu
+        SystemRoleMenu::delete_many()
+            .filter(system_role_menu::Column::RoleId.eq(role_id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn process_menu_deleted<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        menu_id: i64,
+    ) -> ApiResult<()> {
+        SystemRoleMenu::delete_many()
+            .filter(system_role_menu::Column::MenuId.eq(menu_id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn process_user_deleted<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        user_id: i64,
+    ) -> ApiResult<()> {
+        SystemUserRole::delete_many()
+            .filter(system_user_role::Column::UserId.eq(user_id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// 清理拥有该角色的用户的接口权限缓存
+    pub async fn clear_role_permission_cache(&self, role_id: i64) -> ApiResult<()> {
+        let user_ids = self.get_user_id_list_by_role_id([role_id]).await?;
+        self.clear_user_permission_cache(user_ids).await
+    }
+
+    /// 清理所有用户的接口权限缓存，菜单授权接口变化时使用
+    pub async fn clear_all_user_permission_cache(&self) -> ApiResult<()> {
+        redis_util::cache_del_pattern(&format!("{USER_HAS_PERMISSION}:*")).await?;
+        Ok(())
+    }
+
+    /// 清理权限校验中间件写入的用户接口权限缓存
+    pub async fn clear_user_permission_cache(
+        &self,
+        user_ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<()> {
+        for user_id in user_ids {
+            redis_util::cache_del_pattern(&format!("{USER_HAS_PERMISSION}:{user_id}:*")).await?;
+        }
+        Ok(())
+    }
 }
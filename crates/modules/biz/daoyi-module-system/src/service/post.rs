@@ -1,12 +1,23 @@
+use crate::vo::post::post_page_req_vo::PostPageReqVo;
+use crate::vo::post::post_resp_vo::PostRespVo;
+use crate::vo::post::post_save_req_vo::PostSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
 use daoyi_common::app::database;
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
 use daoyi_common_support::support::tenant::TenantContextHolder;
 use daoyi_common_support::utils::enumeration::CommonStatusEnum;
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
-use daoyi_common_support::utils::errors::{POST_NOT_ENABLE, POST_NOT_FOUND};
-use daoyi_entities_system::entity::prelude::SystemPost;
-use daoyi_entities_system::entity::system_post;
+use daoyi_common_support::utils::errors::{
+    POST_CODE_DUPLICATE, POST_NAME_DUPLICATE, POST_NOT_ENABLE, POST_NOT_FOUND,
+};
+use daoyi_entities_system::entity::prelude::{SystemPost, SystemUserPost};
+use daoyi_entities_system::entity::{system_post, system_user_post};
 use sea_orm::*;
+use std::collections::{HashMap, HashSet};
 
 pub struct PostService {
     tenant: TenantContextHolder,
@@ -22,6 +33,128 @@ impl PostService {
 }
 
 impl PostService {
+    pub async fn create_post(&self, req_vo: PostSaveReqVo, operator: &Principal) -> ApiResult<i64> {
+        // 校验正确性
+        self.validate_post_for_create_or_update(None, &req_vo.name, &req_vo.code)
+            .await?;
+        // 插入岗位
+        let active_model = create_with_common_fields(
+            system_post::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_post(&self, req_vo: PostSaveReqVo, operator: &Principal) -> ApiResult<()> {
+        let id = req_vo.id.ok_or_else(|| ApiError::BizCode(POST_NOT_FOUND))?;
+        // 校验正确性
+        self.validate_post_for_create_or_update(Some(id), &req_vo.name, &req_vo.code)
+            .await?;
+        // 更新岗位
+        let mut active_model = system_post::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn delete_post(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验是否存在
+        let post = self.get_post(id).await?;
+        // 删除岗位，并解除用户与该岗位的关联
+        let txn = database::get()?.begin().await?;
+        let active_model = soft_delete_with_common_fields(
+            post.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(&txn).await?;
+        SystemUserPost::delete_many()
+            .filter(system_user_post::Column::PostId.eq(id))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_post(&self, id: i64) -> ApiResult<system_post::Model> {
+        self.base_query()
+            .filter(system_post::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(POST_NOT_FOUND))
+    }
+
+    pub async fn get_post_page(&self, req_vo: PostPageReqVo) -> ApiResult<Page<PostRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.code.as_ref().filter(|c| !c.is_empty()),
+                |query, code| query.filter(system_post::Column::Code.contains(code)),
+            )
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_post::Column::Name.contains(name)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_post::Column::Status.eq(status))
+            })
+            .order_by_asc(system_post::Column::Sort)
+            .order_by_asc(system_post::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(PostRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_post_list_by_status(&self, status: i32) -> ApiResult<Vec<system_post::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_post::Column::Status.eq(status))
+            .order_by_asc(system_post::Column::Sort)
+            .all(database::get()?)
+            .await?)
+    }
+
+    async fn validate_post_for_create_or_update(
+        &self,
+        id: Option<i64>,
+        name: &str,
+        code: &str,
+    ) -> ApiResult<()> {
+        // 校验自己存在
+        if let Some(id) = id {
+            self.get_post(id).await?;
+        }
+        // 校验岗位名的唯一性
+        let post = self
+            .base_query()
+            .filter(system_post::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if post.is_some_and(|post| Some(post.id) != id) {
+            return Err(ApiError::BizCode(POST_NAME_DUPLICATE));
+        }
+        // 校验岗位编码的唯一性
+        let post = self
+            .base_query()
+            .filter(system_post::Column::Code.eq(code))
+            .one(database::get()?)
+            .await?;
+        if post.is_some_and(|post| Some(post.id) != id) {
+            return Err(ApiError::BizCode(POST_CODE_DUPLICATE));
+        }
+        Ok(())
+    }
+
     pub async fn validate_post_list(&self, post_ids: Vec<i64>) -> ApiResult<()> {
         if post_ids.is_empty() {
             return Ok(());
@@ -43,3 +176,90 @@ impl PostService {
         Ok(())
     }
 }
+
+impl PostService {
+    /// 获得用户拥有的岗位编号，以用户编号分组
+    pub async fn get_user_post_ids_map(
+        &self,
+        user_ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<HashMap<i64, Vec<i64>>> {
+        let mut result: HashMap<i64, Vec<i64>> = HashMap::new();
+        for user_post in SystemUserPost::find()
+            .filter(system_user_post::Column::UserId.is_in(user_ids))
+            .order_by_asc(system_user_post::Column::PostId)
+            .all(database::get()?)
+            .await?
+        {
+            result
+                .entry(user_post.user_id)
+                .or_default()
+                .push(user_post.post_id);
+        }
+        Ok(result)
+    }
+
+    /// 覆盖式设置用户的岗位，`system_user_post` 为用户岗位的唯一数据来源
+    pub async fn assign_user_posts<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        user_id: i64,
+        post_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 获得用户拥有的岗位编号
+        let db_post_ids = SystemUserPost::find()
+            .filter(system_user_post::Column::UserId.eq(user_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|item| item.post_id)
+            .collect::<HashSet<_>>();
+        // 计算新增和删除的岗位编号
+        let create_post_ids = post_ids
+            .difference(&db_post_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        let delete_post_ids = db_post_ids
+            .difference(&post_ids)
+            .copied()
+            .collect::<Vec<_>>();
+        if !create_post_ids.is_empty() {
+            let mut models = Vec::with_capacity(create_post_ids.len());
+            for post_id in create_post_ids {
+                models.push(
+                    create_with_common_fields(
+                        system_user_post::ActiveModel {
+                            user_id: Set(user_id),
+                            post_id: Set(post_id),
+                            ..Default::default()
+                        },
+                        Some(operator.user_id.to_string()),
+                        &self.tenant,
+                    )
+                    .await?,
+                );
+            }
+            SystemUserPost::insert_many(models).exec(db).await?;
+        }
+        if !delete_post_ids.is_empty() {
+            SystemUserPost::delete_many()
+                .filter(system_user_post::Column::UserId.eq(user_id))
+                .filter(system_user_post::Column::PostId.is_in(delete_post_ids))
+                .exec(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn process_user_deleted<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        user_id: i64,
+    ) -> ApiResult<()> {
+        SystemUserPost::delete_many()
+            .filter(system_user_post::Column::UserId.eq(user_id))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+}
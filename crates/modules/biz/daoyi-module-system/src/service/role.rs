@@ -1,16 +1,206 @@
+use crate::service::dept::DeptService;
+use crate::service::permission::PermissionService;
+use crate::vo::role::role_page_req_vo::RolePageReqVo;
+use crate::vo::role::role_resp_vo::RoleRespVo;
+use crate::vo::role::role_save_req_vo::RoleSaveReqVo;
+use crate::vo::role::role_update_status_req_vo::RoleUpdateStatusReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
 use daoyi_common::app::database;
 use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_change_log,
+};
 use daoyi_common_support::support::tenant::TenantContextHolder;
-use daoyi_common_support::utils::errors::error::ApiResult;
+use daoyi_common_support::utils::enumeration::{
+    CommonStatusEnum, DataScopeEnum, EMPTY_VEC_STR, RoleCode, RoleTypeEnum,
+};
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    ROLE_ADMIN_CODE_ERROR, ROLE_CAN_NOT_UPDATE_SYSTEM_TYPE_ROLE, ROLE_CODE_DUPLICATE,
+    ROLE_IS_DISABLE, ROLE_NAME_DUPLICATE, ROLE_NOT_EXISTS,
+};
 use daoyi_entities_system::entity::prelude::SystemRole;
 use daoyi_entities_system::entity::system_role;
 use sea_orm::*;
+use std::collections::HashSet;
 
 pub struct RoleService {
     tenant: TenantContextHolder,
 }
 impl_tenant_instance!(RoleService);
 impl RoleService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemRole> {
+        SystemRole::find()
+            .filter(system_role::Column::TenantId.eq(self.tenant_id()))
+            .filter(system_role::Column::Deleted.eq(0))
+    }
+}
+impl RoleService {
+    pub async fn create_role(&self, req_vo: RoleSaveReqVo, operator: &Principal) -> ApiResult<i64> {
+        // 1. 校验角色
+        self.validate_role_duplicate(None, &req_vo.name, &req_vo.code)
+            .await?;
+        // 2. 插入到数据库，自定义角色默认拥有全部数据权限
+        let mut active_model = system_role::ActiveModel::from(req_vo);
+        active_model.r#type = Set(RoleTypeEnum::Custom.value());
+        active_model.data_scope = Set(DataScopeEnum::All.scope());
+        active_model.data_scope_dept_ids = Set(EMPTY_VEC_STR.to_string());
+        let active_model = create_with_common_fields(
+            active_model,
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_role(&self, req_vo: RoleSaveReqVo, operator: &Principal) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(ROLE_NOT_EXISTS))?;
+        // 1.1 校验是否可以更新
+        let role = self.validate_role_for_update(id).await?;
+        // 1.2 校验角色的唯一字段是否重复
+        self.validate_role_duplicate(Some(id), &req_vo.name, &req_vo.code)
+            .await?;
+        // 2. 更新到数据库
+        let status = req_vo.status;
+        let mut active_model = system_role::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_change_log(&role, active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        // 3. 停用或启用角色会影响用户的接口权限
+        if role.status != status {
+            PermissionService::new(self.tenant)
+                .clear_role_permission_cache(id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn update_role_status(
+        &self,
+        req_vo: RoleUpdateStatusReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 1. 校验是否可以更新
+        let role = self.validate_role_for_update(req_vo.id).await?;
+        // 2. 更新状态
+        let mut active_model = role.clone().into_active_model();
+        active_model.status = Set(req_vo.status);
+        let active_model =
+            update_with_change_log(&role, active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        // 3. 清理拥有该角色的用户的权限缓存
+        PermissionService::new(self.tenant)
+            .clear_role_permission_cache(req_vo.id)
+            .await
+    }
+
+    pub async fn update_role_data_scope(
+        &self,
+        id: i64,
+        data_scope: i32,
+        data_scope_dept_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        // 1.1 校验是否可以更新
+        let role = self.validate_role_for_update(id).await?;
+        // 1.2 只有指定部门的数据范围，才需要保存部门编号
+        let mut dept_ids = if data_scope == DataScopeEnum::DeptCustom.scope() {
+            data_scope_dept_ids.into_iter().collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+        dept_ids.sort_unstable();
+        DeptService::new(self.tenant)
+            .validate_dept_list(dept_ids.clone())
+            .await?;
+        // 2. 更新数据范围
+        let mut active_model = role.clone().into_active_model();
+        active_model.data_scope = Set(data_scope);
+        active_model.data_scope_dept_ids = Set(serde_json::to_string(&dept_ids)?);
+        let active_model =
+            update_with_change_log(&role, active_model, Some(operator.user_id.to_string())).await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn delete_role(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 1. 校验是否可以删除
+        let role = self.validate_role_for_update(id).await?;
+        // 2.1 标记删除
+        let permission_service = PermissionService::new(self.tenant);
+        let user_ids = permission_service.get_user_id_list_by_role_id([id]).await?;
+        let txn = database::get()?.begin().await?;
+        let active_model = soft_delete_with_common_fields(
+            role.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(&txn).await?;
+        // 2.2 删除相关数据
+        permission_service.process_role_deleted(&txn, id).await?;
+        txn.commit().await?;
+        // 3. 清理拥有该角色的用户的权限缓存
+        permission_service
+            .clear_user_permission_cache(user_ids)
+            .await
+    }
+
+    pub async fn get_role(&self, id: i64) -> ApiResult<system_role::Model> {
+        self.base_query()
+            .filter(system_role::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(ROLE_NOT_EXISTS))
+    }
+
+    pub async fn get_role_page(&self, req_vo: RolePageReqVo) -> ApiResult<Page<RoleRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_role::Column::Name.contains(name)),
+            )
+            .apply_if(
+                req_vo.code.as_ref().filter(|c| !c.is_empty()),
+                |query, code| query.filter(system_role::Column::Code.contains(code)),
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_role::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_role::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_role::Column::CreateTime.lte(end))
+            })
+            .order_by_asc(system_role::Column::Sort)
+            .order_by_asc(system_role::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(RoleRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_role_list_by_status(&self, status: i32) -> ApiResult<Vec<system_role::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_role::Column::Status.eq(status))
+            .order_by_asc(system_role::Column::Sort)
+            .all(database::get()?)
+            .await?)
+    }
+
     pub async fn get_role_list(
         &self,
         role_ids: impl IntoIterator<Item = i64>,
@@ -20,4 +210,85 @@ impl RoleService {
             .all(database::get()?)
             .await?)
     }
+
+    pub async fn validate_role_list(
+        &self,
+        role_ids: impl IntoIterator<Item = i64>,
+    ) -> ApiResult<()> {
+        let role_ids = role_ids.into_iter().collect::<Vec<_>>();
+        if role_ids.is_empty() {
+            return Ok(());
+        }
+        let len = role_ids.len();
+        let role_list = self
+            .base_query()
+            .filter(system_role::Column::Id.is_in(role_ids))
+            .all(database::get()?)
+            .await?;
+        if role_list.len() != len {
+            return Err(ApiError::BizCode(ROLE_NOT_EXISTS));
+        }
+        for role in role_list {
+            if CommonStatusEnum::is_disable(role.status) {
+                return Err(ApiError::BizCodeWithArgs(ROLE_IS_DISABLE, vec![role.name]));
+            }
+        }
+        Ok(())
+    }
+
+    /// 校验角色是否可以被更新，内置角色不允许操作
+    pub async fn validate_role_for_update(&self, id: i64) -> ApiResult<system_role::Model> {
+        let role = self.get_role(id).await?;
+        if RoleTypeEnum::is_system(role.r#type) {
+            return Err(ApiError::BizCode(ROLE_CAN_NOT_UPDATE_SYSTEM_TYPE_ROLE));
+        }
+        Ok(role)
+    }
+
+    /// 校验角色的唯一字段是否重复
+    ///
+    /// 1. 是否存在相同名字的角色
+    /// 2. 是否存在相同编码的角色
+    pub async fn validate_role_duplicate(
+        &self,
+        id: Option<i64>,
+        name: &str,
+        code: &str,
+    ) -> ApiResult<()> {
+        // 0. 超级管理员，不允许创建
+        if RoleCode::is_super_admin(code) {
+            return Err(ApiError::BizCodeWithArgs(
+                ROLE_ADMIN_CODE_ERROR,
+                vec![code.to_string()],
+            ));
+        }
+        // 1. 该 name 名字被其它角色所使用
+        let role = self
+            .base_query()
+            .filter(system_role::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if role.is_some_and(|role| Some(role.id) != id) {
+            return Err(ApiError::BizCodeWithArgs(
+                ROLE_NAME_DUPLICATE,
+                vec![name.to_string()],
+            ));
+        }
+        // 2. 是否存在相同编码的角色
+        if code.is_empty() {
+            return Ok(());
+        }
+        let role = self
+            .base_query()
+            .filter(system_role::Column::Code.eq(code))
+            .one(database::get()?)
+            .await?;
+        if role.is_some_and(|role| Some(role.id) != id) {
+            return Err(ApiError::BizCodeWithArgs(
+                ROLE_CODE_DUPLICATE,
+                vec![code.to_string()],
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,11 +1,31 @@
-use daoyi_common::app::database;
+use crate::service::permission::PermissionService;
+use crate::service::tenant_package::TenantPackageService;
+use crate::vo::tenant::tenant_page_req_vo::TenantPageReqVo;
+use crate::vo::tenant::tenant_resp_vo::TenantRespVo;
+use crate::vo::tenant::tenant_save_req_vo::TenantSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::{database, redis_util};
 use daoyi_common::impl_tenant_instance;
+use daoyi_common::service::TenantApi;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_change_log,
+};
 use daoyi_common_support::support::tenant::TenantContextHolder;
-use daoyi_common_support::utils::errors::TENANT_NOT_EXISTS;
+use daoyi_common_support::utils::encode_password;
+use daoyi_common_support::utils::enumeration::redis_key_constants::VALID_TENANT;
+use daoyi_common_support::utils::enumeration::{
+    CommonStatusEnum, DataScopeEnum, EMPTY_VEC_STR, RoleCode, RoleTypeEnum,
+};
 use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
-use daoyi_entities_system::entity::prelude::SystemTenant;
-use daoyi_entities_system::entity::system_tenant;
+use daoyi_common_support::utils::errors::{
+    TENANT_CAN_NOT_UPDATE_SYSTEM, TENANT_NAME_DUPLICATE, TENANT_NOT_EXISTS, TENANT_NOT_SYSTEM,
+    TENANT_WEBSITE_DUPLICATE,
+};
+use daoyi_entities_system::entity::prelude::{SystemRole, SystemRoleMenu, SystemTenant};
+use daoyi_entities_system::entity::{system_role, system_role_menu, system_tenant, system_users};
 use sea_orm::*;
+use std::collections::HashSet;
 
 pub struct TenantService {
     tenant: TenantContextHolder,
@@ -20,11 +40,344 @@ impl TenantService {
 
 impl TenantService {
     pub async fn get_current(&self) -> ApiResult<system_tenant::Model> {
+        self.get_tenant(self.tenant_id()).await
+    }
+
+    pub async fn create_tenant(
+        &self,
+        req_vo: TenantSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        // 1.1 校验租户名称、域名是否重复
+        self.validate_tenant_name_duplicate(&req_vo.name, None)
+            .await?;
+        self.validate_tenant_website_duplicate(&req_vo.websites, None)
+            .await?;
+        // 1.2 校验套餐被禁用
+        let package = TenantPackageService::new(self.tenant)
+            .validate_tenant_package(req_vo.package_id)
+            .await?;
+        let menu_ids = TenantPackageService::get_menu_ids(&package)?;
+        // 1.3 校验管理员账号
+        let username = req_vo
+            .username
+            .clone()
+            .ok_or_else(|| ApiError::Validation("管理员账号不能为空".to_string()))?;
+        let password = req_vo
+            .password
+            .clone()
+            .ok_or_else(|| ApiError::Validation("管理员密码不能为空".to_string()))?;
+        let contact_name = req_vo.contact_name.clone();
+        let contact_mobile = req_vo.contact_mobile.clone();
+
+        let txn = database::get()?.begin().await?;
+        // 2. 创建租户
+        let active_model = create_with_common_fields(
+            system_tenant::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        let tenant = active_model.insert(&txn).await?;
+        // 3. 在新租户下创建角色，并授予套餐的菜单
+        let new_tenant = TenantContextHolder::set_tenant_id(tenant.id);
+        let permission_service = PermissionService::new(new_tenant);
+        let role = create_with_common_fields(
+            system_role::ActiveModel {
+                name: Set(RoleCode::TenantAdmin.name().to_string()),
+                code: Set(RoleCode::TenantAdmin.code().to_string()),
+                sort: Set(0),
+                data_scope: Set(DataScopeEnum::All.scope()),
+                data_scope_dept_ids: Set(EMPTY_VEC_STR.to_string()),
+                status: Set(CommonStatusEnum::Enable.status()),
+                r#type: Set(RoleTypeEnum::System.value()),
+                remark: Set(Some("系统自动生成".to_string())),
+                ..Default::default()
+            },
+            Some(operator.user_id.to_string()),
+            &new_tenant,
+        )
+        .await?
+        .insert(&txn)
+        .await?;
+        permission_service
+            .save_role_menu(&txn, role.id, menu_ids, operator)
+            .await?;
+        // 4. 在新租户下创建管理员用户，并分配角色
+        let user = create_with_common_fields(
+            system_users::ActiveModel {
+                username: Set(username),
+                password: Set(encode_password(&password)?),
+                nickname: Set(contact_name),
+                mobile: Set(contact_mobile),
+                status: Set(CommonStatusEnum::Enable.status()),
+                ..Default::default()
+            },
+            Some(operator.user_id.to_string()),
+            &new_tenant,
+        )
+        .await?
+        .insert(&txn)
+        .await?;
+        permission_service
+            .save_user_role(&txn, user.id, HashSet::from([role.id]), operator)
+            .await?;
+        // 5. 修改租户的管理员
+        let mut active_model = tenant.into_active_model();
+        active_model.contact_user_id = Set(Some(user.id));
+        let tenant = active_model.update(&txn).await?;
+        txn.commit().await?;
+        Ok(tenant.id)
+    }
+
+    pub async fn update_tenant(
+        &self,
+        req_vo: TenantSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(TENANT_NOT_EXISTS))?;
+        // 1.1 校验存在，且不是系统租户
+        let tenant = self.validate_update_tenant(id).await?;
+        // 1.2 校验租户名称、域名是否重复
+        self.validate_tenant_name_duplicate(&req_vo.name, Some(id))
+            .await?;
+        self.validate_tenant_website_duplicate(&req_vo.websites, Some(id))
+            .await?;
+        // 1.3 校验套餐被禁用
+        let package = TenantPackageService::new(self.tenant)
+            .validate_tenant_package(req_vo.package_id)
+            .await?;
+
+        let txn = database::get()?.begin().await?;
+        // 2. 更新租户
+        let mut active_model = system_tenant::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_change_log(&tenant, active_model, Some(operator.user_id.to_string()))
+                .await?;
+        let new_tenant = active_model.update(&txn).await?;
+        // 3. 如果套餐发生变化，则修改其角色的权限
+        let package_changed = tenant.package_id != new_tenant.package_id;
+        if package_changed {
+            self.update_tenant_role_menu(
+                &txn,
+                id,
+                TenantPackageService::get_menu_ids(&package)?,
+                operator,
+            )
+            .await?;
+        }
+        txn.commit().await?;
+        if package_changed {
+            PermissionService::new(self.tenant)
+                .clear_all_user_permission_cache()
+                .await?;
+        }
+        // 4. 状态或过期时间变化时，清理租户有效性的缓存
+        if tenant.status != new_tenant.status || tenant.expire_time != new_tenant.expire_time {
+            self.clear_valid_tenant_cache(id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_tenant(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验存在，且不是系统租户
+        let tenant = self.validate_update_tenant(id).await?;
+        // 删除租户
+        let active_model = soft_delete_with_common_fields(
+            tenant.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(database::get()?).await?;
+        self.clear_valid_tenant_cache(id).await
+    }
+
+    pub async fn get_tenant(&self, id: i64) -> ApiResult<system_tenant::Model> {
+        self.base_query()
+            .filter(system_tenant::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(TENANT_NOT_EXISTS))
+    }
+
+    pub async fn get_tenant_page(&self, req_vo: TenantPageReqVo) -> ApiResult<Page<TenantRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_tenant::Column::Name.contains(name)),
+            )
+            .apply_if(
+                req_vo.contact_name.as_ref().filter(|n| !n.is_empty()),
+                |query, contact_name| {
+                    query.filter(system_tenant::Column::ContactName.contains(contact_name))
+                },
+            )
+            .apply_if(
+                req_vo.contact_mobile.as_ref().filter(|m| !m.is_empty()),
+                |query, contact_mobile| {
+                    query.filter(system_tenant::Column::ContactMobile.contains(contact_mobile))
+                },
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_tenant::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_tenant::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_tenant::Column::CreateTime.lte(end))
+            })
+            .order_by_desc(system_tenant::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(TenantRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_tenant_list_by_status(
+        &self,
+        status: i32,
+    ) -> ApiResult<Vec<system_tenant::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_tenant::Column::Status.eq(status))
+            .order_by_asc(system_tenant::Column::Id)
+            .all(database::get()?)
+            .await?)
+    }
+
+    pub async fn get_tenant_list_by_package_id(
+        &self,
+        package_id: i64,
+    ) -> ApiResult<Vec<system_tenant::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_tenant::Column::PackageId.eq(package_id))
+            .all(database::get()?)
+            .await?)
+    }
+
+    pub async fn get_tenant_count_by_package_id(&self, package_id: i64) -> ApiResult<u64> {
         Ok(self
             .base_query()
-            .filter(system_tenant::Column::Id.eq(self.tenant_id()))
+            .filter(system_tenant::Column::PackageId.eq(package_id))
+            .count(database::get()?)
+            .await?)
+    }
+
+    /// 按套餐同步租户下所有角色的菜单：租户管理员拥有套餐的全部菜单，其它角色移除套餐外的菜单
+    pub async fn update_tenant_role_menu<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        tenant_id: i64,
+        menu_ids: HashSet<i64>,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let permission_service =
+            PermissionService::new(TenantContextHolder::set_tenant_id(tenant_id));
+        let roles = SystemRole::find()
+            .filter(system_role::Column::TenantId.eq(tenant_id))
+            .filter(system_role::Column::Deleted.eq(0))
+            .all(db)
+            .await?;
+        for role in roles {
+            if role.code == RoleCode::TenantAdmin.code() {
+                permission_service
+                    .save_role_menu(db, role.id, menu_ids.clone(), operator)
+                    .await?;
+            } else {
+                let role_menu_ids = SystemRoleMenu::find()
+                    .filter(system_role_menu::Column::RoleId.eq(role.id))
+                    .all(db)
+                    .await?
+                    .into_iter()
+                    .map(|item| item.menu_id)
+                    .filter(|menu_id| menu_ids.contains(menu_id))
+                    .collect();
+                permission_service
+                    .save_role_menu(db, role.id, role_menu_ids, operator)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 清理租户中间件写入的租户有效性缓存
+    async fn clear_valid_tenant_cache(&self, id: i64) -> ApiResult<()> {
+        redis_util::cache_del(&format!("{VALID_TENANT}:{id}")).await?;
+        Ok(())
+    }
+
+    /// 校验租户是系统租户，只有系统租户才能管理租户、租户套餐
+    pub async fn validate_system_tenant(&self, id: i64) -> ApiResult<()> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.package_id != TenantApi::PACKAGE_ID_SYSTEM {
+            return Err(ApiError::BizCode(TENANT_NOT_SYSTEM));
+        }
+        Ok(())
+    }
+
+    async fn validate_update_tenant(&self, id: i64) -> ApiResult<system_tenant::Model> {
+        let tenant = self.get_tenant(id).await?;
+        // 内置租户，不允许修改、删除
+        if tenant.package_id == TenantApi::PACKAGE_ID_SYSTEM {
+            return Err(ApiError::BizCode(TENANT_CAN_NOT_UPDATE_SYSTEM));
+        }
+        Ok(tenant)
+    }
+
+    async fn validate_tenant_name_duplicate(&self, name: &str, id: Option<i64>) -> ApiResult<()> {
+        let tenant = self
+            .base_query()
+            .filter(system_tenant::Column::Name.eq(name))
             .one(database::get()?)
-            .await?
-            .ok_or_else(|| ApiError::BizCode(TENANT_NOT_EXISTS))?)
+            .await?;
+        if tenant.is_some_and(|tenant| Some(tenant.id) != id) {
+            return Err(ApiError::BizCodeWithArgs(
+                TENANT_NAME_DUPLICATE,
+                vec![name.to_string()],
+            ));
+        }
+        Ok(())
+    }
+
+    async fn validate_tenant_website_duplicate(
+        &self,
+        websites: &[String],
+        id: Option<i64>,
+    ) -> ApiResult<()> {
+        for website in websites.iter().filter(|website| !website.is_empty()) {
+            // 域名以逗号分隔存储，模糊查询后再精确比较
+            let duplicate = self
+                .base_query()
+                .filter(system_tenant::Column::Websites.contains(website))
+                .all(database::get()?)
+                .await?
+                .into_iter()
+                .filter(|tenant| Some(tenant.id) != id)
+                .any(|tenant| {
+                    tenant
+                        .websites
+                        .unwrap_or_default()
+                        .split(',')
+                        .any(|item| item == website)
+                });
+            if duplicate {
+                return Err(ApiError::BizCodeWithArgs(
+                    TENANT_WEBSITE_DUPLICATE,
+                    vec![website.clone()],
+                ));
+            }
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,207 @@
+use crate::service::permission::PermissionService;
+use crate::service::tenant::TenantService;
+use crate::vo::tenant_package::tenant_package_page_req_vo::TenantPackagePageReqVo;
+use crate::vo::tenant_package::tenant_package_resp_vo::TenantPackageRespVo;
+use crate::vo::tenant_package::tenant_package_save_req_vo::TenantPackageSaveReqVo;
+use daoyi_common::app::auth::Principal;
+use daoyi_common::app::common::Page;
+use daoyi_common::app::database;
+use daoyi_common::impl_tenant_instance;
+use daoyi_common_support::support::orm::{
+    create_with_common_fields, soft_delete_with_common_fields, update_with_common_fields,
+};
+use daoyi_common_support::support::tenant::TenantContextHolder;
+use daoyi_common_support::utils::enumeration::CommonStatusEnum;
+use daoyi_common_support::utils::errors::error::{ApiError, ApiResult};
+use daoyi_common_support::utils::errors::{
+    TENANT_PACKAGE_DISABLE, TENANT_PACKAGE_NAME_DUPLICATE, TENANT_PACKAGE_NOT_EXISTS,
+    TENANT_PACKAGE_USED,
+};
+use daoyi_entities_system::entity::prelude::SystemTenantPackage;
+use daoyi_entities_system::entity::system_tenant_package;
+use sea_orm::*;
+use std::collections::HashSet;
+
+pub struct TenantPackageService {
+    tenant: TenantContextHolder,
+}
+impl_tenant_instance!(TenantPackageService);
+impl TenantPackageService {
+    // 提取公共查询条件到基础方法
+    fn base_query(&self) -> Select<SystemTenantPackage> {
+        SystemTenantPackage::find().filter(system_tenant_package::Column::Deleted.eq(0))
+    }
+}
+
+impl TenantPackageService {
+    pub async fn create_tenant_package(
+        &self,
+        req_vo: TenantPackageSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<i64> {
+        // 校验套餐名是否重复
+        self.validate_tenant_package_name_unique(&req_vo.name, None)
+            .await?;
+        // 插入租户套餐
+        let active_model = create_with_common_fields(
+            system_tenant_package::ActiveModel::from(req_vo),
+            Some(operator.user_id.to_string()),
+            &self.tenant,
+        )
+        .await?;
+        Ok(active_model.insert(database::get()?).await?.id)
+    }
+
+    pub async fn update_tenant_package(
+        &self,
+        req_vo: TenantPackageSaveReqVo,
+        operator: &Principal,
+    ) -> ApiResult<()> {
+        let id = req_vo
+            .id
+            .ok_or_else(|| ApiError::BizCode(TENANT_PACKAGE_NOT_EXISTS))?;
+        // 1. 校验存在，以及套餐名是否重复
+        let package = self.get_tenant_package(id).await?;
+        self.validate_tenant_package_name_unique(&req_vo.name, Some(id))
+            .await?;
+        // 2. 更新租户套餐
+        let txn = database::get()?.begin().await?;
+        let mut active_model = system_tenant_package::ActiveModel::from(req_vo);
+        active_model.id = Unchanged(id);
+        let active_model =
+            update_with_common_fields(active_model, Some(operator.user_id.to_string())).await?;
+        let new_package = active_model.update(&txn).await?;
+        // 3. 如果菜单发生变化，则同步使用该套餐的租户的角色菜单
+        let menu_ids = Self::get_menu_ids(&new_package)?;
+        let menu_changed = Self::get_menu_ids(&package)? != menu_ids;
+        if menu_changed {
+            let tenant_service = TenantService::new(self.tenant);
+            for tenant in tenant_service.get_tenant_list_by_package_id(id).await? {
+                tenant_service
+                    .update_tenant_role_menu(&txn, tenant.id, menu_ids.clone(), operator)
+                    .await?;
+            }
+        }
+        txn.commit().await?;
+        if menu_changed {
+            PermissionService::new(self.tenant)
+                .clear_all_user_permission_cache()
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_tenant_package(&self, id: i64, operator: &Principal) -> ApiResult<()> {
+        // 校验存在
+        let package = self.get_tenant_package(id).await?;
+        // 校验正在使用
+        if TenantService::new(self.tenant)
+            .get_tenant_count_by_package_id(id)
+            .await?
+            > 0
+        {
+            return Err(ApiError::BizCode(TENANT_PACKAGE_USED));
+        }
+        // 删除租户套餐
+        let active_model = soft_delete_with_common_fields(
+            package.into_active_model(),
+            Some(operator.user_id.to_string()),
+        )
+        .await?;
+        active_model.update(database::get()?).await?;
+        Ok(())
+    }
+
+    pub async fn get_tenant_package_page(
+        &self,
+        req_vo: TenantPackagePageReqVo,
+    ) -> ApiResult<Page<TenantPackageRespVo>> {
+        let paginator = self
+            .base_query()
+            .apply_if(
+                req_vo.name.as_ref().filter(|n| !n.is_empty()),
+                |query, name| query.filter(system_tenant_package::Column::Name.contains(name)),
+            )
+            .apply_if(
+                req_vo.remark.as_ref().filter(|r| !r.is_empty()),
+                |query, remark| {
+                    query.filter(system_tenant_package::Column::Remark.contains(remark))
+                },
+            )
+            .apply_if(req_vo.status, |query, status| {
+                query.filter(system_tenant_package::Column::Status.eq(status))
+            })
+            .apply_if(req_vo.begin_create_time, |query, begin| {
+                query.filter(system_tenant_package::Column::CreateTime.gte(begin))
+            })
+            .apply_if(req_vo.end_create_time, |query, end| {
+                query.filter(system_tenant_package::Column::CreateTime.lte(end))
+            })
+            .order_by_desc(system_tenant_package::Column::Id)
+            .paginate(database::get()?, req_vo.pagination.size);
+        let total = paginator.num_items().await?;
+        let items = paginator
+            .fetch_page(req_vo.pagination.page - 1)
+            .await?
+            .into_iter()
+            .map(TenantPackageRespVo::from)
+            .collect();
+        Ok(Page::from_pagination(req_vo.pagination, total, items))
+    }
+
+    pub async fn get_tenant_package_list_by_status(
+        &self,
+        status: i32,
+    ) -> ApiResult<Vec<system_tenant_package::Model>> {
+        Ok(self
+            .base_query()
+            .filter(system_tenant_package::Column::Status.eq(status))
+            .order_by_asc(system_tenant_package::Column::Id)
+            .all(database::get()?)
+            .await?)
+    }
+
+    pub async fn get_tenant_package(&self, id: i64) -> ApiResult<system_tenant_package::Model> {
+        self.base_query()
+            .filter(system_tenant_package::Column::Id.eq(id))
+            .one(database::get()?)
+            .await?
+            .ok_or_else(|| ApiError::BizCode(TENANT_PACKAGE_NOT_EXISTS))
+    }
+
+    /// 校验租户套餐存在且处于开启状态
+    pub async fn validate_tenant_package(
+        &self,
+        id: i64,
+    ) -> ApiResult<system_tenant_package::Model> {
+        let package = self.get_tenant_package(id).await?;
+        if CommonStatusEnum::is_disable(package.status) {
+            return Err(ApiError::BizCodeWithArgs(
+                TENANT_PACKAGE_DISABLE,
+                vec![package.name],
+            ));
+        }
+        Ok(package)
+    }
+
+    async fn validate_tenant_package_name_unique(
+        &self,
+        name: &str,
+        id: Option<i64>,
+    ) -> ApiResult<()> {
+        let package = self
+            .base_query()
+            .filter(system_tenant_package::Column::Name.eq(name))
+            .one(database::get()?)
+            .await?;
+        if package.is_some_and(|package| Some(package.id) != id) {
+            return Err(ApiError::BizCode(TENANT_PACKAGE_NAME_DUPLICATE));
+        }
+        Ok(())
+    }
+
+    /// 解析套餐的菜单编号数组
+    pub fn get_menu_ids(package: &system_tenant_package::Model) -> ApiResult<HashSet<i64>> {
+        Ok(serde_json::from_str(&package.menu_ids)?)
+    }
+}
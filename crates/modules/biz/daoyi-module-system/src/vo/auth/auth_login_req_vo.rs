@@ -1,3 +1,5 @@
+use daoyi_common_support::utils::enumeration::dict_type_constants;
+use daoyi_common_support::utils::web::validation::DictValidate;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -23,3 +25,12 @@ pub struct AuthLoginReqVo {
     #[schema(example = "admin")]
     pub username: String,
 }
+
+impl DictValidate for AuthLoginReqVo {
+    fn dict_checks(&self) -> Vec<(&'static str, String)> {
+        self.social_type
+            .map(|social_type| (dict_type_constants::SOCIAL_TYPE, social_type.to_string()))
+            .into_iter()
+            .collect()
+    }
+}
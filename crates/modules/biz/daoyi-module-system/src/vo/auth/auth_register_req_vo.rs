@@ -1,4 +1,5 @@
-use daoyi_common_support::utils::web::validation::validate_username;
+use daoyi_common_support::utils::enumeration::dict_type_constants;
+use daoyi_common_support::utils::web::validation::{DictValidate, validate_username};
 use daoyi_entities_system::entity::system_users;
 use sea_orm::Set;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,9 @@ pub struct AuthRegisterReqVo {
     #[validate(length(min = 4, max = 16, message = "密码长度为 4-16 位"))]
     #[schema(example = "password123")]
     pub password: String,
+    /// 用户性别，参见 system_user_sex 字典
+    #[schema(example = 1)]
+    pub sex: Option<i32>,
     /// 用户账号
     #[validate(custom(function = "validate_username"))]
     #[validate(length(min = 4, max = 30, message = "用户账号长度为 4-30 个字符"))]
@@ -32,7 +36,17 @@ impl From<AuthRegisterReqVo> for system_users::ActiveModel {
             username: Set(value.username),
             nickname: Set(value.nickname),
             password: Set(value.password),
+            sex: Set(value.sex),
             ..Default::default()
         }
     }
 }
+
+impl DictValidate for AuthRegisterReqVo {
+    fn dict_checks(&self) -> Vec<(&'static str, String)> {
+        self.sex
+            .map(|sex| (dict_type_constants::USER_SEX, sex.to_string()))
+            .into_iter()
+            .collect()
+    }
+}
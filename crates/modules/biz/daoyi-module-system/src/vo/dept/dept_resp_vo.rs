@@ -0,0 +1,55 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_dept;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DeptRespVO，管理后台 - 部门信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeptRespVo {
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 邮箱
+    #[schema(example = "yudao@iocoder.cn")]
+    pub email: Option<String>,
+    /// 部门编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 负责人的用户编号
+    #[schema(example = 2048)]
+    pub leader_user_id: Option<i64>,
+    /// 部门名称
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父部门 ID
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 联系电话
+    #[schema(example = "15601691000")]
+    pub phone: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态,见 CommonStatusEnum 枚举
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<system_dept::Model> for DeptRespVo {
+    fn from(model: system_dept::Model) -> Self {
+        Self {
+            create_time: model.create_time,
+            email: model.email,
+            id: model.id,
+            leader_user_id: model.leader_user_id,
+            name: model.name,
+            parent_id: model.parent_id,
+            phone: model.phone,
+            sort: model.sort,
+            status: model.status,
+        }
+    }
+}
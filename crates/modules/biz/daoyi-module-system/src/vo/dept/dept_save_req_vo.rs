@@ -0,0 +1,56 @@
+use daoyi_entities_system::entity::system_dept;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// DeptSaveReqVO，管理后台 - 部门创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeptSaveReqVo {
+    /// 邮箱
+    #[validate(email(message = "邮箱格式不正确"))]
+    #[validate(length(max = 50, message = "邮箱长度不能超过 50 个字符"))]
+    #[schema(example = "yudao@iocoder.cn")]
+    pub email: Option<String>,
+    /// 部门编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 负责人的用户编号
+    #[schema(example = 2048)]
+    pub leader_user_id: Option<i64>,
+    /// 部门名称
+    #[validate(length(min = 1, max = 30, message = "部门名称长度为 1-30 个字符"))]
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父部门 ID，根部门为 0
+    #[serde(default)]
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 联系电话
+    #[validate(length(max = 11, message = "联系电话长度不能超过 11 个字符"))]
+    #[schema(example = "15601691000")]
+    pub phone: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态,见 CommonStatusEnum 枚举
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<DeptSaveReqVo> for system_dept::ActiveModel {
+    fn from(value: DeptSaveReqVo) -> Self {
+        Self {
+            name: Set(value.name),
+            parent_id: Set(value.parent_id),
+            sort: Set(value.sort),
+            leader_user_id: Set(value.leader_user_id),
+            phone: Set(value.phone),
+            email: Set(value.email),
+            status: Set(value.status),
+            ..Default::default()
+        }
+    }
+}
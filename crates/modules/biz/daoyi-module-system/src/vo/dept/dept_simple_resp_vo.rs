@@ -0,0 +1,28 @@
+use daoyi_entities_system::entity::system_dept;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DeptSimpleRespVO，管理后台 - 部门精简信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeptSimpleRespVo {
+    /// 部门编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 部门名称
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父部门 ID
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+}
+
+impl From<system_dept::Model> for DeptSimpleRespVo {
+    fn from(model: system_dept::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            parent_id: model.parent_id,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use daoyi_entities_system::entity::system_dept;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DeptTreeRespVO，管理后台 - 部门树 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeptTreeRespVo {
+    /// 部门编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 负责人的用户编号
+    #[schema(example = 2048)]
+    pub leader_user_id: Option<i64>,
+    /// 部门名称
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父部门 ID
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态,见 CommonStatusEnum 枚举
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 子部门
+    #[schema(no_recursion)]
+    pub children: Vec<DeptTreeRespVo>,
+}
+
+impl From<system_dept::Model> for DeptTreeRespVo {
+    fn from(model: system_dept::Model) -> Self {
+        Self {
+            id: model.id,
+            leader_user_id: model.leader_user_id,
+            name: model.name,
+            parent_id: model.parent_id,
+            sort: model.sort,
+            status: model.status,
+            children: vec![],
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dept_list_req_vo;
+pub mod dept_resp_vo;
+pub mod dept_save_req_vo;
+pub mod dept_simple_resp_vo;
+pub mod dept_tree_resp_vo;
@@ -0,0 +1,21 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// DictDataPageReqVO，管理后台 - 字典数据分页列表 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataPageReqVo {
+    /// 字典类型，精确匹配
+    pub dict_type: Option<String>,
+    /// 字典标签，模糊匹配
+    pub label: Option<String>,
+    /// 展示状态，参见 CommonStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
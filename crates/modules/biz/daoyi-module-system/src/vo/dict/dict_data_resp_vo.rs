@@ -0,0 +1,59 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_dict_data;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DictDataRespVO，管理后台 - 字典数据信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataRespVo {
+    /// 颜色类型，default、primary、success、info、warning、danger
+    #[schema(example = "default")]
+    pub color_type: Option<String>,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// css 样式
+    #[schema(example = "btn-visible")]
+    pub css_class: Option<String>,
+    /// 字典类型
+    #[schema(example = "sys_common_sex")]
+    pub dict_type: String,
+    /// 字典数据编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 字典标签
+    #[schema(example = "芋道")]
+    pub label: String,
+    /// 备注
+    #[schema(example = "我是一个角色")]
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 字典值
+    #[schema(example = "iocoder")]
+    pub value: String,
+}
+
+impl From<system_dict_data::Model> for DictDataRespVo {
+    fn from(model: system_dict_data::Model) -> Self {
+        Self {
+            color_type: model.color_type,
+            create_time: model.create_time,
+            css_class: model.css_class,
+            dict_type: model.dict_type,
+            id: model.id,
+            label: model.label,
+            remark: model.remark,
+            sort: model.sort,
+            status: model.status,
+            value: model.value,
+        }
+    }
+}
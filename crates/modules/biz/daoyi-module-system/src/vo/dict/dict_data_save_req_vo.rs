@@ -0,0 +1,61 @@
+use daoyi_entities_system::entity::system_dict_data;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// DictDataSaveReqVO，管理后台 - 字典数据创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataSaveReqVo {
+    /// 颜色类型，default、primary、success、info、warning、danger
+    #[validate(length(max = 100, message = "颜色类型长度不能超过 100 个字符"))]
+    #[schema(example = "default")]
+    pub color_type: Option<String>,
+    /// css 样式
+    #[validate(length(max = 100, message = "css 样式长度不能超过 100 个字符"))]
+    #[schema(example = "btn-visible")]
+    pub css_class: Option<String>,
+    /// 字典类型
+    #[validate(length(min = 1, max = 100, message = "字典类型长度为 1-100 个字符"))]
+    #[schema(example = "sys_common_sex")]
+    pub dict_type: String,
+    /// 字典数据编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 字典标签
+    #[validate(length(min = 1, max = 100, message = "字典标签长度为 1-100 个字符"))]
+    #[schema(example = "芋道")]
+    pub label: String,
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过 500 个字符"))]
+    #[schema(example = "我是一个角色")]
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 字典值
+    #[validate(length(min = 1, max = 100, message = "字典键值长度为 1-100 个字符"))]
+    #[schema(example = "iocoder")]
+    pub value: String,
+}
+
+impl From<DictDataSaveReqVo> for system_dict_data::ActiveModel {
+    fn from(value: DictDataSaveReqVo) -> Self {
+        Self {
+            sort: Set(value.sort),
+            label: Set(value.label),
+            value: Set(value.value),
+            dict_type: Set(value.dict_type),
+            status: Set(value.status),
+            color_type: Set(value.color_type),
+            css_class: Set(value.css_class),
+            remark: Set(value.remark),
+            ..Default::default()
+        }
+    }
+}
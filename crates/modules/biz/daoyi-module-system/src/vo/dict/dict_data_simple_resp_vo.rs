@@ -0,0 +1,36 @@
+use daoyi_entities_system::entity::system_dict_data;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DictDataSimpleRespVO，管理后台 - 数据字典精简 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataSimpleRespVo {
+    /// 颜色类型，default、primary、success、info、warning、danger
+    #[schema(example = "default")]
+    pub color_type: Option<String>,
+    /// css 样式
+    #[schema(example = "btn-visible")]
+    pub css_class: Option<String>,
+    /// 字典类型
+    #[schema(example = "gender")]
+    pub dict_type: String,
+    /// 字典标签
+    #[schema(example = "男")]
+    pub label: String,
+    /// 字典值
+    #[schema(example = "1")]
+    pub value: String,
+}
+
+impl From<system_dict_data::Model> for DictDataSimpleRespVo {
+    fn from(model: system_dict_data::Model) -> Self {
+        Self {
+            color_type: model.color_type,
+            css_class: model.css_class,
+            dict_type: model.dict_type,
+            label: model.label,
+            value: model.value,
+        }
+    }
+}
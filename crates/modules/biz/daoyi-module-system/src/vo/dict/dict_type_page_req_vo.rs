@@ -0,0 +1,28 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::{deserializer_option_number, option_datetime_format};
+use sea_orm::prelude::DateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+/// DictTypePageReqVO，管理后台 - 字典类型分页列表 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypePageReqVo {
+    /// 创建时间的开始
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub begin_create_time: Option<DateTime>,
+    /// 创建时间的结束
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub end_create_time: Option<DateTime>,
+    /// 字典类型名称，模糊匹配
+    pub name: Option<String>,
+    /// 展示状态，参见 CommonStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+    /// 字典类型，模糊匹配
+    pub r#type: Option<String>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
@@ -0,0 +1,43 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_dict_type;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DictTypeRespVO，管理后台 - 字典类型信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeRespVo {
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 字典类型编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 字典名称
+    #[schema(example = "性别")]
+    pub name: String,
+    /// 备注
+    #[schema(example = "快乐的备注")]
+    pub remark: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 字典类型
+    #[schema(example = "sys_common_sex")]
+    pub r#type: String,
+}
+
+impl From<system_dict_type::Model> for DictTypeRespVo {
+    fn from(model: system_dict_type::Model) -> Self {
+        Self {
+            create_time: model.create_time,
+            id: model.id,
+            name: model.name,
+            remark: model.remark,
+            status: model.status,
+            r#type: model.r#type,
+        }
+    }
+}
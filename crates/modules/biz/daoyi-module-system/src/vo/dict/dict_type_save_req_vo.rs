@@ -0,0 +1,44 @@
+use daoyi_common_support::utils::web::validation::validate_dict_type;
+use daoyi_entities_system::entity::system_dict_type;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// DictTypeSaveReqVO，管理后台 - 字典类型创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeSaveReqVo {
+    /// 字典类型编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 字典名称
+    #[validate(length(min = 1, max = 100, message = "字典类型名称长度为 1-100 个字符"))]
+    #[schema(example = "性别")]
+    pub name: String,
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过 500 个字符"))]
+    #[schema(example = "快乐的备注")]
+    pub remark: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 字典类型，只能以小写字母开头，由小写字母、数字、下划线组成
+    #[validate(length(min = 1, max = 100, message = "字典类型长度为 1-100 个字符"))]
+    #[validate(custom(function = "validate_dict_type"))]
+    #[schema(example = "sys_common_sex")]
+    pub r#type: String,
+}
+
+impl From<DictTypeSaveReqVo> for system_dict_type::ActiveModel {
+    fn from(value: DictTypeSaveReqVo) -> Self {
+        Self {
+            name: Set(value.name),
+            r#type: Set(value.r#type),
+            status: Set(value.status),
+            remark: Set(value.remark),
+            ..Default::default()
+        }
+    }
+}
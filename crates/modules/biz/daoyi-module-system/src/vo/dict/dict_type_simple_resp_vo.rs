@@ -0,0 +1,28 @@
+use daoyi_entities_system::entity::system_dict_type;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// DictTypeSimpleRespVO，管理后台 - 字典类型精简信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeSimpleRespVo {
+    /// 字典类型编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 字典类型名称
+    #[schema(example = "性别")]
+    pub name: String,
+    /// 字典类型
+    #[schema(example = "sys_common_sex")]
+    pub r#type: String,
+}
+
+impl From<system_dict_type::Model> for DictTypeSimpleRespVo {
+    fn from(model: system_dict_type::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            r#type: model.r#type,
+        }
+    }
+}
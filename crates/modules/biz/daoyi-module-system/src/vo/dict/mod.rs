@@ -0,0 +1,8 @@
+pub mod dict_data_page_req_vo;
+pub mod dict_data_resp_vo;
+pub mod dict_data_save_req_vo;
+pub mod dict_data_simple_resp_vo;
+pub mod dict_type_page_req_vo;
+pub mod dict_type_resp_vo;
+pub mod dict_type_save_req_vo;
+pub mod dict_type_simple_resp_vo;
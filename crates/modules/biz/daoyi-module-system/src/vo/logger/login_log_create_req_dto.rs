@@ -0,0 +1,22 @@
+use daoyi_common_support::utils::enumeration::{LoginLogTypeEnum, LoginResultEnum};
+
+/// 登录日志的创建 Request DTO
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginLogCreateReqDTO {
+    /// 日志类型
+    pub log_type: LoginLogTypeEnum,
+    /// 登录结果
+    pub result: LoginResultEnum,
+    /// 链路追踪编号
+    pub trace_id: String,
+    /// 浏览器 UA
+    pub user_agent: String,
+    /// 用户编号
+    pub user_id: i64,
+    /// 用户 IP
+    pub user_ip: String,
+    /// 用户类型
+    pub user_type: i32,
+    /// 用户账号
+    pub username: String,
+}
@@ -0,0 +1,33 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::{deserializer_option_number, option_datetime_format};
+use sea_orm::prelude::DateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+/// LoginLogPageReqVO，管理后台 - 登录日志分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginLogPageReqVo {
+    /// 登录时间的开始
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub begin_create_time: Option<DateTime>,
+    /// 登录时间的结束
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub end_create_time: Option<DateTime>,
+    /// 日志类型，参见 LoginLogTypeEnum 枚举
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub log_type: Option<i64>,
+    /// 登录结果，参见 LoginResultEnum 枚举
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub result: Option<i32>,
+    /// 链路追踪编号
+    pub trace_id: Option<String>,
+    /// 用户 IP，模糊匹配
+    pub user_ip: Option<String>,
+    /// 用户账号，模糊匹配
+    pub username: Option<String>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
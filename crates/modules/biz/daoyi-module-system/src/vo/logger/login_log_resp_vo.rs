@@ -0,0 +1,59 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_login_log;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// LoginLogRespVO，管理后台 - 登录日志 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginLogRespVo {
+    /// 登录时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 日志编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 日志类型，参见 LoginLogTypeEnum 枚举
+    #[schema(example = 100)]
+    pub log_type: i64,
+    /// 登录结果，参见 LoginResultEnum 枚举
+    #[schema(example = 0)]
+    pub result: i32,
+    /// 链路追踪编号
+    #[schema(example = "d0v2kpq5ek9s73cg5ddg")]
+    pub trace_id: String,
+    /// 浏览器 UA
+    #[schema(example = "Mozilla/5.0")]
+    pub user_agent: String,
+    /// 用户编号
+    #[schema(example = 1)]
+    pub user_id: i64,
+    /// 用户 IP
+    #[schema(example = "127.0.0.1")]
+    pub user_ip: String,
+    /// 用户类型，参见 UserTypeEnum 枚举
+    #[schema(example = 2)]
+    pub user_type: i32,
+    /// 用户账号
+    #[schema(example = "admin")]
+    pub username: String,
+}
+
+impl From<system_login_log::Model> for LoginLogRespVo {
+    fn from(model: system_login_log::Model) -> Self {
+        Self {
+            create_time: model.create_time,
+            id: model.id,
+            log_type: model.log_type,
+            result: model.result,
+            trace_id: model.trace_id,
+            user_agent: model.user_agent,
+            user_id: model.user_id,
+            user_ip: model.user_ip,
+            user_type: model.user_type,
+            username: model.username,
+        }
+    }
+}
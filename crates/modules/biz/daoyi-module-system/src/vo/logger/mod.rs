@@ -0,0 +1,6 @@
+pub mod login_log_create_req_dto;
+pub mod login_log_page_req_vo;
+pub mod login_log_resp_vo;
+pub mod operate_log_page_req_vo;
+pub mod operate_log_resp_vo;
+pub mod operate_log_timeline_req_vo;
@@ -0,0 +1,34 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::{deserializer_option_number, option_datetime_format};
+use sea_orm::prelude::DateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+/// OperateLogPageReqVO，管理后台 - 操作日志分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OperateLogPageReqVo {
+    /// 操作时间的开始
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub begin_create_time: Option<DateTime>,
+    /// 操作的业务编号
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub biz_id: Option<i64>,
+    /// 操作时间的结束
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub end_create_time: Option<DateTime>,
+    /// 操作名，模糊匹配
+    pub sub_type: Option<String>,
+    /// 操作结果
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub success: Option<bool>,
+    /// 操作模块类型
+    pub r#type: Option<String>,
+    /// 用户编号
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub user_id: Option<i64>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
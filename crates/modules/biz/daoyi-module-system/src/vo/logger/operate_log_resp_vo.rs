@@ -0,0 +1,83 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_operate_log;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OperateLogRespVO，管理后台 - 操作日志 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OperateLogRespVo {
+    /// 操作内容
+    #[schema(example = "更新用户")]
+    pub action: String,
+    /// 操作的业务编号
+    #[schema(example = 1024)]
+    pub biz_id: i64,
+    /// 操作时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 拓展字段，JSON 格式，更新操作变更的字段记录在 `changes` 中
+    #[schema(example = r#"{"changes":[{"field":"nickname","before":"张三","after":"李四"}]}"#)]
+    pub extra: String,
+    /// 日志编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 请求方法名
+    #[schema(example = "PUT")]
+    pub request_method: Option<String>,
+    /// 请求地址
+    #[schema(example = "/system/admin-api/system/user/update")]
+    pub request_url: Option<String>,
+    /// 操作名
+    #[schema(example = "更新用户")]
+    pub sub_type: String,
+    /// 操作结果
+    #[schema(example = true)]
+    pub success: bool,
+    /// 链路追踪编号
+    #[schema(example = "d0v2kpq5ek9s73cg5ddg")]
+    pub trace_id: String,
+    /// 操作模块类型
+    #[schema(example = "SYSTEM 用户")]
+    pub r#type: String,
+    /// 浏览器 UA
+    #[schema(example = "Mozilla/5.0")]
+    pub user_agent: Option<String>,
+    /// 用户编号
+    #[schema(example = 1)]
+    pub user_id: i64,
+    /// 用户 IP
+    #[schema(example = "127.0.0.1")]
+    pub user_ip: Option<String>,
+    /// 用户昵称
+    #[schema(example = "张三")]
+    pub user_name: Option<String>,
+    /// 用户类型，参见 UserTypeEnum 枚举
+    #[schema(example = 2)]
+    pub user_type: i32,
+}
+
+impl From<system_operate_log::Model> for OperateLogRespVo {
+    fn from(model: system_operate_log::Model) -> Self {
+        Self {
+            action: model.action,
+            biz_id: model.biz_id,
+            create_time: model.create_time,
+            extra: model.extra,
+            id: model.id,
+            request_method: model.request_method,
+            request_url: model.request_url,
+            sub_type: model.sub_type,
+            success: model.success,
+            trace_id: model.trace_id,
+            r#type: model.r#type,
+            user_agent: model.user_agent,
+            user_id: model.user_id,
+            user_ip: model.user_ip,
+            user_name: None,
+            user_type: model.user_type,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// OperateLogTimelineReqVO，管理后台 - 业务数据的变更历史 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OperateLogTimelineReqVo {
+    /// 操作的业务编号
+    pub biz_id: i64,
+    /// 操作模块类型
+    #[validate(length(min = 1, max = 50, message = "操作模块类型长度为 1-50 个字符"))]
+    pub r#type: String,
+}
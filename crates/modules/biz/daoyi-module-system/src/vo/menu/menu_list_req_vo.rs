@@ -0,0 +1,14 @@
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// MenuListReqVO，管理后台 - 菜单列表 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuListReqVo {
+    /// 菜单名称，模糊匹配
+    pub name: Option<String>,
+    /// 展示状态，参见 CommonStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+}
@@ -0,0 +1,84 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_menu;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// MenuRespVO，管理后台 - 菜单信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuRespVo {
+    /// 是否总是显示
+    pub always_show: bool,
+    /// 组件路径,仅菜单类型为菜单时，才需要传
+    #[schema(example = "system/post/index")]
+    pub component: Option<String>,
+    /// 组件名
+    #[schema(example = "SystemUser")]
+    pub component_name: Option<String>,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 菜单图标,仅菜单类型为菜单或者目录时，才需要传
+    #[schema(example = "/menu/list")]
+    pub icon: Option<String>,
+    /// 菜单编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 是否缓存
+    pub keep_alive: bool,
+    /// 菜单名称
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父菜单 ID
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 路由地址,仅菜单类型为菜单或者目录时，才需要传
+    #[schema(example = "post")]
+    pub path: Option<String>,
+    /// 授权接口列表
+    #[schema(example = json!(["/system/admin-api/system/user/page"]))]
+    pub perm_apis: Vec<String>,
+    /// 权限标识,仅菜单类型为按钮时，才需要传递
+    #[schema(example = "sys:menu:add")]
+    pub permission: String,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态,见 CommonStatusEnum 枚举
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 类型，参见 MenuTypeEnum 枚举类
+    #[schema(example = 1)]
+    pub r#type: i32,
+    /// 是否可见
+    pub visible: bool,
+}
+
+impl From<system_menu::Model> for MenuRespVo {
+    fn from(model: system_menu::Model) -> Self {
+        Self {
+            always_show: model.always_show,
+            component: model.component,
+            component_name: model.component_name,
+            create_time: model.create_time,
+            icon: model.icon,
+            id: model.id,
+            keep_alive: model.keep_alive,
+            name: model.name,
+            parent_id: model.parent_id,
+            path: model.path,
+            perm_apis: model
+                .perm_apis
+                .as_deref()
+                .and_then(|apis| serde_json::from_str(apis).ok())
+                .unwrap_or_default(),
+            permission: model.permission,
+            sort: model.sort,
+            status: model.status,
+            r#type: model.r#type,
+            visible: model.visible,
+        }
+    }
+}
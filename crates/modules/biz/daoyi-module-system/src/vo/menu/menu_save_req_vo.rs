@@ -0,0 +1,91 @@
+use daoyi_entities_system::entity::system_menu;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// MenuSaveVO，管理后台 - 菜单创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuSaveReqVo {
+    /// 是否总是显示
+    #[serde(default = "default_true")]
+    pub always_show: bool,
+    /// 组件路径,仅菜单类型为菜单时，才需要传
+    #[validate(length(max = 200, message = "组件路径不能超过200个字符"))]
+    #[schema(example = "system/post/index")]
+    pub component: Option<String>,
+    /// 组件名
+    #[schema(example = "SystemUser")]
+    pub component_name: Option<String>,
+    /// 菜单图标,仅菜单类型为菜单或者目录时，才需要传
+    #[schema(example = "/menu/list")]
+    pub icon: Option<String>,
+    /// 菜单编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 是否缓存
+    #[serde(default = "default_true")]
+    pub keep_alive: bool,
+    /// 菜单名称
+    #[validate(length(min = 1, max = 50, message = "菜单名称长度为 1-50 个字符"))]
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父菜单 ID
+    #[serde(default)]
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 路由地址,仅菜单类型为菜单或者目录时，才需要传
+    #[validate(length(max = 200, message = "路由地址不能超过200个字符"))]
+    #[schema(example = "post")]
+    pub path: Option<String>,
+    /// 授权接口列表，访问这些接口需要拥有该菜单
+    #[serde(default)]
+    #[schema(example = json!(["/system/admin-api/system/user/page"]))]
+    pub perm_apis: Vec<String>,
+    /// 权限标识,仅菜单类型为按钮时，才需要传递
+    #[serde(default)]
+    #[validate(length(max = 100, message = "权限标识长度不能超过 100 个字符"))]
+    #[schema(example = "sys:menu:add")]
+    pub permission: String,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态,见 CommonStatusEnum 枚举
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 类型，参见 MenuTypeEnum 枚举类
+    #[validate(range(min = 1, max = 3, message = "菜单类型必须是 1、2 或 3"))]
+    #[schema(example = 1)]
+    pub r#type: i32,
+    /// 是否可见
+    #[serde(default = "default_true")]
+    pub visible: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl From<MenuSaveReqVo> for system_menu::ActiveModel {
+    fn from(value: MenuSaveReqVo) -> Self {
+        Self {
+            name: Set(value.name),
+            permission: Set(value.permission),
+            r#type: Set(value.r#type),
+            sort: Set(value.sort),
+            parent_id: Set(value.parent_id),
+            path: Set(value.path),
+            icon: Set(value.icon),
+            component: Set(value.component),
+            component_name: Set(value.component_name),
+            perm_apis: Set(serde_json::to_string(&value.perm_apis).ok()),
+            status: Set(value.status),
+            visible: Set(value.visible),
+            keep_alive: Set(value.keep_alive),
+            always_show: Set(value.always_show),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use daoyi_entities_system::entity::system_menu;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// MenuSimpleRespVO，管理后台 - 菜单精简信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuSimpleRespVo {
+    /// 菜单编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 菜单名称
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 父菜单 ID
+    #[schema(example = 1024)]
+    pub parent_id: i64,
+    /// 类型，参见 MenuTypeEnum 枚举类
+    #[schema(example = 1)]
+    pub r#type: i32,
+}
+
+impl From<system_menu::Model> for MenuSimpleRespVo {
+    fn from(model: system_menu::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            parent_id: model.parent_id,
+            r#type: model.r#type,
+        }
+    }
+}
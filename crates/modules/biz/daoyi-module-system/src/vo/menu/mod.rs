@@ -1 +1,5 @@
+pub mod menu_list_req_vo;
+pub mod menu_resp_vo;
+pub mod menu_save_req_vo;
+pub mod menu_simple_resp_vo;
 pub mod menu_vo;
@@ -1,4 +1,14 @@
 pub mod auth;
+pub mod dept;
+pub mod dict;
+pub mod logger;
 pub mod menu;
+pub mod notice;
+pub mod oauth2;
+pub mod permission;
+pub mod post;
+pub mod role;
 pub mod sms;
+pub mod tenant;
+pub mod tenant_package;
 pub mod user;
@@ -0,0 +1,6 @@
+pub mod notice_my_page_req_vo;
+pub mod notice_my_resp_vo;
+pub mod notice_page_req_vo;
+pub mod notice_read_req_vo;
+pub mod notice_resp_vo;
+pub mod notice_save_req_vo;
@@ -0,0 +1,17 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// NoticeMyPageReqVO，管理后台 - 我的通知公告分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeMyPageReqVo {
+    /// 是否已读，不传时返回全部
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub read_status: Option<bool>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
@@ -0,0 +1,43 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_notice;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// NoticeMyRespVO，管理后台 - 我的通知公告 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeMyRespVo {
+    /// 公告内容
+    #[schema(example = "半生编码")]
+    pub content: String,
+    /// 发布时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 通知公告序号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 是否已读
+    #[schema(example = false)]
+    pub read_status: bool,
+    /// 公告标题
+    #[schema(example = "小博主")]
+    pub title: String,
+    /// 公告类型，参见 system_notice_type 字典
+    #[schema(example = 1)]
+    pub r#type: i32,
+}
+
+impl NoticeMyRespVo {
+    pub fn new(model: system_notice::Model, read_status: bool) -> Self {
+        Self {
+            content: model.content,
+            create_time: model.create_time,
+            id: model.id,
+            read_status,
+            title: model.title,
+            r#type: model.r#type,
+        }
+    }
+}
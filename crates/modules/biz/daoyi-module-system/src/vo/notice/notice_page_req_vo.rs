@@ -0,0 +1,22 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// NoticePageReqVO，管理后台 - 通知公告分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticePageReqVo {
+    /// 公告状态，参见 NoticeStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+    /// 公告标题，模糊匹配
+    pub title: Option<String>,
+    /// 公告类型，参见 system_notice_type 字典
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub r#type: Option<i32>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
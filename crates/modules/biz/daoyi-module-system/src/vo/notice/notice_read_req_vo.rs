@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// NoticeReadReqVO，管理后台 - 标记通知公告已读 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeReadReqVo {
+    /// 公告编号列表
+    #[validate(length(min = 1, message = "公告编号列表不能为空"))]
+    #[schema(example = json!([1024, 2048]))]
+    pub ids: Vec<i64>,
+}
@@ -0,0 +1,43 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_notice;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// NoticeRespVO，管理后台 - 通知公告信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeRespVo {
+    /// 公告内容
+    #[schema(example = "半生编码")]
+    pub content: String,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 通知公告序号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 公告状态，参见 NoticeStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 公告标题
+    #[schema(example = "小博主")]
+    pub title: String,
+    /// 公告类型，参见 system_notice_type 字典
+    #[schema(example = 1)]
+    pub r#type: i32,
+}
+
+impl From<system_notice::Model> for NoticeRespVo {
+    fn from(model: system_notice::Model) -> Self {
+        Self {
+            content: model.content,
+            create_time: model.create_time,
+            id: model.id,
+            status: model.status,
+            title: model.title,
+            r#type: model.r#type,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use daoyi_common_support::utils::enumeration::dict_type_constants;
+use daoyi_common_support::utils::web::validation::DictValidate;
+use daoyi_entities_system::entity::system_notice;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// NoticeSaveReqVO，管理后台 - 通知公告创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeSaveReqVo {
+    /// 公告内容
+    #[validate(length(min = 1, message = "公告内容不能为空"))]
+    #[schema(example = "半生编码")]
+    pub content: String,
+    /// 通知公告编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 公告状态，0 已发布、1 草稿，参见 NoticeStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 公告标题
+    #[validate(length(min = 1, max = 50, message = "公告标题长度为 1-50 个字符"))]
+    #[schema(example = "小博主")]
+    pub title: String,
+    /// 公告类型，参见 system_notice_type 字典
+    #[schema(example = 1)]
+    pub r#type: i32,
+}
+
+impl From<NoticeSaveReqVo> for system_notice::ActiveModel {
+    fn from(value: NoticeSaveReqVo) -> Self {
+        Self {
+            title: Set(value.title),
+            content: Set(value.content),
+            r#type: Set(value.r#type),
+            status: Set(value.status),
+            ..Default::default()
+        }
+    }
+}
+
+impl DictValidate for NoticeSaveReqVo {
+    fn dict_checks(&self) -> Vec<(&'static str, String)> {
+        vec![(dict_type_constants::NOTICE_TYPE, self.r#type.to_string())]
+    }
+}
@@ -0,0 +1,23 @@
+pub mod oauth2_access_token_resp_vo;
+pub mod oauth2_authorize_client_vo;
+pub mod oauth2_authorize_info_req_vo;
+pub mod oauth2_authorize_info_resp_vo;
+pub mod oauth2_authorize_req_vo;
+pub mod oauth2_authorize_scope_vo;
+pub mod oauth2_client_page_req_vo;
+pub mod oauth2_client_resp_vo;
+pub mod oauth2_client_save_req_vo;
+pub mod oauth2_device_approve_req_vo;
+pub mod oauth2_device_authorization_req_vo;
+pub mod oauth2_device_authorization_resp_vo;
+pub mod oauth2_device_info_req_vo;
+pub mod oauth2_device_info_resp_vo;
+pub mod oauth2_error_resp_vo;
+pub mod oauth2_introspect_req_vo;
+pub mod oauth2_introspect_resp_vo;
+pub mod oauth2_revoke_req_vo;
+pub mod oauth2_token_page_req_vo;
+pub mod oauth2_token_req_vo;
+pub mod oauth2_token_resp_vo;
+pub mod oidc_discovery_resp_vo;
+pub mod oidc_user_info_resp_vo;
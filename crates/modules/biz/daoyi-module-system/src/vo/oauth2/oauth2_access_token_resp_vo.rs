@@ -0,0 +1,49 @@
+use daoyi_common_support::utils::enumeration::EMPTY_VEC_STR;
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use sea_orm::sqlx::types::chrono::Local;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2AccessTokenRespVO，管理后台 - 访问令牌 Response VO，字段名遵循 OAuth2 规范
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OAuth2AccessTokenRespVo {
+    /// 访问令牌
+    #[schema(example = "tudou")]
+    pub access_token: String,
+    /// 过期时间，单位：秒
+    #[schema(example = 42430)]
+    pub expires_in: i64,
+    /// OpenID Connect 的身份令牌，申请 openid 授权范围时返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "eyJhbGciOiJSUzI1NiJ9.xxx.xxx")]
+    pub id_token: Option<String>,
+    /// 刷新令牌，客户端模式不返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "nice")]
+    pub refresh_token: Option<String>,
+    /// 授权范围，多个使用空格分隔
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "user.read user.write")]
+    pub scope: Option<String>,
+    /// 令牌类型
+    #[schema(example = "bearer")]
+    pub token_type: String,
+}
+
+impl From<system_oauth2_access_token::Model> for OAuth2AccessTokenRespVo {
+    fn from(model: system_oauth2_access_token::Model) -> Self {
+        let scopes =
+            serde_json::from_str::<Vec<String>>(model.scopes.as_deref().unwrap_or(EMPTY_VEC_STR))
+                .unwrap_or_default();
+        Self {
+            access_token: model.access_token,
+            expires_in: (model.expires_time - Local::now().naive_local())
+                .num_seconds()
+                .max(0),
+            id_token: None,
+            refresh_token: Some(model.refresh_token).filter(|token| !token.is_empty()),
+            scope: Some(scopes.join(" ")).filter(|scope| !scope.is_empty()),
+            token_type: String::from("bearer"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use daoyi_entities_system::entity::system_oauth2_client;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2AuthorizeInfoRespVO.Client，管理后台 - 授权页的客户端信息 VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2AuthorizeClientVo {
+    /// 应用图标
+    #[schema(example = "https://www.iocoder.cn/xx.png")]
+    pub logo: String,
+    /// 应用名
+    #[schema(example = "土豆")]
+    pub name: String,
+}
+
+impl From<system_oauth2_client::Model> for OAuth2AuthorizeClientVo {
+    fn from(model: system_oauth2_client::Model) -> Self {
+        Self {
+            logo: model.logo,
+            name: model.name,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// OAuth2AuthorizeInfoReqVO，管理后台 - 获得授权页信息 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2AuthorizeInfoReqVo {
+    /// 客户端编号
+    #[validate(length(min = 1, message = "客户端编号不能为空"))]
+    pub client_id: String,
+}
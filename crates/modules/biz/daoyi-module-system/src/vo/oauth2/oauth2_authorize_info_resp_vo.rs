@@ -0,0 +1,14 @@
+use crate::vo::oauth2::oauth2_authorize_client_vo::OAuth2AuthorizeClientVo;
+use crate::vo::oauth2::oauth2_authorize_scope_vo::OAuth2AuthorizeScopeVo;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2AuthorizeInfoRespVO，管理后台 - 授权页的信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2AuthorizeInfoRespVo {
+    /// 客户端
+    pub client: OAuth2AuthorizeClientVo,
+    /// 授权范围及是否已经批准
+    pub scopes: Vec<OAuth2AuthorizeScopeVo>,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// OAuth2AuthorizeReqVO，管理后台 - 申请授权 Request VO，参数名遵循 OAuth2 规范
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+pub struct OAuth2AuthorizeReqVo {
+    /// 是否自动授权，为 true 时基于已有的批准计算，为 false 时为用户在授权页的选择
+    #[serde(default)]
+    pub auto_approve: bool,
+    /// 客户端编号
+    #[validate(length(min = 1, message = "client_id 不能为空"))]
+    pub client_id: String,
+    /// PKCE 的 code_challenge
+    #[validate(length(min = 43, max = 128, message = "code_challenge 长度为 43-128 个字符"))]
+    pub code_challenge: Option<String>,
+    /// PKCE 的 code_challenge_method，仅支持 S256
+    pub code_challenge_method: Option<String>,
+    /// OpenID Connect 的 nonce，申请 openid 授权范围时传递，原样写入 id_token
+    pub nonce: Option<String>,
+    /// 重定向 URI 地址
+    #[validate(length(min = 1, message = "redirect_uri 不能为空"))]
+    pub redirect_uri: String,
+    /// 响应类型，仅支持 code
+    #[validate(length(min = 1, message = "response_type 不能为空"))]
+    pub response_type: String,
+    /// 授权范围，JSON 格式，key 为授权范围，value 为是否批准
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// 状态
+    pub state: Option<String>,
+}
@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2AuthorizeInfoRespVO.Scope，管理后台 - 授权页的授权范围 VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2AuthorizeScopeVo {
+    /// 授权范围
+    #[schema(example = "user.read")]
+    pub key: String,
+    /// 是否已经批准
+    #[schema(example = true)]
+    pub value: bool,
+}
@@ -0,0 +1,19 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// OAuth2ClientPageReqVO，管理后台 - OAuth2 客户端分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ClientPageReqVo {
+    /// 应用名，模糊匹配
+    pub name: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
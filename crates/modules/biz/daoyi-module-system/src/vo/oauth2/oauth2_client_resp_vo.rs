@@ -0,0 +1,94 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_oauth2_client;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2ClientRespVO，管理后台 - OAuth2 客户端 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ClientRespVo {
+    /// 访问令牌的有效期，单位：秒
+    #[schema(example = 8640)]
+    pub access_token_validity_seconds: i32,
+    /// 附加信息，JSON 格式
+    #[schema(example = "{yunai: true}")]
+    pub additional_information: Option<String>,
+    /// 权限
+    #[schema(example = json!(["system:user:query"]))]
+    pub authorities: Vec<String>,
+    /// 授权类型，参见 OAuth2GrantTypeEnum 枚举
+    #[schema(example = json!(["password"]))]
+    pub authorized_grant_types: Vec<String>,
+    /// 自动通过的授权范围
+    #[schema(example = json!(["user_info"]))]
+    pub auto_approve_scopes: Vec<String>,
+    /// 客户端编号
+    #[schema(example = "tudou")]
+    pub client_id: String,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 应用描述
+    #[schema(example = "我是一个应用")]
+    pub description: Option<String>,
+    /// 编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 应用图标
+    #[schema(example = "https://www.iocoder.cn/xx.png")]
+    pub logo: String,
+    /// 应用名
+    #[schema(example = "土豆")]
+    pub name: String,
+    /// 可重定向的 URI 地址
+    #[schema(example = json!(["https://www.iocoder.cn"]))]
+    pub redirect_uris: Vec<String>,
+    /// 刷新令牌的有效期，单位：秒
+    #[schema(example = 8640)]
+    pub refresh_token_validity_seconds: i32,
+    /// 资源
+    #[schema(example = json!(["1024"]))]
+    pub resource_ids: Vec<String>,
+    /// 授权范围
+    #[schema(example = json!(["user_info"]))]
+    pub scopes: Vec<String>,
+    /// 客户端密钥
+    #[schema(example = "fan")]
+    pub secret: String,
+    /// 状态，参见 CommonStatusEnum 枚举
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+/// 解析 JSON 数组格式的字段，为空或格式错误时返回空数组
+fn parse_json_array(value: Option<&str>) -> Vec<String> {
+    value
+        .and_then(|value| serde_json::from_str(value).ok())
+        .unwrap_or_default()
+}
+
+impl From<system_oauth2_client::Model> for OAuth2ClientRespVo {
+    fn from(model: system_oauth2_client::Model) -> Self {
+        Self {
+            access_token_validity_seconds: model.access_token_validity_seconds,
+            authorities: parse_json_array(model.authorities.as_deref()),
+            authorized_grant_types: parse_json_array(model.authorized_grant_types.as_deref()),
+            auto_approve_scopes: parse_json_array(model.auto_approve_scopes.as_deref()),
+            additional_information: model.additional_information,
+            client_id: model.client_id,
+            create_time: model.create_time,
+            description: model.description,
+            id: model.id,
+            logo: model.logo,
+            name: model.name,
+            redirect_uris: parse_json_array(model.redirect_uris.as_deref()),
+            refresh_token_validity_seconds: model.refresh_token_validity_seconds,
+            resource_ids: parse_json_array(model.resource_ids.as_deref()),
+            scopes: parse_json_array(model.scopes.as_deref()),
+            secret: model.secret,
+            status: model.status,
+        }
+    }
+}
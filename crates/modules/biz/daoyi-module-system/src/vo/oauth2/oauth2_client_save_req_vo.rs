@@ -0,0 +1,106 @@
+use daoyi_common_support::utils::enumeration::EMPTY_VEC_STR;
+use daoyi_common_support::utils::web::validation::{validate_json, validate_oauth2_grant_types};
+use daoyi_entities_system::entity::system_oauth2_client;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// OAuth2ClientSaveReqVO，管理后台 - OAuth2 客户端创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ClientSaveReqVo {
+    /// 访问令牌的有效期，单位：秒
+    #[validate(range(min = 1, message = "访问令牌的有效期必须大于 0"))]
+    #[schema(example = 8640)]
+    pub access_token_validity_seconds: i32,
+    /// 附加信息，JSON 格式
+    #[validate(custom(function = "validate_json"))]
+    #[schema(example = "{yunai: true}")]
+    pub additional_information: Option<String>,
+    /// 权限
+    #[serde(default)]
+    #[schema(example = json!(["system:user:query"]))]
+    pub authorities: Vec<String>,
+    /// 授权类型，参见 OAuth2GrantTypeEnum 枚举
+    #[validate(
+        length(min = 1, message = "授权类型不能为空"),
+        custom(function = "validate_oauth2_grant_types")
+    )]
+    #[schema(example = json!(["password"]))]
+    pub authorized_grant_types: Vec<String>,
+    /// 自动通过的授权范围
+    #[serde(default)]
+    #[schema(example = json!(["user_info"]))]
+    pub auto_approve_scopes: Vec<String>,
+    /// 客户端编号
+    #[validate(length(min = 1, max = 255, message = "客户端编号长度为 1-255 个字符"))]
+    #[schema(example = "tudou")]
+    pub client_id: String,
+    /// 应用描述
+    #[validate(length(max = 255, message = "应用描述长度不能超过 255 个字符"))]
+    #[schema(example = "我是一个应用")]
+    pub description: Option<String>,
+    /// 编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 应用图标
+    #[validate(length(min = 1, max = 255, message = "应用图标长度为 1-255 个字符"))]
+    #[schema(example = "https://www.iocoder.cn/xx.png")]
+    pub logo: String,
+    /// 应用名
+    #[validate(length(min = 1, max = 255, message = "应用名长度为 1-255 个字符"))]
+    #[schema(example = "土豆")]
+    pub name: String,
+    /// 可重定向的 URI 地址
+    #[validate(length(min = 1, message = "可重定向的 URI 地址不能为空"))]
+    #[schema(example = json!(["https://www.iocoder.cn"]))]
+    pub redirect_uris: Vec<String>,
+    /// 刷新令牌的有效期，单位：秒
+    #[validate(range(min = 1, message = "刷新令牌的有效期必须大于 0"))]
+    #[schema(example = 8640)]
+    pub refresh_token_validity_seconds: i32,
+    /// 资源
+    #[serde(default)]
+    #[schema(example = json!(["1024"]))]
+    pub resource_ids: Vec<String>,
+    /// 授权范围
+    #[serde(default)]
+    #[schema(example = json!(["user_info"]))]
+    pub scopes: Vec<String>,
+    /// 客户端密钥，创建时为空则自动生成，修改时为空则保持不变
+    #[validate(length(max = 255, message = "客户端密钥长度不能超过 255 个字符"))]
+    #[schema(example = "fan")]
+    pub secret: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+/// 序列化为 JSON 数组格式的字段
+fn to_json_array(value: &[String]) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| EMPTY_VEC_STR.to_string())
+}
+
+impl From<OAuth2ClientSaveReqVo> for system_oauth2_client::ActiveModel {
+    fn from(value: OAuth2ClientSaveReqVo) -> Self {
+        Self {
+            client_id: Set(value.client_id),
+            name: Set(value.name),
+            logo: Set(value.logo),
+            description: Set(value.description),
+            status: Set(value.status),
+            access_token_validity_seconds: Set(value.access_token_validity_seconds),
+            refresh_token_validity_seconds: Set(value.refresh_token_validity_seconds),
+            redirect_uris: Set(Some(to_json_array(&value.redirect_uris))),
+            authorized_grant_types: Set(Some(to_json_array(&value.authorized_grant_types))),
+            scopes: Set(Some(to_json_array(&value.scopes))),
+            auto_approve_scopes: Set(Some(to_json_array(&value.auto_approve_scopes))),
+            authorities: Set(Some(to_json_array(&value.authorities))),
+            resource_ids: Set(Some(to_json_array(&value.resource_ids))),
+            additional_information: Set(value.additional_information),
+            ..Default::default()
+        }
+    }
+}
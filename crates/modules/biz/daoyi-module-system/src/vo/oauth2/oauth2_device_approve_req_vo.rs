@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// OAuth2DeviceApproveReqVO，管理后台 - 确认设备授权 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2DeviceApproveReqVo {
+    /// 是否批准，为 false 时拒绝设备授权
+    #[schema(example = true)]
+    pub approved: bool,
+    /// 用户码
+    #[validate(length(min = 1, message = "用户码不能为空"))]
+    #[schema(example = "WDJB-MJHT")]
+    pub user_code: String,
+}
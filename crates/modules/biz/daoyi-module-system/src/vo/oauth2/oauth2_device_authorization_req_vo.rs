@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// OAuth2DeviceAuthorizationReqVO，管理后台 - 申请设备授权 Request VO，参数名遵循 RFC 8628 规范
+#[derive(Debug, Clone, PartialEq, Deserialize, ToSchema)]
+pub struct OAuth2DeviceAuthorizationReqVo {
+    /// 客户端编号，未使用 Basic 认证时传递
+    pub client_id: Option<String>,
+    /// 客户端密钥，未使用 Basic 认证时传递，公开客户端可以不传递
+    pub client_secret: Option<String>,
+    /// 授权范围，多个使用空格分隔
+    pub scope: Option<String>,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2DeviceAuthorizationRespVO，管理后台 - 设备授权 Response VO，字段名遵循 RFC 8628 规范
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OAuth2DeviceAuthorizationRespVo {
+    /// 设备码，设备使用它轮询令牌端点
+    #[schema(example = "GmRhmhcxhwAzkoEqiMEg_DnyEysNkuNhszIySk9eS")]
+    pub device_code: String,
+    /// 过期时间，单位：秒
+    #[schema(example = 600)]
+    pub expires_in: u64,
+    /// 轮询间隔，单位：秒
+    #[schema(example = 5)]
+    pub interval: u64,
+    /// 用户码，用户在验证页输入它确认授权
+    #[schema(example = "WDJB-MJHT")]
+    pub user_code: String,
+    /// 验证页地址
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/device/verify")]
+    pub verification_uri: String,
+    /// 携带用户码的验证页地址，可以生成二维码供用户扫描
+    #[schema(
+        example = "http://localhost:8080/system/admin-api/system/oauth2/device/verify?userCode=WDJB-MJHT"
+    )]
+    pub verification_uri_complete: String,
+}
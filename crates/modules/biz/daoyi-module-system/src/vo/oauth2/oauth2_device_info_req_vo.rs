@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// OAuth2DeviceInfoReqVO，管理后台 - 获得设备授权信息 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2DeviceInfoReqVo {
+    /// 用户码
+    #[validate(length(min = 1, message = "用户码不能为空"))]
+    pub user_code: String,
+}
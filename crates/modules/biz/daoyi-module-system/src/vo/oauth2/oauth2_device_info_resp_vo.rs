@@ -0,0 +1,17 @@
+use crate::vo::oauth2::oauth2_authorize_client_vo::OAuth2AuthorizeClientVo;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2DeviceInfoRespVO，管理后台 - 设备授权的验证页信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2DeviceInfoRespVo {
+    /// 客户端
+    pub client: OAuth2AuthorizeClientVo,
+    /// 设备申请的授权范围
+    #[schema(example = json!(["user.read", "user.write"]))]
+    pub scopes: Vec<String>,
+    /// 用户码
+    #[schema(example = "WDJB-MJHT")]
+    pub user_code: String,
+}
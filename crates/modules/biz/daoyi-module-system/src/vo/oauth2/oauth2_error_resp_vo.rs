@@ -0,0 +1,152 @@
+use axum::Json;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use daoyi_common_support::utils::enumeration::oauth2_error_constants::{
+    ACCESS_DENIED, AUTHORIZATION_PENDING, EXPIRED_TOKEN, INSUFFICIENT_SCOPE, INVALID_CLIENT,
+    INVALID_GRANT, INVALID_REQUEST, INVALID_SCOPE, INVALID_TOKEN, SERVER_ERROR, SLOW_DOWN,
+    UNAUTHORIZED_CLIENT,
+};
+use daoyi_common_support::utils::errors::error::ApiError;
+use daoyi_common_support::utils::errors::{
+    AUTH_LOGIN_BAD_CREDENTIALS, AUTH_LOGIN_SESSION_LIMIT, AUTH_LOGIN_USER_DISABLED, ErrorCode,
+    OAUTH2_CLIENT_AUTHORIZED_GRANT_TYPE_NOT_EXISTS, OAUTH2_CLIENT_CLIENT_SECRET_ERROR,
+    OAUTH2_CLIENT_DISABLE, OAUTH2_CLIENT_NOT_EXISTS, OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH,
+    OAUTH2_CLIENT_SCOPE_OVER, OAUTH2_CODE_EXPIRE, OAUTH2_CODE_NOT_EXISTS,
+    OAUTH2_GRANT_CLIENT_ID_MISMATCH, OAUTH2_GRANT_CODE_VERIFIER_MISMATCH,
+    OAUTH2_GRANT_DEVICE_ACCESS_DENIED, OAUTH2_GRANT_DEVICE_AUTHORIZATION_PENDING,
+    OAUTH2_GRANT_DEVICE_CODE_EXPIRE, OAUTH2_GRANT_DEVICE_SLOW_DOWN,
+    OAUTH2_GRANT_REDIRECT_URI_MISMATCH, OAUTH2_GRANT_STATE_MISMATCH,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2ErrorRespVO，OAuth2 规范的错误 Response VO，参见 RFC 6749 5.2
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OAuth2ErrorRespVo {
+    /// 错误码，参见 oauth2_error_constants 常量
+    #[schema(example = "invalid_grant")]
+    pub error: String,
+    /// 错误描述
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "code 已过期")]
+    pub error_description: Option<String>,
+}
+
+impl OAuth2ErrorRespVo {
+    pub fn new(error: &str, error_description: impl Into<String>) -> Self {
+        Self {
+            error: error.to_string(),
+            error_description: Some(error_description.into()),
+        }
+    }
+
+    /// 根据错误码获得 HTTP 状态码，客户端认证失败、访问令牌无效为 401，授权范围不足为 403，
+    /// 服务端错误为 500，其余为 400
+    fn status_code(&self) -> StatusCode {
+        match self.error.as_str() {
+            INVALID_CLIENT | INVALID_TOKEN => StatusCode::UNAUTHORIZED,
+            INSUFFICIENT_SCOPE => StatusCode::FORBIDDEN,
+            SERVER_ERROR => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// 业务错误码对应的 OAuth2 错误码
+    fn error_of(error_code: &ErrorCode) -> &'static str {
+        if [
+            OAUTH2_CLIENT_NOT_EXISTS,
+            OAUTH2_CLIENT_DISABLE,
+            OAUTH2_CLIENT_CLIENT_SECRET_ERROR,
+        ]
+        .contains(error_code)
+        {
+            INVALID_CLIENT
+        } else if *error_code == OAUTH2_CLIENT_AUTHORIZED_GRANT_TYPE_NOT_EXISTS {
+            UNAUTHORIZED_CLIENT
+        } else if *error_code == OAUTH2_CLIENT_SCOPE_OVER {
+            INVALID_SCOPE
+        } else if *error_code == OAUTH2_GRANT_DEVICE_AUTHORIZATION_PENDING {
+            AUTHORIZATION_PENDING
+        } else if *error_code == OAUTH2_GRANT_DEVICE_SLOW_DOWN {
+            SLOW_DOWN
+        } else if *error_code == OAUTH2_GRANT_DEVICE_CODE_EXPIRE {
+            EXPIRED_TOKEN
+        } else if *error_code == OAUTH2_GRANT_DEVICE_ACCESS_DENIED {
+            ACCESS_DENIED
+        } else if [
+            OAUTH2_CLIENT_REDIRECT_URI_NOT_MATCH,
+            OAUTH2_GRANT_CLIENT_ID_MISMATCH,
+            OAUTH2_GRANT_REDIRECT_URI_MISMATCH,
+            OAUTH2_GRANT_STATE_MISMATCH,
+            OAUTH2_GRANT_CODE_VERIFIER_MISMATCH,
+            OAUTH2_CODE_NOT_EXISTS,
+            OAUTH2_CODE_EXPIRE,
+            AUTH_LOGIN_BAD_CREDENTIALS,
+            AUTH_LOGIN_USER_DISABLED,
+            AUTH_LOGIN_SESSION_LIMIT,
+        ]
+        .contains(error_code)
+        {
+            INVALID_GRANT
+        } else {
+            INVALID_REQUEST
+        }
+    }
+}
+
+impl From<ApiError> for OAuth2ErrorRespVo {
+    fn from(error: ApiError) -> Self {
+        match error {
+            ApiError::BizCode(error_code) => {
+                Self::new(Self::error_of(&error_code), error_code.msg())
+            }
+            ApiError::BizCodeWithArgs(error_code, args) => {
+                Self::new(Self::error_of(&error_code), error_code.format_message(args))
+            }
+            ApiError::InvalidToken
+            | ApiError::InvalidRefreshToken
+            | ApiError::Unauthenticated(_) => Self::new(INVALID_GRANT, error.to_string()),
+            ApiError::Query(_)
+            | ApiError::Json(_)
+            | ApiError::Form(_)
+            | ApiError::Validation(_)
+            | ApiError::ValidationError(_)
+            | ApiError::Biz(_) => Self::new(INVALID_REQUEST, error.to_string()),
+            error => {
+                tracing::error!("OAuth2 令牌端点异常: {error}");
+                Self::new(SERVER_ERROR, "服务器内部错误")
+            }
+        }
+    }
+}
+
+impl IntoResponse for OAuth2ErrorRespVo {
+    fn into_response(self) -> Response {
+        let status_code = self.status_code();
+        // 客户端认证失败时，提示客户端使用 Basic 认证；访问令牌错误时，提示 Bearer 认证的错误码
+        let authenticate = match self.error.as_str() {
+            INVALID_CLIENT => Some(String::from("Basic realm=\"oauth2\"")),
+            INVALID_TOKEN | INSUFFICIENT_SCOPE => {
+                Some(format!("Bearer realm=\"oauth2\", error=\"{}\"", self.error))
+            }
+            _ => None,
+        };
+        let mut response = (
+            status_code,
+            [
+                (header::CACHE_CONTROL, "no-store"),
+                (header::PRAGMA, "no-cache"),
+            ],
+            Json(self),
+        )
+            .into_response();
+        if let Some(value) =
+            authenticate.and_then(|value| header::HeaderValue::from_str(&value).ok())
+        {
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, value);
+        }
+        response
+    }
+}
@@ -0,0 +1,88 @@
+use daoyi_common_support::utils::enumeration::EMPTY_VEC_STR;
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use sea_orm::sqlx::types::chrono::Local;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OAuth2IntrospectRespVO，管理后台 - 校验令牌 Response VO，字段名遵循 RFC 7662 规范
+///
+/// 令牌无效时仅返回 active = false
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OAuth2IntrospectRespVo {
+    /// 令牌是否有效
+    #[schema(example = true)]
+    pub active: bool,
+    /// 客户端编号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "default")]
+    pub client_id: Option<String>,
+    /// 过期时间，Unix 时间戳，单位：秒
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 1760000000)]
+    pub exp: Option<i64>,
+    /// 授权范围，多个使用空格分隔
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "user.read user.write")]
+    pub scope: Option<String>,
+    /// 令牌主体，即用户编号；客户端模式为客户端编号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "1")]
+    pub sub: Option<String>,
+    /// 租户编号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 1)]
+    pub tenant_id: Option<i64>,
+    /// 令牌类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "bearer")]
+    pub token_type: Option<String>,
+    /// 用户类型，参见 UserTypeEnum 枚举
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2)]
+    pub user_type: Option<i32>,
+}
+
+impl OAuth2IntrospectRespVo {
+    /// 无效令牌的响应
+    pub fn inactive() -> Self {
+        Self {
+            active: false,
+            client_id: None,
+            exp: None,
+            scope: None,
+            sub: None,
+            tenant_id: None,
+            token_type: None,
+            user_type: None,
+        }
+    }
+}
+
+impl From<system_oauth2_access_token::Model> for OAuth2IntrospectRespVo {
+    fn from(model: system_oauth2_access_token::Model) -> Self {
+        let scopes =
+            serde_json::from_str::<Vec<String>>(model.scopes.as_deref().unwrap_or(EMPTY_VEC_STR))
+                .unwrap_or_default();
+        // 客户端模式的访问令牌不关联用户，主体为客户端自身
+        let sub = if model.refresh_token.is_empty() {
+            model.client_id.clone()
+        } else {
+            model.user_id.to_string()
+        };
+        let exp = model
+            .expires_time
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|time| time.timestamp());
+        Self {
+            active: true,
+            client_id: Some(model.client_id),
+            exp,
+            scope: Some(scopes.join(" ")).filter(|scope| !scope.is_empty()),
+            sub: Some(sub),
+            tenant_id: Some(model.tenant_id),
+            token_type: Some(String::from("bearer")),
+            user_type: Some(model.user_type),
+        }
+    }
+}
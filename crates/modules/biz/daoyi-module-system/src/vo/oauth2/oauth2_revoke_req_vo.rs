@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// OAuth2RevokeReqVO，管理后台 - 撤销令牌 Request VO，参数名遵循 RFC 7009 规范
+#[derive(Debug, Clone, PartialEq, Deserialize, ToSchema)]
+pub struct OAuth2RevokeReqVo {
+    /// 客户端编号，未使用 Basic 认证时传递
+    pub client_id: Option<String>,
+    /// 客户端密钥，未使用 Basic 认证时传递
+    pub client_secret: Option<String>,
+    /// 待撤销的令牌，可以是访问令牌或刷新令牌
+    pub token: String,
+    /// 令牌类型提示，可选值：access_token、refresh_token
+    pub token_type_hint: Option<String>,
+}
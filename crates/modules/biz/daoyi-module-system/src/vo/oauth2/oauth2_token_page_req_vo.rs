@@ -0,0 +1,22 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::deserializer_option_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// OAuth2AccessTokenPageReqVO，管理后台 - 在线用户（访问令牌）分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2TokenPageReqVo {
+    /// 客户端编号
+    pub client_id: Option<String>,
+    /// 用户编号
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub user_id: Option<i64>,
+    /// 用户类型，参见 UserTypeEnum 枚举
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub user_type: Option<i32>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// OAuth2TokenReqVO，管理后台 - 获得访问令牌 Request VO，参数名遵循 OAuth2 规范
+#[derive(Debug, Clone, PartialEq, Deserialize, ToSchema)]
+pub struct OAuth2TokenReqVo {
+    /// 客户端编号，未使用 Basic 认证时传递
+    pub client_id: Option<String>,
+    /// 客户端密钥，未使用 Basic 认证时传递
+    pub client_secret: Option<String>,
+    /// 授权码，授权码模式时传递
+    pub code: Option<String>,
+    /// PKCE 的 code_verifier，授权码模式使用 PKCE 时传递
+    pub code_verifier: Option<String>,
+    /// 设备码，设备授权模式时传递
+    pub device_code: Option<String>,
+    /// 授权类型
+    pub grant_type: String,
+    /// 密码，密码模式时传递
+    pub password: Option<String>,
+    /// 重定向 URI 地址，授权码模式时传递
+    pub redirect_uri: Option<String>,
+    /// 刷新令牌，刷新模式时传递
+    pub refresh_token: Option<String>,
+    /// 授权范围，多个使用空格分隔，密码模式、客户端模式时传递
+    pub scope: Option<String>,
+    /// 状态，授权码模式时传递
+    pub state: Option<String>,
+    /// 用户名，密码模式时传递
+    pub username: Option<String>,
+}
@@ -0,0 +1,67 @@
+use daoyi_common::security::login_user::LoginUser;
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_oauth2_access_token;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// OAuth2AccessTokenRespVO，管理后台 - 在线用户（访问令牌）Response VO
+///
+/// 不返回令牌本身，强制退出时使用编号
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2TokenRespVo {
+    /// 客户端编号
+    #[schema(example = "default")]
+    pub client_id: String,
+    /// 登录时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 过期时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub expires_time: DateTime,
+    /// 编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 用户昵称
+    #[schema(example = "张三")]
+    pub nickname: Option<String>,
+    /// 浏览器 UA
+    #[schema(example = "Mozilla/5.0")]
+    pub user_agent: Option<String>,
+    /// 用户编号，客户端模式的令牌为 0
+    #[schema(example = 1)]
+    pub user_id: i64,
+    /// 用户 IP
+    #[schema(example = "127.0.0.1")]
+    pub user_ip: Option<String>,
+    /// 用户类型，参见 UserTypeEnum 枚举
+    #[schema(example = 2)]
+    pub user_type: i32,
+    /// 用户账号
+    #[schema(example = "admin")]
+    pub username: Option<String>,
+}
+
+impl From<system_oauth2_access_token::Model> for OAuth2TokenRespVo {
+    fn from(model: system_oauth2_access_token::Model) -> Self {
+        let nickname = serde_json::from_str::<HashMap<String, String>>(&model.user_info)
+            .ok()
+            .and_then(|mut user_info| user_info.remove(LoginUser::INFO_KEY_NICKNAME));
+        Self {
+            client_id: model.client_id,
+            create_time: model.create_time,
+            expires_time: model.expires_time,
+            id: model.id,
+            nickname,
+            user_agent: model.user_agent,
+            user_id: model.user_id,
+            user_ip: model.user_ip,
+            user_type: model.user_type,
+            username: None,
+        }
+    }
+}
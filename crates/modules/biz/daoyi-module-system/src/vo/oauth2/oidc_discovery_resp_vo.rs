@@ -0,0 +1,55 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OidcDiscoveryRespVO，OpenID Connect 发现文档 Response VO，字段名遵循 OpenID Connect Discovery 1.0 规范
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OidcDiscoveryRespVo {
+    /// 授权端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/authorize")]
+    pub authorization_endpoint: String,
+    /// 支持的用户声明
+    #[schema(example = json!(["sub", "nickname", "email"]))]
+    pub claims_supported: Vec<String>,
+    /// 支持的 PKCE code_challenge_method
+    #[schema(example = json!(["S256"]))]
+    pub code_challenge_methods_supported: Vec<String>,
+    /// 设备授权端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/device/authorize")]
+    pub device_authorization_endpoint: String,
+    /// 支持的授权类型
+    #[schema(example = json!(["authorization_code", "refresh_token"]))]
+    pub grant_types_supported: Vec<String>,
+    /// 支持的 id_token 签名算法
+    #[schema(example = json!(["RS256"]))]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    /// 令牌校验端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/introspect")]
+    pub introspection_endpoint: String,
+    /// 签发者
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2")]
+    pub issuer: String,
+    /// 签名公钥的 JWKS 地址
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/jwks")]
+    pub jwks_uri: String,
+    /// 支持的响应类型
+    #[schema(example = json!(["code"]))]
+    pub response_types_supported: Vec<String>,
+    /// 令牌撤销端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/revoke")]
+    pub revocation_endpoint: String,
+    /// 支持的授权范围
+    #[schema(example = json!(["openid", "profile", "email", "phone", "dept"]))]
+    pub scopes_supported: Vec<String>,
+    /// 支持的主体标识类型
+    #[schema(example = json!(["public"]))]
+    pub subject_types_supported: Vec<String>,
+    /// 令牌端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/token")]
+    pub token_endpoint: String,
+    /// 令牌端点支持的客户端认证方式
+    #[schema(example = json!(["client_secret_basic", "client_secret_post"]))]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    /// 用户信息端点
+    #[schema(example = "http://localhost:8080/system/admin-api/system/oauth2/userinfo")]
+    pub userinfo_endpoint: String,
+}
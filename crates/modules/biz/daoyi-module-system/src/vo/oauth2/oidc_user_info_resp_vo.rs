@@ -0,0 +1,40 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OidcUserInfoRespVO，OpenID Connect 用户信息 Response VO，字段名遵循 OpenID Connect Core 1.0 规范
+///
+/// 除 sub 外的声明按照访问令牌的授权范围返回，未授权的声明不返回
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OidcUserInfoRespVo {
+    /// 部门编号，dept 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 103)]
+    pub dept_id: Option<i64>,
+    /// 部门名称，dept 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "研发部门")]
+    pub dept_name: Option<String>,
+    /// 邮箱，email 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "zhangsan@example.com")]
+    pub email: Option<String>,
+    /// 用户昵称，profile 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "张三")]
+    pub nickname: Option<String>,
+    /// 手机号码，phone 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "13800138000")]
+    pub phone_number: Option<String>,
+    /// 用户头像，profile 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "https://example.com/avatar.png")]
+    pub picture: Option<String>,
+    /// 用户账号，profile 授权范围
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "zhangsan")]
+    pub preferred_username: Option<String>,
+    /// 用户编号
+    #[schema(example = "1")]
+    pub sub: String,
+}
@@ -0,0 +1,4 @@
+pub mod permission_assign_role_data_scope_req_vo;
+pub mod permission_assign_role_menu_req_vo;
+pub mod permission_assign_user_role_req_vo;
+pub mod permission_user_roles_req_vo;
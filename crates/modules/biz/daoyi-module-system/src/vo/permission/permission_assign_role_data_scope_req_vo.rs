@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// PermissionAssignRoleDataScopeReqVO，管理后台 - 赋予角色数据权限 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionAssignRoleDataScopeReqVo {
+    /// 数据范围，参见 DataScopeEnum 枚举类
+    #[validate(range(min = 1, max = 5, message = "数据范围必须在 1-5 之间"))]
+    #[schema(example = 1)]
+    pub data_scope: i32,
+    /// 部门编号列表，只有范围类型为 DEPT_CUSTOM 时，该字段才需要
+    #[serde(default)]
+    #[schema(example = json!([1, 3, 5]))]
+    pub data_scope_dept_ids: HashSet<i64>,
+    /// 角色编号
+    #[schema(example = 1)]
+    pub role_id: i64,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// PermissionAssignRoleMenuReqVO，管理后台 - 赋予角色菜单 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionAssignRoleMenuReqVo {
+    /// 菜单编号列表
+    #[serde(default)]
+    #[schema(example = json!([1, 3, 5]))]
+    pub menu_ids: HashSet<i64>,
+    /// 角色编号
+    #[schema(example = 1)]
+    pub role_id: i64,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// PermissionAssignUserRoleReqVO，管理后台 - 赋予用户角色 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionAssignUserRoleReqVo {
+    /// 角色编号列表
+    #[serde(default)]
+    #[schema(example = json!([1, 3, 5]))]
+    pub role_ids: HashSet<i64>,
+    /// 用户编号
+    #[schema(example = 1)]
+    pub user_id: i64,
+}
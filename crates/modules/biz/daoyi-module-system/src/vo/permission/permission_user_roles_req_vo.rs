@@ -0,0 +1,12 @@
+use daoyi_common_support::utils::serde::deserializer_number;
+use serde::Deserialize;
+use validator::Validate;
+
+/// PermissionUserRolesReqVO，管理后台 - 获得用户拥有的角色 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionUserRolesReqVo {
+    /// 用户编号
+    #[serde(deserialize_with = "deserializer_number")]
+    pub user_id: i64,
+}
@@ -0,0 +1,4 @@
+pub mod post_page_req_vo;
+pub mod post_resp_vo;
+pub mod post_save_req_vo;
+pub mod post_simple_resp_vo;
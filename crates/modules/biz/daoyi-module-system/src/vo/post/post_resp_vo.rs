@@ -0,0 +1,47 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_post;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// PostRespVO，管理后台 - 岗位信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRespVo {
+    /// 岗位编码
+    #[schema(example = "yudao")]
+    pub code: String,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 岗位序号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 岗位名称
+    #[schema(example = "小土豆")]
+    pub name: String,
+    /// 备注
+    #[schema(example = "快乐的备注")]
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<system_post::Model> for PostRespVo {
+    fn from(model: system_post::Model) -> Self {
+        Self {
+            code: model.code,
+            create_time: model.create_time,
+            id: model.id,
+            name: model.name,
+            remark: model.remark,
+            sort: model.sort,
+            status: model.status,
+        }
+    }
+}
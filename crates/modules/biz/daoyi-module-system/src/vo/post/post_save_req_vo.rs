@@ -0,0 +1,46 @@
+use daoyi_entities_system::entity::system_post;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// PostSaveReqVO，管理后台 - 岗位创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSaveReqVo {
+    /// 岗位编码
+    #[validate(length(min = 1, max = 64, message = "岗位编码长度为 1-64 个字符"))]
+    #[schema(example = "yudao")]
+    pub code: String,
+    /// 岗位编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 岗位名称
+    #[validate(length(min = 1, max = 50, message = "岗位名称长度为 1-50 个字符"))]
+    #[schema(example = "小土豆")]
+    pub name: String,
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过 500 个字符"))]
+    #[schema(example = "快乐的备注")]
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<PostSaveReqVo> for system_post::ActiveModel {
+    fn from(value: PostSaveReqVo) -> Self {
+        Self {
+            code: Set(value.code),
+            name: Set(value.name),
+            sort: Set(value.sort),
+            status: Set(value.status),
+            remark: Set(value.remark),
+            ..Default::default()
+        }
+    }
+}
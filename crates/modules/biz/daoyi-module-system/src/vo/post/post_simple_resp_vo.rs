@@ -0,0 +1,24 @@
+use daoyi_entities_system::entity::system_post;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// PostSimpleRespVO，管理后台 - 岗位信息的精简 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSimpleRespVo {
+    /// 岗位序号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 岗位名称
+    #[schema(example = "小土豆")]
+    pub name: String,
+}
+
+impl From<system_post::Model> for PostSimpleRespVo {
+    fn from(model: system_post::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+        }
+    }
+}
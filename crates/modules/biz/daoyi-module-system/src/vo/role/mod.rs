@@ -0,0 +1,4 @@
+pub mod role_page_req_vo;
+pub mod role_resp_vo;
+pub mod role_save_req_vo;
+pub mod role_update_status_req_vo;
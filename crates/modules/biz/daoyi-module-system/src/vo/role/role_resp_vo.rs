@@ -0,0 +1,58 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_role;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// RoleRespVO，管理后台 - 角色信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleRespVo {
+    /// 角色标志
+    #[schema(example = "admin")]
+    pub code: String,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 数据范围，参见 DataScopeEnum 枚举类
+    #[schema(example = 1)]
+    pub data_scope: i32,
+    /// 数据范围(指定部门数组)
+    pub data_scope_dept_ids: Vec<i64>,
+    /// 角色编号
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 角色名称
+    #[schema(example = "管理员")]
+    pub name: String,
+    /// 备注
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 角色类型，参见 RoleTypeEnum 枚举类
+    #[schema(example = 1)]
+    pub r#type: i32,
+}
+
+impl From<system_role::Model> for RoleRespVo {
+    fn from(model: system_role::Model) -> Self {
+        Self {
+            code: model.code,
+            create_time: model.create_time,
+            data_scope: model.data_scope,
+            data_scope_dept_ids: serde_json::from_str(&model.data_scope_dept_ids)
+                .unwrap_or_default(),
+            id: model.id,
+            name: model.name,
+            remark: model.remark,
+            sort: model.sort,
+            status: model.status,
+            r#type: model.r#type,
+        }
+    }
+}
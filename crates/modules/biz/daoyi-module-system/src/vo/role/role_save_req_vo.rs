@@ -0,0 +1,45 @@
+use daoyi_entities_system::entity::system_role;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// RoleSaveReqVO，管理后台 - 角色创建/更新 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleSaveReqVo {
+    /// 角色标志
+    #[validate(length(min = 1, max = 100, message = "角色标志长度为 1-100 个字符"))]
+    #[schema(example = "admin")]
+    pub code: String,
+    /// 角色编号，修改时必传
+    #[schema(example = 1)]
+    pub id: Option<i64>,
+    /// 角色名称
+    #[validate(length(min = 1, max = 30, message = "角色名称长度为 1-30 个字符"))]
+    #[schema(example = "管理员")]
+    pub name: String,
+    /// 备注
+    #[validate(length(max = 500, message = "备注长度不能超过 500 个字符"))]
+    pub remark: Option<String>,
+    /// 显示顺序
+    #[schema(example = 1024)]
+    pub sort: i32,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<RoleSaveReqVo> for system_role::ActiveModel {
+    fn from(value: RoleSaveReqVo) -> Self {
+        Self {
+            name: Set(value.name),
+            code: Set(value.code),
+            sort: Set(value.sort),
+            status: Set(value.status),
+            remark: Set(value.remark),
+            ..Default::default()
+        }
+    }
+}
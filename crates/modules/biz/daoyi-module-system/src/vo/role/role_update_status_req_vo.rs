@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// RoleUpdateStatusReqVO，管理后台 - 角色更新状态 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleUpdateStatusReqVo {
+    /// 角色编号
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 状态，见 CommonStatusEnum 枚举
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 1)]
+    pub status: i32,
+}
@@ -0,0 +1,4 @@
+pub mod tenant_page_req_vo;
+pub mod tenant_resp_vo;
+pub mod tenant_save_req_vo;
+pub mod tenant_simple_resp_vo;
@@ -0,0 +1,30 @@
+use daoyi_common::app::common::PaginationParams;
+use daoyi_common_support::utils::serde::{deserializer_option_number, option_datetime_format};
+use sea_orm::prelude::DateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+/// TenantPageReqVO，管理后台 - 租户分页 Request VO
+#[derive(Debug, Clone, PartialEq, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPageReqVo {
+    /// 创建时间的开始
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub begin_create_time: Option<DateTime>,
+    /// 联系手机，模糊匹配
+    pub contact_mobile: Option<String>,
+    /// 联系人，模糊匹配
+    pub contact_name: Option<String>,
+    /// 创建时间的结束
+    #[serde(default, deserialize_with = "option_datetime_format::deserialize")]
+    pub end_create_time: Option<DateTime>,
+    /// 租户名，模糊匹配
+    pub name: Option<String>,
+    /// 租户状态，参见 CommonStatusEnum 枚举类
+    #[serde(default, deserialize_with = "deserializer_option_number")]
+    pub status: Option<i32>,
+    /// 分页参数
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
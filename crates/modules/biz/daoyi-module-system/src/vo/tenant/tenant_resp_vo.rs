@@ -0,0 +1,73 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_tenant;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// TenantRespVO，管理后台 - 租户 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantRespVo {
+    /// 账号数量
+    #[schema(example = 1024)]
+    pub account_count: i32,
+    /// 联系手机
+    #[schema(example = "15601691300")]
+    pub contact_mobile: Option<String>,
+    /// 联系人
+    #[schema(example = "芋艿")]
+    pub contact_name: String,
+    /// 联系人的用户编号
+    #[schema(example = 1024)]
+    pub contact_user_id: Option<i64>,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 过期时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2099-12-31 23:59:59")]
+    pub expire_time: DateTime,
+    /// 租户编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 租户名
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 租户套餐编号
+    #[schema(example = 1024)]
+    pub package_id: i64,
+    /// 租户状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 绑定域名数组
+    #[schema(example = json!(["www.iocoder.cn"]))]
+    pub websites: Vec<String>,
+}
+
+impl From<system_tenant::Model> for TenantRespVo {
+    fn from(model: system_tenant::Model) -> Self {
+        Self {
+            account_count: model.account_count,
+            contact_mobile: model.contact_mobile,
+            contact_name: model.contact_name,
+            contact_user_id: model.contact_user_id,
+            create_time: model.create_time,
+            expire_time: model.expire_time,
+            id: model.id,
+            name: model.name,
+            package_id: model.package_id,
+            status: model.status,
+            websites: model
+                .websites
+                .map(|websites| {
+                    websites
+                        .split(',')
+                        .filter(|website| !website.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_common_support::utils::web::validation::{is_mobile_phone, validate_username};
+use daoyi_entities_system::entity::system_tenant;
+use sea_orm::Set;
+use sea_orm::prelude::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// TenantSaveReqVO，管理后台 - 租户创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantSaveReqVo {
+    /// 账号数量
+    #[validate(range(min = 1, message = "账号数量必须大于 0"))]
+    #[schema(example = 1024)]
+    pub account_count: i32,
+    /// 联系手机
+    #[validate(custom(function = "is_mobile_phone"))]
+    #[schema(example = "15601691300")]
+    pub contact_mobile: Option<String>,
+    /// 联系人
+    #[validate(length(min = 1, max = 30, message = "联系人长度为 1-30 个字符"))]
+    #[schema(example = "芋艿")]
+    pub contact_name: String,
+    /// 过期时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2099-12-31 23:59:59")]
+    pub expire_time: DateTime,
+    /// 租户编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 租户名
+    #[validate(length(min = 1, max = 30, message = "租户名长度为 1-30 个字符"))]
+    #[schema(example = "芋道")]
+    pub name: String,
+    /// 租户套餐编号
+    #[schema(example = 1024)]
+    pub package_id: i64,
+    /// 管理员密码，仅创建时需要传递
+    #[validate(length(min = 4, max = 16, message = "密码长度为 4-16 位"))]
+    #[schema(example = "123456")]
+    pub password: Option<String>,
+    /// 租户状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 管理员账号，仅创建时需要传递
+    #[validate(custom(function = "validate_username"))]
+    #[validate(length(min = 4, max = 30, message = "用户账号长度为 4-30 个字符"))]
+    #[schema(example = "yudao")]
+    pub username: Option<String>,
+    /// 绑定域名数组
+    #[serde(default)]
+    #[schema(example = json!(["www.iocoder.cn"]))]
+    pub websites: Vec<String>,
+}
+
+impl From<TenantSaveReqVo> for system_tenant::ActiveModel {
+    fn from(value: TenantSaveReqVo) -> Self {
+        Self {
+            name: Set(value.name),
+            contact_name: Set(value.contact_name),
+            contact_mobile: Set(value.contact_mobile),
+            status: Set(value.status),
+            websites: Set(Some(value.websites.join(","))),
+            package_id: Set(value.package_id),
+            expire_time: Set(value.expire_time),
+            account_count: Set(value.account_count),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use daoyi_entities_system::entity::system_tenant;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// TenantSimpleRespVO，管理后台 - 租户精简 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantSimpleRespVo {
+    /// 租户编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 租户名
+    #[schema(example = "芋道")]
+    pub name: String,
+}
+
+impl From<system_tenant::Model> for TenantSimpleRespVo {
+    fn from(model: system_tenant::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod tenant_package_page_req_vo;
+pub mod tenant_package_resp_vo;
+pub mod tenant_package_save_req_vo;
+pub mod tenant_package_simple_resp_vo;
@@ -0,0 +1,44 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_entities_system::entity::system_tenant_package;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+
+/// TenantPackageRespVO，管理后台 - 租户套餐 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageRespVo {
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 套餐编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 关联的菜单编号
+    #[schema(example = json!([1, 2, 100]))]
+    pub menu_ids: HashSet<i64>,
+    /// 套餐名
+    #[schema(example = "VIP")]
+    pub name: String,
+    /// 备注
+    #[schema(example = "好")]
+    pub remark: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<system_tenant_package::Model> for TenantPackageRespVo {
+    fn from(model: system_tenant_package::Model) -> Self {
+        Self {
+            create_time: model.create_time,
+            id: model.id,
+            menu_ids: serde_json::from_str(&model.menu_ids).unwrap_or_default(),
+            name: model.name,
+            remark: model.remark,
+            status: model.status,
+        }
+    }
+}
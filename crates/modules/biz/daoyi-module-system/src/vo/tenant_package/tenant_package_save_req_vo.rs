@@ -0,0 +1,48 @@
+use daoyi_common_support::utils::enumeration::EMPTY_VEC_STR;
+use daoyi_entities_system::entity::system_tenant_package;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// TenantPackageSaveReqVO，管理后台 - 租户套餐创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageSaveReqVo {
+    /// 套餐编号，修改时必传
+    #[schema(example = 1024)]
+    pub id: Option<i64>,
+    /// 关联的菜单编号
+    #[serde(default)]
+    #[schema(example = json!([1, 2, 100]))]
+    pub menu_ids: HashSet<i64>,
+    /// 套餐名
+    #[validate(length(min = 1, max = 30, message = "套餐名长度为 1-30 个字符"))]
+    #[schema(example = "VIP")]
+    pub name: String,
+    /// 备注
+    #[validate(length(max = 256, message = "备注长度不能超过 256 个字符"))]
+    #[schema(example = "好")]
+    pub remark: Option<String>,
+    /// 状态，参见 CommonStatusEnum 枚举类
+    #[validate(range(min = 0, max = 1, message = "状态必须是 0 或 1"))]
+    #[schema(example = 0)]
+    pub status: i32,
+}
+
+impl From<TenantPackageSaveReqVo> for system_tenant_package::ActiveModel {
+    fn from(value: TenantPackageSaveReqVo) -> Self {
+        let mut menu_ids = value.menu_ids.into_iter().collect::<Vec<_>>();
+        menu_ids.sort_unstable();
+        Self {
+            name: Set(value.name),
+            status: Set(value.status),
+            remark: Set(value.remark),
+            menu_ids: Set(
+                serde_json::to_string(&menu_ids).unwrap_or_else(|_| EMPTY_VEC_STR.to_string())
+            ),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use daoyi_entities_system::entity::system_tenant_package;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// TenantPackageSimpleRespVO，管理后台 - 租户套餐精简 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageSimpleRespVo {
+    /// 套餐编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 套餐名
+    #[schema(example = "VIP")]
+    pub name: String,
+}
+
+impl From<system_tenant_package::Model> for TenantPackageSimpleRespVo {
+    fn from(model: system_tenant_package::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+        }
+    }
+}
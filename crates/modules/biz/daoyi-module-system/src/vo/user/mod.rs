@@ -1 +1,7 @@
+pub mod user_page_req_vo;
+pub mod user_resp_vo;
+pub mod user_save_req_vo;
+pub mod user_session_resp_vo;
+pub mod user_update_password_req_vo;
+pub mod user_update_status_req_vo;
 pub mod user_vo;
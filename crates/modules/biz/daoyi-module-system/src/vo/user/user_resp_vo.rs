@@ -0,0 +1,71 @@
+use daoyi_common_support::utils::serde::{datetime_format, option_datetime_format};
+use daoyi_entities_system::entity::system_users;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// UserRespVO，管理后台 - 用户信息 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRespVo {
+    /// 用户头像
+    pub avatar: Option<String>,
+    /// 创建时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 部门编号
+    pub dept_id: Option<i64>,
+    /// 部门名称
+    pub dept_name: Option<String>,
+    /// 用户邮箱
+    pub email: Option<String>,
+    /// 用户编号
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 最后登录 IP
+    pub login_ip: Option<String>,
+    /// 最后登录时间
+    #[serde(with = "option_datetime_format")]
+    #[schema(value_type = Option<String>, example = "2024-12-31 23:59:59")]
+    pub login_date: Option<DateTime>,
+    /// 手机号码
+    pub mobile: Option<String>,
+    /// 用户昵称
+    #[schema(example = "张三")]
+    pub nickname: String,
+    /// 岗位编号数组
+    pub post_ids: Vec<i64>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 用户性别
+    pub sex: Option<i32>,
+    /// 帐号状态
+    #[schema(example = 0)]
+    pub status: i32,
+    /// 用户账号
+    #[schema(example = "zhangsan")]
+    pub username: String,
+}
+
+impl From<system_users::Model> for UserRespVo {
+    fn from(model: system_users::Model) -> Self {
+        Self {
+            avatar: model.avatar,
+            create_time: model.create_time,
+            dept_id: model.dept_id,
+            dept_name: None,
+            email: model.email,
+            id: model.id,
+            login_ip: model.login_ip,
+            login_date: model.login_date,
+            mobile: model.mobile,
+            nickname: model.nickname,
+            post_ids: vec![],
+            remark: model.remark,
+            sex: model.sex,
+            status: model.status,
+            username: model.username,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use daoyi_common_support::utils::enumeration::dict_type_constants;
+use daoyi_common_support::utils::web::validation::{
+    DictValidate, is_mobile_phone, validate_username,
+};
+use daoyi_entities_system::entity::system_users;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// UserSaveReqVO，管理后台 - 用户创建/修改 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSaveReqVo {
+    /// 用户头像
+    pub avatar: Option<String>,
+    /// 部门编号
+    #[schema(example = 100)]
+    pub dept_id: Option<i64>,
+    /// 用户邮箱
+    #[validate(email(message = "邮箱格式不正确"))]
+    #[validate(length(max = 50, message = "邮箱长度不能超过 50 个字符"))]
+    #[schema(example = "zhangsan@iocoder.cn")]
+    pub email: Option<String>,
+    /// 用户编号，修改时必传
+    #[schema(example = 1)]
+    pub id: Option<i64>,
+    /// 手机号码
+    #[validate(custom(function = "is_mobile_phone"))]
+    #[schema(example = "15601691300")]
+    pub mobile: Option<String>,
+    /// 用户昵称
+    #[validate(length(min = 1, max = 30, message = "用户昵称长度为 1-30 个字符"))]
+    #[schema(example = "张三")]
+    pub nickname: String,
+    /// 密码，仅创建时生效，不传则使用初始密码
+    #[validate(length(min = 4, max = 16, message = "密码长度为 4-16 位"))]
+    #[schema(example = "123456")]
+    pub password: Option<String>,
+    /// 岗位编号数组
+    #[serde(default)]
+    #[schema(example = json!([1, 2]))]
+    pub post_ids: HashSet<i64>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 用户性别，参见 system_user_sex 字典
+    #[schema(example = 1)]
+    pub sex: Option<i32>,
+    /// 用户账号
+    #[validate(custom(function = "validate_username"))]
+    #[validate(length(min = 4, max = 30, message = "用户账号长度为 4-30 个字符"))]
+    #[schema(example = "zhangsan")]
+    pub username: String,
+}
+
+impl From<UserSaveReqVo> for system_users::ActiveModel {
+    fn from(value: UserSaveReqVo) -> Self {
+        Self {
+            username: Set(value.username),
+            nickname: Set(value.nickname),
+            remark: Set(value.remark),
+            dept_id: Set(value.dept_id),
+            email: Set(value.email),
+            mobile: Set(value.mobile),
+            sex: Set(value.sex),
+            avatar: Set(value.avatar),
+            ..Default::default()
+        }
+    }
+}
+
+impl DictValidate for UserSaveReqVo {
+    fn dict_checks(&self) -> Vec<(&'static str, String)> {
+        self.sex
+            .map(|sex| (dict_type_constants::USER_SEX, sex.to_string()))
+            .into_iter()
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use daoyi_common_support::utils::serde::datetime_format;
+use daoyi_common_support::utils::web::client_info::UserAgent;
+use daoyi_entities_system::entity::system_oauth2_refresh_token;
+use sea_orm::prelude::DateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// UserSessionRespVO，管理后台 - 用户个人中心登录会话 Response VO
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSessionRespVo {
+    /// 浏览器，由 UA 解析
+    #[schema(example = "Chrome 120.0.0.0")]
+    pub browser: Option<String>,
+    /// 客户端编号
+    #[schema(example = "default")]
+    pub client_id: String,
+    /// 登录时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub create_time: DateTime,
+    /// 是否为当前会话
+    #[schema(example = true)]
+    pub current: bool,
+    /// 过期时间
+    #[serde(with = "datetime_format")]
+    #[schema(value_type = String, example = "2024-12-31 23:59:59")]
+    pub expires_time: DateTime,
+    /// 会话编号
+    #[schema(example = 1024)]
+    pub id: i64,
+    /// 操作系统，由 UA 解析
+    #[schema(example = "Windows 10")]
+    pub os: Option<String>,
+    /// 浏览器 UA
+    #[schema(example = "Mozilla/5.0")]
+    pub user_agent: Option<String>,
+    /// 用户 IP
+    #[schema(example = "127.0.0.1")]
+    pub user_ip: Option<String>,
+}
+
+impl From<system_oauth2_refresh_token::Model> for UserSessionRespVo {
+    fn from(model: system_oauth2_refresh_token::Model) -> Self {
+        let user_agent = UserAgent::parse(model.user_agent.as_deref().unwrap_or_default());
+        Self {
+            browser: user_agent.browser,
+            client_id: model.client_id,
+            create_time: model.create_time,
+            current: false,
+            expires_time: model.expires_time,
+            id: model.id,
+            os: user_agent.os,
+            user_agent: model.user_agent,
+            user_ip: model.user_ip,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// UserUpdatePasswordReqVO，管理后台 - 用户重置密码 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUpdatePasswordReqVo {
+    /// 用户编号
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 密码
+    #[validate(length(min = 4, max = 16, message = "密码长度为 4-16 位"))]
+    #[schema(example = "123456")]
+    pub password: String,
+}
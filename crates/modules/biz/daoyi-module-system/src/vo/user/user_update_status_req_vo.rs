@@ -0,0 +1,23 @@
+use daoyi_common_support::utils::enumeration::dict_type_constants;
+use daoyi_common_support::utils::web::validation::DictValidate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// UserUpdateStatusReqVO，管理后台 - 用户更新状态 Request VO
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUpdateStatusReqVo {
+    /// 用户编号
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 状态，见 CommonStatusEnum 枚举
+    #[schema(example = 1)]
+    pub status: i32,
+}
+
+impl DictValidate for UserUpdateStatusReqVo {
+    fn dict_checks(&self) -> Vec<(&'static str, String)> {
+        vec![(dict_type_constants::COMMON_STATUS, self.status.to_string())]
+    }
+}
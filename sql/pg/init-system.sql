@@ -2142,6 +2142,66 @@ INSERT INTO system_menu (id, name, permission, type, sort, parent_id, path, icon
 INSERT INTO system_menu (id, name, permission, type, sort, parent_id, path, icon, component, component_name, status, visible, keep_alive, always_show, creator, create_time, updater, update_time, deleted) VALUES (5010, '租户切换', 'system:tenant:visit', 3, 999, 1138, '', '', '', '', 0, '1', '1', '1', '1', '2025-05-05 15:25:32', '1', '2025-05-05 15:25:32', '0');
 INSERT INTO system_menu (id, name, permission, type, sort, parent_id, path, icon, component, component_name, status, visible, keep_alive, always_show, creator, create_time, updater, update_time, deleted) VALUES (5011, '转账订单查询', 'pay:transfer:query', 3, 1, 2559, '', '', '', '', 0, '1', '1', '1', '1', '2025-05-08 12:46:53', '1', '2025-05-08 12:46:53', '0');
 INSERT INTO system_menu (id, name, permission, type, sort, parent_id, path, icon, component, component_name, status, visible, keep_alive, always_show, creator, create_time, updater, update_time, deleted) VALUES (5012, '转账订单导出', 'pay:transfer:export', 3, 2, 2559, '', '', '', '', 0, '1', '1', '1', '1', '2025-05-10 17:00:28', '1', '2025-05-10 17:00:28', '0');
+
+-- 租户管理菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/page","/system/admin-api/system/tenant/get"]' WHERE id = 1139;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/create","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1140;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/update","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1141;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/delete"]' WHERE id = 1142;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/simple-list"]' WHERE id = 5010;
+
+-- 租户套餐菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/page","/system/admin-api/system/tenant-package/get","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1226;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/create"]' WHERE id = 1227;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/update"]' WHERE id = 1228;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/delete"]' WHERE id = 1229;
+
+-- 系统管理菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/page","/system/admin-api/system/user/get"]' WHERE id = 1001;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/create"]' WHERE id = 1002;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/update","/system/admin-api/system/user/update-status"]' WHERE id = 1003;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/delete"]' WHERE id = 1004;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/reset-password"]' WHERE id = 1007;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/page","/system/admin-api/system/role/get"]' WHERE id = 1008;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/create"]' WHERE id = 1009;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/update","/system/admin-api/system/role/update-status"]' WHERE id = 1010;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/delete"]' WHERE id = 1011;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/assign-menus","/system/admin-api/system/role/list-menus"]' WHERE id = 1063;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/assign-data-scope"]' WHERE id = 1064;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/assign-roles","/system/admin-api/system/role/list-by-user"]' WHERE id = 1065;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/list","/system/admin-api/system/menu/get"]' WHERE id = 1013;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/create"]' WHERE id = 1014;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/update"]' WHERE id = 1015;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/delete"]' WHERE id = 1016;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/list","/system/admin-api/system/dept/get"]' WHERE id = 1017;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/create"]' WHERE id = 1018;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/update"]' WHERE id = 1019;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/delete"]' WHERE id = 1020;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/page","/system/admin-api/system/post/get"]' WHERE id = 1021;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/create"]' WHERE id = 1022;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/update"]' WHERE id = 1023;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/delete"]' WHERE id = 1024;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/page","/system/admin-api/system/dict-type/get","/system/admin-api/system/dict-data/page","/system/admin-api/system/dict-data/get"]' WHERE id = 1026;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/create","/system/admin-api/system/dict-data/create"]' WHERE id = 1027;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/update","/system/admin-api/system/dict-data/update"]' WHERE id = 1028;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/delete","/system/admin-api/system/dict-data/delete"]' WHERE id = 1029;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/page","/system/admin-api/system/notice/get"]' WHERE id = 1036;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/create"]' WHERE id = 1037;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/update"]' WHERE id = 1038;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/delete"]' WHERE id = 1039;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/operate-log/page","/system/admin-api/system/operate-log/timeline"]' WHERE id = 1040;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/login-log/page"]' WHERE id = 1043;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/login-log/export-csv"]' WHERE id = 1045;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-token/page"]' WHERE id = 1046;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-token/delete"]' WHERE id = 1048;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/page","/system/admin-api/system/oauth2-client/get"]' WHERE id = 1264;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/create"]' WHERE id = 1265;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/update","/system/admin-api/system/oauth2-client/regenerate-secret"]' WHERE id = 1266;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/delete"]' WHERE id = 1267;
+
+-- 参数配置菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/infra/admin-api/infra/config/get"]' WHERE id = 1031;
+UPDATE system_menu SET perm_apis = '["/infra/admin-api/infra/config/update"]' WHERE id = 1033;
 COMMIT;
 -- @formatter:on
 
@@ -2213,6 +2273,52 @@ ALTER TABLE system_notice
 ALTER SEQUENCE system_notice_seq
     OWNED BY system_notice.id;
 
+-- ----------------------------
+-- Table structure for system_notice_read
+-- ----------------------------
+DROP TABLE IF EXISTS system_notice_read;
+CREATE TABLE system_notice_read
+(
+    id          int8        NOT NULL,
+    notice_id   int8        NOT NULL,
+    user_id     int8        NOT NULL,
+    user_type   int4        NOT NULL,
+    creator     varchar(64) NULL     DEFAULT '',
+    create_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(64) NULL     DEFAULT '',
+    update_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     int4        NOT NULL DEFAULT 0,
+    tenant_id   int8        NOT NULL DEFAULT 0
+);
+
+ALTER TABLE system_notice_read
+    ADD CONSTRAINT pk_system_notice_read PRIMARY KEY (id);
+
+CREATE UNIQUE INDEX uk_system_notice_read_notice_user ON system_notice_read (notice_id, user_id, user_type);
+
+COMMENT ON COLUMN system_notice_read.id IS '编号';
+COMMENT ON COLUMN system_notice_read.notice_id IS '公告编号';
+COMMENT ON COLUMN system_notice_read.user_id IS '用户编号';
+COMMENT ON COLUMN system_notice_read.user_type IS '用户类型';
+COMMENT ON COLUMN system_notice_read.creator IS '创建者';
+COMMENT ON COLUMN system_notice_read.create_time IS '创建时间';
+COMMENT ON COLUMN system_notice_read.updater IS '更新者';
+COMMENT ON COLUMN system_notice_read.update_time IS '更新时间';
+COMMENT ON COLUMN system_notice_read.deleted IS '是否删除';
+COMMENT ON COLUMN system_notice_read.tenant_id IS '租户编号';
+COMMENT ON TABLE system_notice_read IS '通知公告已读记录表';
+
+DROP SEQUENCE IF EXISTS system_notice_read_seq;
+CREATE SEQUENCE system_notice_read_seq
+    START 1;
+-- 将序列与表的 id 字段关联
+ALTER TABLE system_notice_read
+    ALTER COLUMN id SET DEFAULT nextval('system_notice_read_seq');
+
+-- 设置序列由表拥有
+ALTER SEQUENCE system_notice_read_seq
+    OWNED BY system_notice_read.id;
+
 -- ----------------------------
 -- Table structure for system_notify_message
 -- ----------------------------
@@ -2355,6 +2461,8 @@ CREATE TABLE system_oauth2_access_token
     client_id     varchar(255) NOT NULL,
     scopes        varchar(255) NULL     DEFAULT NULL,
     expires_time  timestamp    NOT NULL,
+    user_ip       varchar(50)  NULL     DEFAULT NULL,
+    user_agent    varchar(512) NULL     DEFAULT NULL,
     creator       varchar(64)  NULL     DEFAULT '',
     create_time   timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updater       varchar(64)  NULL     DEFAULT '',
@@ -2378,6 +2486,8 @@ COMMENT ON COLUMN system_oauth2_access_token.refresh_token IS '刷新令牌';
 COMMENT ON COLUMN system_oauth2_access_token.client_id IS '客户端编号';
 COMMENT ON COLUMN system_oauth2_access_token.scopes IS '授权范围';
 COMMENT ON COLUMN system_oauth2_access_token.expires_time IS '过期时间';
+COMMENT ON COLUMN system_oauth2_access_token.user_ip IS '用户 IP';
+COMMENT ON COLUMN system_oauth2_access_token.user_agent IS '浏览器 UA';
 COMMENT ON COLUMN system_oauth2_access_token.creator IS '创建者';
 COMMENT ON COLUMN system_oauth2_access_token.create_time IS '创建时间';
 COMMENT ON COLUMN system_oauth2_access_token.updater IS '更新者';
@@ -2531,21 +2641,24 @@ ALTER SEQUENCE system_oauth2_client_seq
 DROP TABLE IF EXISTS system_oauth2_code;
 CREATE TABLE system_oauth2_code
 (
-    id           int8         NOT NULL,
-    user_id      int8         NOT NULL,
-    user_type    int4         NOT NULL,
-    code         varchar(32)  NOT NULL,
-    client_id    varchar(255) NOT NULL,
-    scopes       varchar(255) NULL     DEFAULT '',
-    expires_time timestamp    NOT NULL,
-    redirect_uri varchar(255) NULL     DEFAULT NULL,
-    state        varchar(255) NOT NULL DEFAULT '',
-    creator      varchar(64)  NULL     DEFAULT '',
-    create_time  timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
-    updater      varchar(64)  NULL     DEFAULT '',
-    update_time  timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
-    deleted      int4         NOT NULL DEFAULT 0,
-    tenant_id    int8         NOT NULL DEFAULT 0
+    id                    int8         NOT NULL,
+    user_id               int8         NOT NULL,
+    user_type             int4         NOT NULL,
+    code                  varchar(32)  NOT NULL,
+    client_id             varchar(255) NOT NULL,
+    scopes                varchar(255) NULL     DEFAULT '',
+    expires_time          timestamp    NOT NULL,
+    redirect_uri          varchar(255) NULL     DEFAULT NULL,
+    state                 varchar(255) NOT NULL DEFAULT '',
+    code_challenge        varchar(128) NULL     DEFAULT NULL,
+    code_challenge_method varchar(16)  NULL     DEFAULT NULL,
+    nonce                 varchar(255) NULL     DEFAULT NULL,
+    creator               varchar(64)  NULL     DEFAULT '',
+    create_time           timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater               varchar(64)  NULL     DEFAULT '',
+    update_time           timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted               int4         NOT NULL DEFAULT 0,
+    tenant_id             int8         NOT NULL DEFAULT 0
 );
 
 ALTER TABLE system_oauth2_code
@@ -2560,6 +2673,9 @@ COMMENT ON COLUMN system_oauth2_code.scopes IS '授权范围';
 COMMENT ON COLUMN system_oauth2_code.expires_time IS '过期时间';
 COMMENT ON COLUMN system_oauth2_code.redirect_uri IS '可重定向的 URI 地址';
 COMMENT ON COLUMN system_oauth2_code.state IS '状态';
+COMMENT ON COLUMN system_oauth2_code.code_challenge IS 'PKCE 的 code_challenge';
+COMMENT ON COLUMN system_oauth2_code.code_challenge_method IS 'PKCE 的 code_challenge_method';
+COMMENT ON COLUMN system_oauth2_code.nonce IS 'OpenID Connect 的 nonce';
 COMMENT ON COLUMN system_oauth2_code.creator IS '创建者';
 COMMENT ON COLUMN system_oauth2_code.create_time IS '创建时间';
 COMMENT ON COLUMN system_oauth2_code.updater IS '更新者';
@@ -2592,6 +2708,9 @@ CREATE TABLE system_oauth2_refresh_token
     client_id     varchar(255) NOT NULL,
     scopes        varchar(255) NULL     DEFAULT NULL,
     expires_time  timestamp    NOT NULL,
+    user_ip       varchar(50)  NULL     DEFAULT NULL,
+    user_agent    varchar(512) NULL     DEFAULT NULL,
+    family_id     varchar(32)  NULL     DEFAULT NULL,
     creator       varchar(64)  NULL     DEFAULT '',
     create_time   timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updater       varchar(64)  NULL     DEFAULT '',
@@ -2610,6 +2729,9 @@ COMMENT ON COLUMN system_oauth2_refresh_token.user_type IS '用户类型';
 COMMENT ON COLUMN system_oauth2_refresh_token.client_id IS '客户端编号';
 COMMENT ON COLUMN system_oauth2_refresh_token.scopes IS '授权范围';
 COMMENT ON COLUMN system_oauth2_refresh_token.expires_time IS '过期时间';
+COMMENT ON COLUMN system_oauth2_refresh_token.user_ip IS '用户 IP';
+COMMENT ON COLUMN system_oauth2_refresh_token.user_agent IS '浏览器 UA';
+COMMENT ON COLUMN system_oauth2_refresh_token.family_id IS '令牌家族编号，轮换产生的刷新令牌共用同一家族';
 COMMENT ON COLUMN system_oauth2_refresh_token.creator IS '创建者';
 COMMENT ON COLUMN system_oauth2_refresh_token.create_time IS '创建时间';
 COMMENT ON COLUMN system_oauth2_refresh_token.updater IS '更新者';
@@ -4341,12 +4463,13 @@ INSERT INTO system_user_post (id, user_id, post_id, creator, create_time, update
 INSERT INTO system_user_post (id, user_id, post_id, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (123, 115, 1, '1', '2024-04-04 09:37:14', '1', '2024-04-04 09:37:14', '0', 1);
 INSERT INTO system_user_post (id, user_id, post_id, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (124, 115, 2, '1', '2024-04-04 09:37:14', '1', '2024-04-04 09:37:14', '0', 1);
 INSERT INTO system_user_post (id, user_id, post_id, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (125, 1, 2, '1', '2024-07-13 22:31:39', '1', '2024-07-13 22:31:39', '0', 1);
+INSERT INTO system_user_post (id, user_id, post_id, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (126, 104, 2, '1', '2022-05-16 19:36:28', '1', '2022-05-16 19:36:28', '0', 1);
 COMMIT;
 -- @formatter:on
 
 DROP SEQUENCE IF EXISTS system_user_post_seq;
 CREATE SEQUENCE system_user_post_seq
-    START 126;
+    START 127;
 -- 将序列与表的 id 字段关联
 ALTER TABLE system_user_post
     ALTER COLUMN id SET DEFAULT nextval('system_user_post_seq');
@@ -4459,7 +4582,7 @@ COMMENT ON COLUMN system_users.password IS '密码';
 COMMENT ON COLUMN system_users.nickname IS '用户昵称';
 COMMENT ON COLUMN system_users.remark IS '备注';
 COMMENT ON COLUMN system_users.dept_id IS '部门ID';
-COMMENT ON COLUMN system_users.post_ids IS '岗位编号数组';
+COMMENT ON COLUMN system_users.post_ids IS '岗位编号数组，已废弃，以 system_user_post 为准';
 COMMENT ON COLUMN system_users.email IS '用户邮箱';
 COMMENT ON COLUMN system_users.mobile IS '手机号码';
 COMMENT ON COLUMN system_users.sex IS '用户性别';
@@ -4480,22 +4603,22 @@ COMMENT ON TABLE system_users IS '用户信息表';
 -- ----------------------------
 -- @formatter:off
 BEGIN;
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (1, 'admin', '$2a$04$KljJDa/LK7QfDm0lF5OhuePhlPfjRH3tB2Wu351Uidz.oQGJXevPi', '芋道源码', '管理员', 103, '[1,2]', '11aoteman@126.com', '18818260277', 2, 'http://test.yudao.iocoder.cn/test/20250502/avatar_1746154660449.png', 0, '0:0:0:0:0:0:0:1', '2025-05-10 18:03:15', 'admin', '2021-01-05 17:03:47', NULL, '2025-05-10 18:03:15', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (100, 'yudao', '$2a$04$h.aaPKgO.odHepnk5PCsWeEwKdojFWdTItxGKfx1r0e1CSeBzsTJ6', '芋道', '不要吓我', 104, '[1]', 'yudao@iocoder.cn', '15601691300', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2025-04-08 09:36:40', '', '2021-01-07 09:07:17', NULL, '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (1, 'admin', '$2a$04$KljJDa/LK7QfDm0lF5OhuePhlPfjRH3tB2Wu351Uidz.oQGJXevPi', '芋道源码', '管理员', 103, NULL, '11aoteman@126.com', '18818260277', 2, 'http://test.yudao.iocoder.cn/test/20250502/avatar_1746154660449.png', 0, '0:0:0:0:0:0:0:1', '2025-05-10 18:03:15', 'admin', '2021-01-05 17:03:47', NULL, '2025-05-10 18:03:15', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (100, 'yudao', '$2a$04$h.aaPKgO.odHepnk5PCsWeEwKdojFWdTItxGKfx1r0e1CSeBzsTJ6', '芋道', '不要吓我', 104, NULL, 'yudao@iocoder.cn', '15601691300', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2025-04-08 09:36:40', '', '2021-01-07 09:07:17', NULL, '2025-04-21 14:23:08', '0', 1);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (103, 'yuanma', '$2a$04$fUBSmjKCPYAUmnMzOb6qE.eZCGPhHi1JmAKclODbfS/O7fHOl2bH6', '源码', NULL, 106, NULL, 'yuanma@iocoder.cn', '15601701300', 0, NULL, 0, '0:0:0:0:0:0:0:1', '2024-08-11 17:48:12', '', '2021-01-13 23:50:35', NULL, '2025-04-21 14:23:08', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (104, 'test', '$2a$04$BrwaYn303hjA/6TnXqdGoOLhyHOAA0bVrAFu6.1dJKycqKUnIoRz2', '测试号', NULL, 107, '[1,2]', '111@qq.com', '15601691200', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2025-03-28 20:01:16', '', '2021-01-21 02:13:53', NULL, '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (104, 'test', '$2a$04$BrwaYn303hjA/6TnXqdGoOLhyHOAA0bVrAFu6.1dJKycqKUnIoRz2', '测试号', NULL, 107, NULL, '111@qq.com', '15601691200', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2025-03-28 20:01:16', '', '2021-01-21 02:13:53', NULL, '2025-04-21 14:23:08', '0', 1);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (107, 'admin107', '$2a$10$dYOOBKMO93v/.ReCqzyFg.o67Tqk.bbc2bhrpyBGkIw9aypCtr2pm', '芋艿', NULL, NULL, NULL, '', '15601691300', 0, NULL, 0, '', NULL, '1', '2022-02-20 22:59:33', '1', '2025-04-21 14:23:08', '0', 118);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (108, 'admin108', '$2a$10$y6mfvKoNYL1GXWak8nYwVOH.kCWqjactkzdoIDgiKl93WN3Ejg.Lu', '芋艿', NULL, NULL, NULL, '', '15601691300', 0, NULL, 0, '', NULL, '1', '2022-02-20 23:00:50', '1', '2025-04-21 14:23:08', '0', 119);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (109, 'admin109', '$2a$10$JAqvH0tEc0I7dfDVBI7zyuB4E3j.uH6daIjV53.vUS6PknFkDJkuK', '芋艿', NULL, NULL, NULL, '', '15601691300', 0, NULL, 0, '', NULL, '1', '2022-02-20 23:11:50', '1', '2025-04-21 14:23:08', '0', 120);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (110, 'admin110', '$2a$10$mRMIYLDtRHlf6.9ipiqH1.Z.bh/R9dO9d5iHiGYPigi6r5KOoR2Wm', '小王', NULL, NULL, NULL, '', '15601691300', 0, NULL, 0, '0:0:0:0:0:0:0:1', '2024-07-20 22:23:17', '1', '2022-02-22 00:56:14', NULL, '2025-04-21 14:23:08', '0', 121);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (111, 'test', '$2a$10$mRMIYLDtRHlf6.9ipiqH1.Z.bh/R9dO9d5iHiGYPigi6r5KOoR2Wm', '测试用户', NULL, NULL, '[]', '', '', 0, NULL, 0, '0:0:0:0:0:0:0:1', '2023-12-30 11:42:17', '110', '2022-02-23 13:14:33', NULL, '2025-04-21 14:23:08', '0', 121);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (112, 'newobject', '$2a$04$dB0z8Q819fJWz0hbaLe6B.VfHCjYgWx6LFfET5lyz3JwcqlyCkQ4C', '新对象', NULL, 100, '[]', '', '15601691235', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-16 23:11:38', '1', '2022-02-23 19:08:03', NULL, '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (111, 'test', '$2a$10$mRMIYLDtRHlf6.9ipiqH1.Z.bh/R9dO9d5iHiGYPigi6r5KOoR2Wm', '测试用户', NULL, NULL, NULL, '', '', 0, NULL, 0, '0:0:0:0:0:0:0:1', '2023-12-30 11:42:17', '110', '2022-02-23 13:14:33', NULL, '2025-04-21 14:23:08', '0', 121);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (112, 'newobject', '$2a$04$dB0z8Q819fJWz0hbaLe6B.VfHCjYgWx6LFfET5lyz3JwcqlyCkQ4C', '新对象', NULL, 100, NULL, '', '15601691235', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-16 23:11:38', '1', '2022-02-23 19:08:03', NULL, '2025-04-21 14:23:08', '0', 1);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (113, 'aoteman', '$2a$10$0acJOIk2D25/oC87nyclE..0lzeu9DtQ/n3geP4fkun/zIVRhHJIO', '芋道1', NULL, NULL, NULL, '', '15601691300', 0, NULL, 0, '127.0.0.1', '2022-03-19 18:38:51', '1', '2022-03-07 21:37:58', '1', '2025-05-05 15:30:53', '0', 122);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (114, 'hrmgr', '$2a$10$TR4eybBioGRhBmDBWkqWLO6NIh3mzYa8KBKDDB5woiGYFVlRAi.fu', 'hr 小姐姐', NULL, NULL, '[5]', '', '15601691236', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-24 22:21:05', '1', '2022-03-19 21:50:58', NULL, '2025-04-21 14:23:08', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (115, 'aotemane', '$2a$04$GcyP0Vyzb2F2Yni5PuIK9ueGxM0tkZGMtDwVRwrNbtMvorzbpNsV2', '阿呆', '11222', 102, '[1,2]', '7648@qq.com', '15601691229', 2, NULL, 0, '', NULL, '1', '2022-04-30 02:55:43', '1', '2025-04-21 14:23:08', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (117, 'admin123', '$2a$04$sEtimsHu9YCkYY4/oqElHem2Ijc9ld20eYO6lN.g/21NfLUTDLB9W', '测试号02', '1111', 100, '[2]', '', '15601691234', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-10-02 10:16:20', '1', '2022-07-09 17:40:26', NULL, '2025-04-21 14:23:08', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (118, 'goudan', '$2a$04$jth0yOj8cSJq84D6vrzusOHDwW/LpBfgBnQ6bfFlD8zNZfM632Ta2', '狗蛋', NULL, 103, '[1]', '', '15601691239', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-17 09:10:27', '1', '2022-07-09 17:44:43', '1', '2025-04-21 14:23:08', '0', 1);
-INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (131, 'hh', '$2a$04$jyH9h6.gaw8mpOjPfHIpx.8as2Rzfcmdlj5rlJFwgCw4rsv/MTb2K', '呵呵', NULL, 100, '[]', '777@qq.com', '15601882312', 1, NULL, 0, '', NULL, '1', '2024-04-27 08:45:56', '1', '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (114, 'hrmgr', '$2a$10$TR4eybBioGRhBmDBWkqWLO6NIh3mzYa8KBKDDB5woiGYFVlRAi.fu', 'hr 小姐姐', NULL, NULL, NULL, '', '15601691236', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-24 22:21:05', '1', '2022-03-19 21:50:58', NULL, '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (115, 'aotemane', '$2a$04$GcyP0Vyzb2F2Yni5PuIK9ueGxM0tkZGMtDwVRwrNbtMvorzbpNsV2', '阿呆', '11222', 102, NULL, '7648@qq.com', '15601691229', 2, NULL, 0, '', NULL, '1', '2022-04-30 02:55:43', '1', '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (117, 'admin123', '$2a$04$sEtimsHu9YCkYY4/oqElHem2Ijc9ld20eYO6lN.g/21NfLUTDLB9W', '测试号02', '1111', 100, NULL, '', '15601691234', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-10-02 10:16:20', '1', '2022-07-09 17:40:26', NULL, '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (118, 'goudan', '$2a$04$jth0yOj8cSJq84D6vrzusOHDwW/LpBfgBnQ6bfFlD8zNZfM632Ta2', '狗蛋', NULL, 103, NULL, '', '15601691239', 1, NULL, 0, '0:0:0:0:0:0:0:1', '2024-03-17 09:10:27', '1', '2022-07-09 17:44:43', '1', '2025-04-21 14:23:08', '0', 1);
+INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (131, 'hh', '$2a$04$jyH9h6.gaw8mpOjPfHIpx.8as2Rzfcmdlj5rlJFwgCw4rsv/MTb2K', '呵呵', NULL, 100, NULL, '777@qq.com', '15601882312', 1, NULL, 0, '', NULL, '1', '2024-04-27 08:45:56', '1', '2025-04-21 14:23:08', '0', 1);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (139, 'wwbwwb', '$2a$04$aOHoFbQU6zfBk/1Z9raF/ugTdhjNdx7culC1HhO0zvoczAnahCiMq', '小秃头', NULL, NULL, NULL, '', '', 0, NULL, 0, '0:0:0:0:0:0:0:1', '2024-09-10 21:03:58', NULL, '2024-09-10 21:03:58', NULL, '2025-04-21 14:23:08', '0', 1);
 INSERT INTO system_users (id, username, password, nickname, remark, dept_id, post_ids, email, mobile, sex, avatar, status, login_ip, login_date, creator, create_time, updater, update_time, deleted, tenant_id) VALUES (141, 'admin1', '$2a$04$oj6F6d7HrZ70kYVD3TNzEu.m3TPUzajOVuC66zdKna8KRerK1FmVa', '新用户', NULL, NULL, NULL, '', '', 0, '', 0, '0:0:0:0:0:0:0:1', '2025-04-08 13:09:07', '1', '2025-04-08 13:09:07', '1', '2025-04-08 13:09:07', '0', 1);
 COMMIT;
@@ -0,0 +1,150 @@
+CREATE TABLE system_notice_read
+(
+    id          int8        NOT NULL,
+    notice_id   int8        NOT NULL,
+    user_id     int8        NOT NULL,
+    user_type   int4        NOT NULL,
+    creator     varchar(64) NULL     DEFAULT '',
+    create_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(64) NULL     DEFAULT '',
+    update_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     int4        NOT NULL DEFAULT 0,
+    tenant_id   int8        NOT NULL DEFAULT 0
+);
+
+ALTER TABLE system_notice_read
+    ADD CONSTRAINT pk_system_notice_read PRIMARY KEY (id);
+
+CREATE UNIQUE INDEX uk_system_notice_read_notice_user ON system_notice_read (notice_id, user_id, user_type);
+
+COMMENT ON COLUMN system_notice_read.id IS '编号';
+COMMENT ON COLUMN system_notice_read.notice_id IS '公告编号';
+COMMENT ON COLUMN system_notice_read.user_id IS '用户编号';
+COMMENT ON COLUMN system_notice_read.user_type IS '用户类型';
+COMMENT ON COLUMN system_notice_read.creator IS '创建者';
+COMMENT ON COLUMN system_notice_read.create_time IS '创建时间';
+COMMENT ON COLUMN system_notice_read.updater IS '更新者';
+COMMENT ON COLUMN system_notice_read.update_time IS '更新时间';
+COMMENT ON COLUMN system_notice_read.deleted IS '是否删除';
+COMMENT ON COLUMN system_notice_read.tenant_id IS '租户编号';
+COMMENT ON TABLE system_notice_read IS '通知公告已读记录表';
+
+CREATE SEQUENCE system_notice_read_seq
+    START 1;
+-- 将序列与表的 id 字段关联
+ALTER TABLE system_notice_read
+    ALTER COLUMN id SET DEFAULT nextval('system_notice_read_seq');
+
+-- 设置序列由表拥有
+ALTER SEQUENCE system_notice_read_seq
+    OWNED BY system_notice_read.id;
+
+ALTER TABLE system_oauth2_code
+    ADD COLUMN code_challenge varchar(128) NULL DEFAULT NULL,
+    ADD COLUMN code_challenge_method varchar(16) NULL DEFAULT NULL;
+
+COMMENT ON COLUMN system_oauth2_code.code_challenge IS 'PKCE 的 code_challenge';
+COMMENT ON COLUMN system_oauth2_code.code_challenge_method IS 'PKCE 的 code_challenge_method';
+
+ALTER TABLE system_oauth2_code
+    ADD COLUMN nonce varchar(255) NULL DEFAULT NULL;
+
+COMMENT ON COLUMN system_oauth2_code.nonce IS 'OpenID Connect 的 nonce';
+
+ALTER TABLE system_oauth2_access_token
+    ADD COLUMN user_ip varchar(50) NULL DEFAULT NULL,
+    ADD COLUMN user_agent varchar(512) NULL DEFAULT NULL;
+
+COMMENT ON COLUMN system_oauth2_access_token.user_ip IS '用户 IP';
+COMMENT ON COLUMN system_oauth2_access_token.user_agent IS '浏览器 UA';
+
+ALTER TABLE system_oauth2_refresh_token
+    ADD COLUMN user_ip varchar(50) NULL DEFAULT NULL,
+    ADD COLUMN user_agent varchar(512) NULL DEFAULT NULL;
+
+COMMENT ON COLUMN system_oauth2_refresh_token.user_ip IS '用户 IP';
+COMMENT ON COLUMN system_oauth2_refresh_token.user_agent IS '浏览器 UA';
+
+ALTER TABLE system_oauth2_refresh_token
+    ADD COLUMN family_id varchar(32) NULL DEFAULT NULL;
+
+COMMENT ON COLUMN system_oauth2_refresh_token.family_id IS '令牌家族编号，轮换产生的刷新令牌共用同一家族';
+
+-- 用户岗位以 system_user_post 为准，先将只记录在 post_ids 中的岗位补充到 system_user_post，再清理不再维护的 post_ids 字段
+INSERT INTO system_user_post (user_id, post_id, creator, create_time, updater, update_time, deleted, tenant_id)
+SELECT u.id, p.post_id::int8, u.creator, CURRENT_TIMESTAMP, u.creator, CURRENT_TIMESTAMP, 0, u.tenant_id
+FROM system_users u,
+     jsonb_array_elements_text(u.post_ids::jsonb) AS p(post_id)
+WHERE u.post_ids IS NOT NULL
+  AND u.post_ids <> ''
+  AND NOT EXISTS (SELECT 1
+                  FROM system_user_post up
+                  WHERE up.user_id = u.id
+                    AND up.post_id = p.post_id::int8
+                    AND up.deleted = 0);
+
+UPDATE system_users
+SET post_ids = NULL
+WHERE post_ids IS NOT NULL;
+
+COMMENT ON COLUMN system_users.post_ids IS '岗位编号数组，已废弃，以 system_user_post 为准';
+
+-- 租户管理菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/page","/system/admin-api/system/tenant/get"]' WHERE id = 1139;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/create","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1140;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/update","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1141;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/delete"]' WHERE id = 1142;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant/simple-list"]' WHERE id = 5010;
+
+-- 租户套餐菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/page","/system/admin-api/system/tenant-package/get","/system/admin-api/system/tenant-package/simple-list"]' WHERE id = 1226;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/create"]' WHERE id = 1227;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/update"]' WHERE id = 1228;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/tenant-package/delete"]' WHERE id = 1229;
+
+-- 系统管理菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/page","/system/admin-api/system/user/get"]' WHERE id = 1001;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/create"]' WHERE id = 1002;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/update","/system/admin-api/system/user/update-status"]' WHERE id = 1003;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/delete"]' WHERE id = 1004;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/reset-password"]' WHERE id = 1007;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/page","/system/admin-api/system/role/get"]' WHERE id = 1008;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/create"]' WHERE id = 1009;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/update","/system/admin-api/system/role/update-status"]' WHERE id = 1010;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/delete"]' WHERE id = 1011;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/assign-menus","/system/admin-api/system/role/list-menus"]' WHERE id = 1063;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/role/assign-data-scope"]' WHERE id = 1064;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/user/assign-roles","/system/admin-api/system/role/list-by-user"]' WHERE id = 1065;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/list","/system/admin-api/system/menu/get"]' WHERE id = 1013;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/create"]' WHERE id = 1014;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/update"]' WHERE id = 1015;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/menu/delete"]' WHERE id = 1016;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/list","/system/admin-api/system/dept/get"]' WHERE id = 1017;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/create"]' WHERE id = 1018;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/update"]' WHERE id = 1019;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dept/delete"]' WHERE id = 1020;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/page","/system/admin-api/system/post/get"]' WHERE id = 1021;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/create"]' WHERE id = 1022;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/update"]' WHERE id = 1023;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/post/delete"]' WHERE id = 1024;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/page","/system/admin-api/system/dict-type/get","/system/admin-api/system/dict-data/page","/system/admin-api/system/dict-data/get"]' WHERE id = 1026;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/create","/system/admin-api/system/dict-data/create"]' WHERE id = 1027;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/update","/system/admin-api/system/dict-data/update"]' WHERE id = 1028;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/dict-type/delete","/system/admin-api/system/dict-data/delete"]' WHERE id = 1029;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/page","/system/admin-api/system/notice/get"]' WHERE id = 1036;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/create"]' WHERE id = 1037;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/update"]' WHERE id = 1038;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/notice/delete"]' WHERE id = 1039;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/operate-log/page","/system/admin-api/system/operate-log/timeline"]' WHERE id = 1040;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/login-log/page"]' WHERE id = 1043;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/login-log/export-csv"]' WHERE id = 1045;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-token/page"]' WHERE id = 1046;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-token/delete"]' WHERE id = 1048;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/page","/system/admin-api/system/oauth2-client/get"]' WHERE id = 1264;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/create"]' WHERE id = 1265;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/update","/system/admin-api/system/oauth2-client/regenerate-secret"]' WHERE id = 1266;
+UPDATE system_menu SET perm_apis = '["/system/admin-api/system/oauth2-client/delete"]' WHERE id = 1267;
+
+-- 参数配置菜单的授权接口
+UPDATE system_menu SET perm_apis = '["/infra/admin-api/infra/config/get"]' WHERE id = 1031;
+UPDATE system_menu SET perm_apis = '["/infra/admin-api/infra/config/update"]' WHERE id = 1033;
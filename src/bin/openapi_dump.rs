@@ -1,9 +1,38 @@
 use daoyi_common::app::openapi;
-use daoyi_module_system::api::admin::auth::AuthApiDoc;
+use daoyi_module_infra::api::admin::config::ConfigApiDoc;
+use daoyi_module_system::api::admin::{
+    auth::AuthApiDoc, dept::DeptApiDoc, dict_data::DictDataApiDoc, dict_type::DictTypeApiDoc,
+    login_log::LoginLogApiDoc, menu::MenuApiDoc, notice::NoticeApiDoc,
+    oauth2_client::OAuth2ClientApiDoc, oauth2_device::OAuth2DeviceApiDoc,
+    oauth2_oidc::OAuth2OidcApiDoc, oauth2_open::OAuth2OpenApiDoc, oauth2_token::OAuth2TokenApiDoc,
+    operate_log::OperateLogApiDoc, post::PostApiDoc, role::RoleApiDoc, tenant::TenantApiDoc,
+    tenant_package::TenantPackageApiDoc, user::UserApiDoc, user_profile::UserProfileApiDoc,
+};
 use utoipa::OpenApi;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let doc = openapi::build_openapi_with(&[AuthApiDoc::openapi()]);
+    let doc = openapi::build_openapi_with(&[
+        AuthApiDoc::openapi(),
+        UserApiDoc::openapi(),
+        RoleApiDoc::openapi(),
+        MenuApiDoc::openapi(),
+        DeptApiDoc::openapi(),
+        PostApiDoc::openapi(),
+        DictTypeApiDoc::openapi(),
+        DictDataApiDoc::openapi(),
+        NoticeApiDoc::openapi(),
+        TenantApiDoc::openapi(),
+        TenantPackageApiDoc::openapi(),
+        OAuth2ClientApiDoc::openapi(),
+        OAuth2OpenApiDoc::openapi(),
+        OAuth2OidcApiDoc::openapi(),
+        OAuth2DeviceApiDoc::openapi(),
+        OAuth2TokenApiDoc::openapi(),
+        UserProfileApiDoc::openapi(),
+        LoginLogApiDoc::openapi(),
+        OperateLogApiDoc::openapi(),
+        ConfigApiDoc::openapi(),
+    ]);
     println!("{}", serde_json::to_string_pretty(&doc)?);
     Ok(())
 }